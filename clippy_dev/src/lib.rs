@@ -11,7 +11,8 @@ lazy_static! {
     static ref DEC_CLIPPY_LINT_RE: Regex = Regex::new(
         r#"(?x)
         declare_clippy_lint!\s*[\{(]
-        (?:\s+///.*)*
+        (?P<docs>(?:\s+///.*)*)
+        (?:\s+\#\[clippy::version\s*=\s*"(?P<version>[^"]*)"\])?
         \s+pub\s+(?P<name>[A-Z_][A-Z_0-9]*)\s*,\s*
         (?P<cat>[a-z_]+)\s*,\s*
         "(?P<desc>(?:[^"\\]+|\\(?s).(?-s))*)"\s*[})]
@@ -20,6 +21,7 @@ lazy_static! {
     .unwrap();
     static ref DEC_DEPRECATED_LINT_RE: Regex = Regex::new(
         r#"(?x)
+        (?P<docs>(?:///.*\n)*)
         declare_deprecated_lint!\s*[{(]\s*
         (?:\s+///.*)*
         \s+pub\s+(?P<name>[A-Z_][A-Z_0-9]*)\s*,\s*
@@ -28,6 +30,14 @@ lazy_static! {
     )
     .unwrap();
     static ref NL_ESCAPE_RE: Regex = Regex::new(r#"\\\n\s*"#).unwrap();
+    static ref DOC_COMMENT_RE: Regex = Regex::new(r#"^\s*/// ?(.*)$"#).unwrap();
+    static ref CONF_KEY_RE: Regex = Regex::new(
+        r#"(?x)
+        ///\s*Lint:\s*(?P<lints>[A-Z_0-9,\s]+)\..*\n
+        \s*\(\s*[a-z_0-9]+\s*,\s*"(?P<key>[a-z_0-9]+)"
+    "#
+    )
+    .unwrap();
     pub static ref DOCS_LINK: String = "https://rust-lang.github.io/rust-clippy/master/index.html".to_string();
 }
 
@@ -39,6 +49,12 @@ pub struct Lint {
     pub desc: String,
     pub deprecation: Option<String>,
     pub module: String,
+    /// The documentation of the lint, without the leading `///`.
+    pub docs: String,
+    /// The `clippy.toml` keys configuring the lint.
+    pub config: Vec<String>,
+    /// The version of Clippy the lint was added in, from its `#[clippy::version]` attribute.
+    pub version: Option<String>,
 }
 
 impl Lint {
@@ -50,9 +66,30 @@ impl Lint {
             desc: NL_ESCAPE_RE.replace(&desc.replace("\\\"", "\""), "").to_string(),
             deprecation: deprecation.map(std::string::ToString::to_string),
             module: module.to_string(),
+            docs: String::new(),
+            config: Vec::new(),
+            version: None,
         }
     }
 
+    /// Sets the documentation from the `///` comment lines in front of the lint declaration.
+    #[must_use]
+    pub fn with_docs(mut self, doc_comment: &str) -> Self {
+        self.docs = doc_comment
+            .lines()
+            .filter_map(|line| DOC_COMMENT_RE.captures(line))
+            .map(|m| m.get(1).map_or("", |doc| doc.as_str()))
+            .join("\n");
+        self
+    }
+
+    /// Sets the version from the `#[clippy::version]` attribute of the lint declaration.
+    #[must_use]
+    pub fn with_version(mut self, version: Option<&str>) -> Self {
+        self.version = version.map(ToString::to_string);
+        self
+    }
+
     /// Returns all non-deprecated lints and non-internal lints
     pub fn usable_lints(lints: impl Iterator<Item = Self>) -> impl Iterator<Item = Self> {
         lints.filter(|l| l.deprecation.is_none() && !l.is_internal())
@@ -89,6 +126,44 @@ pub fn gen_lint_group_list(lints: Vec<Lint>) -> Vec<String> {
         .collect::<Vec<String>>()
 }
 
+/// Generates the `ALL_LINTS` and `DEPRECATED_LINTS` lists in `src/lintlist/mod.rs`.
+#[must_use]
+pub fn gen_lint_list(lints: &[Lint]) -> Vec<String> {
+    let mut usable_lints: Vec<&Lint> = lints
+        .iter()
+        .filter(|l| l.deprecation.is_none() && !l.is_internal())
+        .collect();
+    usable_lints.sort_by_key(|l| l.name.clone());
+    let mut deprecated_lints: Vec<&Lint> = lints.iter().filter(|l| l.deprecation.is_some()).collect();
+    deprecated_lints.sort_by_key(|l| l.name.clone());
+
+    let mut list = gen_lint_array("ALL_LINTS", &usable_lints);
+    list.push(String::new());
+    list.extend(gen_lint_array("DEPRECATED_LINTS", &deprecated_lints));
+    list
+}
+
+fn gen_lint_array(name: &str, lints: &[&Lint]) -> Vec<String> {
+    let mut array = vec![format!("pub const {}: [Lint; {}] = [", name, lints.len())];
+    for lint in lints {
+        array.push("    Lint {".to_string());
+        array.push(format!("        name: {:?},", lint.name));
+        array.push(format!("        group: {:?},", lint.group));
+        array.push(format!("        desc: {:?},", lint.desc));
+        array.push(format!("        deprecation: {:?},", lint.deprecation));
+        array.push(format!("        module: {:?},", lint.module));
+        array.push(format!("        docs: {:?},", lint.docs));
+        array.push(format!(
+            "        config: &[{}],",
+            lint.config.iter().map(|key| format!("{:?}", key)).join(", ")
+        ));
+        array.push(format!("        version: {:?},", lint.version));
+        array.push("    },".to_string());
+    }
+    array.push("];".to_string());
+    array
+}
+
 /// Generates the `pub mod module_name` list in `clippy_lints/src/lib.rs`.
 #[must_use]
 pub fn gen_modules_list(lints: Vec<Lint>) -> Vec<String> {
@@ -165,7 +240,32 @@ pub fn gen_register_lint_list(lints: &[Lint]) -> Vec<String> {
 
 /// Gathers all files in `src/clippy_lints` and gathers all lints inside
 pub fn gather_all() -> impl Iterator<Item = Lint> {
-    lint_files().flat_map(|f| gather_from_file(&f))
+    let config = gather_config();
+    lint_files().flat_map(|f| gather_from_file(&f)).map(move |mut lint| {
+        lint.config = config.get(&lint.name).cloned().unwrap_or_default();
+        lint
+    })
+}
+
+/// Gathers the `clippy.toml` keys of each lint from `clippy_lints/src/utils/conf.rs`
+fn gather_config() -> HashMap<String, Vec<String>> {
+    let mut file = fs::File::open("../clippy_lints/src/utils/conf.rs").unwrap();
+    let mut content = String::new();
+    file.read_to_string(&mut content).unwrap();
+    parse_config(&content)
+}
+
+fn parse_config(content: &str) -> HashMap<String, Vec<String>> {
+    CONF_KEY_RE
+        .captures_iter(content)
+        .flat_map(|m| {
+            let key = m["key"].replace('_', "-");
+            m["lints"]
+                .split(',')
+                .map(|lint| (lint.trim().to_lowercase(), key.clone()))
+                .collect::<Vec<_>>()
+        })
+        .into_group_map()
 }
 
 fn gather_from_file(dir_entry: &walkdir::DirEntry) -> impl Iterator<Item = Lint> {
@@ -189,12 +289,14 @@ fn gather_from_file(dir_entry: &walkdir::DirEntry) -> impl Iterator<Item = Lint>
 }
 
 fn parse_contents(content: &str, filename: &str) -> impl Iterator<Item = Lint> {
-    let lints = DEC_CLIPPY_LINT_RE
-        .captures_iter(content)
-        .map(|m| Lint::new(&m["name"], &m["cat"], &m["desc"], None, filename));
+    let lints = DEC_CLIPPY_LINT_RE.captures_iter(content).map(|m| {
+        Lint::new(&m["name"], &m["cat"], &m["desc"], None, filename)
+            .with_docs(&m["docs"])
+            .with_version(m.name("version").map(|version| version.as_str()))
+    });
     let deprecated = DEC_DEPRECATED_LINT_RE
         .captures_iter(content)
-        .map(|m| Lint::new(&m["name"], "Deprecated", &m["desc"], Some(&m["desc"]), filename));
+        .map(|m| Lint::new(&m["name"], "Deprecated", &m["desc"], Some(&m["desc"]), filename).with_docs(&m["docs"]));
     // Removing the `.collect::<Vec<Lint>>().into_iter()` causes some lifetime issues due to the map
     lints.chain(deprecated).collect::<Vec<Lint>>().into_iter()
 }
@@ -321,6 +423,9 @@ fn test_parse_contents() {
     let result: Vec<Lint> = parse_contents(
         r#"
 declare_clippy_lint! {
+    /// **What it does:** Checks for stuff.
+    ///
+    /// **Example:**
     pub PTR_ARG,
     style,
     "really long \
@@ -328,6 +433,7 @@ declare_clippy_lint! {
 }
 
 declare_clippy_lint!{
+    #[clippy::version = "0.0.212"]
     pub DOC_MARKDOWN,
     pedantic,
     "single line"
@@ -344,17 +450,91 @@ declare_deprecated_lint! {
     .collect();
 
     let expected = vec![
-        Lint::new("ptr_arg", "style", "really long text", None, "module_name"),
-        Lint::new("doc_markdown", "pedantic", "single line", None, "module_name"),
+        Lint::new("ptr_arg", "style", "really long text", None, "module_name")
+            .with_docs("/// **What it does:** Checks for stuff.\n///\n/// **Example:**"),
+        Lint::new("doc_markdown", "pedantic", "single line", None, "module_name").with_version(Some("0.0.212")),
         Lint::new(
             "should_assert_eq",
             "Deprecated",
             "`assert!()` will be more flexible with RFC 2011",
             Some("`assert!()` will be more flexible with RFC 2011"),
             "module_name",
-        ),
+        )
+        .with_docs("/// some doc comment"),
     ];
     assert_eq!(expected, result);
+    assert_eq!("**What it does:** Checks for stuff.\n\n**Example:**", result[0].docs);
+}
+
+#[test]
+fn test_parse_config() {
+    let result = parse_config(
+        r#"
+define_Conf! {
+    /// Lint: BLACKLISTED_NAME. The list of blacklisted names to lint about
+    (blacklisted_names, "blacklisted_names", ["foo", "bar", "baz", "quux"] => Vec<String>),
+    /// DEPRECATED LINT: CYCLOMATIC_COMPLEXITY. Use the Cognitive Complexity lint instead.
+    (cyclomatic_complexity_threshold, "cyclomatic_complexity_threshold", None => Option<u64>),
+    /// Lint: TOO_MANY_ARGUMENTS, TOO_MANY_LINES. The maximum number of argument a function or method can have
+    (too_many_arguments_threshold, "too_many_arguments_threshold", 7 => u64),
+}
+    "#,
+    );
+    let mut expected = HashMap::new();
+    expected.insert("blacklisted_name".to_string(), vec!["blacklisted-names".to_string()]);
+    expected.insert(
+        "too_many_arguments".to_string(),
+        vec!["too-many-arguments-threshold".to_string()],
+    );
+    expected.insert(
+        "too_many_lines".to_string(),
+        vec!["too-many-arguments-threshold".to_string()],
+    );
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn test_gen_lint_list() {
+    let mut documented = Lint::new("should_assert_eq2", "group2", "abc", None, "module_name")
+        .with_docs("/// Docs")
+        .with_version(Some("0.0.212"));
+    documented.config = vec!["a-key".to_string()];
+    let lints = vec![
+        documented,
+        Lint::new("should_assert_eq", "group1", "abc", Some("Reason"), "module_name"),
+        Lint::new("incorrect_internal", "internal_style", "abc", None, "module_name"),
+    ];
+    let expected: Vec<String> = vec![
+        "pub const ALL_LINTS: [Lint; 1] = [",
+        "    Lint {",
+        "        name: \"should_assert_eq2\",",
+        "        group: \"group2\",",
+        "        desc: \"abc\",",
+        "        deprecation: None,",
+        "        module: \"module_name\",",
+        "        docs: \"Docs\",",
+        "        config: &[\"a-key\"],",
+        "        version: Some(\"0.0.212\"),",
+        "    },",
+        "];",
+        "",
+        "pub const DEPRECATED_LINTS: [Lint; 1] = [",
+        "    Lint {",
+        "        name: \"should_assert_eq\",",
+        "        group: \"group1\",",
+        "        desc: \"abc\",",
+        "        deprecation: Some(\"Reason\"),",
+        "        module: \"module_name\",",
+        "        docs: \"\",",
+        "        config: &[],",
+        "        version: None,",
+        "    },",
+        "];",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect();
+    assert_eq!(expected, gen_lint_list(&lints));
 }
 
 #[test]
@@ -0,0 +1,333 @@
+//! `util/dev lintcheck`: runs Clippy on a list of local crates and writes their warnings to a
+//! sorted report, which can be diffed against the report of another version of Clippy.
+
+use flate2::read::GzDecoder;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const LINTCHECK_DIR: &str = "../target/lintcheck";
+
+/// The crates to check, e.g.:
+///
+/// ```toml
+/// [crates]
+/// # a directory, relative to this file
+/// my_crate = { path = "../my_crate" }
+/// # a `.crate` archive, as downloaded by `cargo vendor` or `cargo package`
+/// regex = { file = "vendor/regex-1.3.1.crate" }
+/// ```
+#[derive(Deserialize)]
+struct CrateList {
+    crates: BTreeMap<String, CrateSource>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CrateSource {
+    path: Option<PathBuf>,
+    file: Option<PathBuf>,
+}
+
+/// A warning of the report. The derived ordering follows the fields, so the report is sorted by
+/// crate, then file and position. Text reports print the crate, the lint, then the location, as
+/// the crate and the lint have no spaces, unlike the file, but keep the order of the warnings.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+struct Warning {
+    #[serde(rename = "crate")]
+    krate: String,
+    file: String,
+    line: u64,
+    column: u64,
+    lint: String,
+    message: String,
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}:{}:{} {:?}",
+            self.krate, self.lint, self.file, self.line, self.column, self.message
+        )
+    }
+}
+
+/// Checks the crates of `crate_list` with the `clippy_args` and writes the report to `output`, as
+/// JSON if its extension is `.json`, or as text otherwise. With `diff`, prints the warnings added
+/// and removed since that report.
+///
+/// # Errors
+///
+/// Returns an error if Clippy isn't built, or if a file can't be read or written.
+pub fn run(crate_list: &Path, clippy_args: &[&str], output: &Path, diff: Option<&Path>) -> Result<(), io::Error> {
+    let cargo_clippy = Path::new("../target/debug/cargo-clippy");
+    if !cargo_clippy.exists() {
+        return Err(io::Error::new(
+            ErrorKind::NotFound,
+            "`target/debug/cargo-clippy` doesn't exist, run `cargo build` first",
+        ));
+    }
+    let cargo_clippy = cargo_clippy.canonicalize()?;
+
+    let list: CrateList = toml::from_str(&fs::read_to_string(crate_list).map_err(|e| context(e, crate_list))?)
+        .map_err(|e| io::Error::new(ErrorKind::InvalidData, format!("{}: {}", crate_list.display(), e)))?;
+    let base = crate_list.parent().unwrap_or_else(|| Path::new("."));
+
+    let mut warnings = BTreeSet::new();
+    for (name, source) in &list.crates {
+        if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                format!("`{}` isn't a valid crate name", name),
+            ));
+        }
+        let dir = match (&source.path, &source.file) {
+            (Some(path), None) => base.join(path),
+            (None, Some(file)) => extract(name, &base.join(file))?,
+            _ => {
+                return Err(io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!("`{}` needs either a `path` or a `file`", name),
+                ))
+            },
+        };
+        eprintln!("Checking {}", name);
+        warnings.extend(check_crate(&cargo_clippy, name, &dir.canonicalize()?, clippy_args)?);
+    }
+
+    let report = if output.extension().map_or(false, |ext| ext == "json") {
+        serde_json::to_string_pretty(&warnings).expect("failed to serialize the warnings")
+    } else {
+        warnings.iter().map(|warning| format!("{}\n", warning)).collect()
+    };
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(output, report).map_err(|e| context(e, output))?;
+    eprintln!("Wrote {} warnings to {}", warnings.len(), output.display());
+
+    if let Some(old) = diff {
+        print!(
+            "{}",
+            report_diff(&read_report(old)?, &warnings.iter().map(ToString::to_string).collect())
+        );
+    }
+    Ok(())
+}
+
+fn context(error: io::Error, path: &Path) -> io::Error {
+    io::Error::new(error.kind(), format!("{}: {}", path.display(), error))
+}
+
+/// Extracts a `.crate` archive, returning the directory of the package.
+fn extract(name: &str, file: &Path) -> Result<PathBuf, io::Error> {
+    let dest = Path::new(LINTCHECK_DIR).join("sources").join(name);
+    if dest.exists() {
+        fs::remove_dir_all(&dest)?;
+    }
+    let archive = File::open(file).map_err(|e| context(e, file))?;
+    tar::Archive::new(GzDecoder::new(archive))
+        .unpack(&dest)
+        .map_err(|e| context(e, file))?;
+
+    // the archives contain a single `name-version` directory
+    let mut entries = fs::read_dir(&dest)?.collect::<Result<Vec<_>, _>>()?;
+    match entries.pop() {
+        Some(entry) if entries.is_empty() && entry.path().is_dir() => Ok(entry.path()),
+        _ => Ok(dest),
+    }
+}
+
+/// Runs Clippy on the crate in `dir`, returning its warnings.
+fn check_crate(cargo_clippy: &Path, name: &str, dir: &Path, clippy_args: &[&str]) -> Result<Vec<Warning>, io::Error> {
+    // cargo runs in the directory of the crate, so the target directory must be absolute
+    let target_dir = Path::new(LINTCHECK_DIR).join("target").join(name);
+    fs::create_dir_all(&target_dir)?;
+    let target_dir = target_dir.canonicalize()?;
+    clean_workspace(dir, &target_dir)?;
+
+    let output = Command::new(cargo_clippy)
+        .args(&["clippy", "--offline", "--message-format=json", "--", "--cap-lints=warn"])
+        .args(clippy_args)
+        .current_dir(dir)
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .output()?;
+    if !output.status.success() {
+        eprintln!(
+            "warning: `{}` doesn't compile, its report is incomplete:\n{}",
+            name,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter(|message| message["reason"] == "compiler-message")
+        .filter_map(|message| {
+            let diag = &message["message"];
+            let lint = diag["code"]["code"]
+                .as_str()
+                .filter(|code| code.starts_with("clippy::"))?;
+            let span = diag["spans"]
+                .as_array()?
+                .iter()
+                .find(|span| span["is_primary"].as_bool() == Some(true))?;
+            let file = Path::new(span["file_name"].as_str()?);
+            Some(Warning {
+                krate: name.to_string(),
+                file: file.strip_prefix(dir).unwrap_or(file).display().to_string(),
+                line: span["line_start"].as_u64()?,
+                column: span["column_start"].as_u64()?,
+                lint: lint.to_string(),
+                message: diag["message"].as_str()?.to_string(),
+            })
+        })
+        .collect())
+}
+
+/// Removes the artifacts of the workspace in `dir` from `target_dir`, so that cargo checks its
+/// packages again with the current Clippy, without rebuilding their dependencies.
+fn clean_workspace(dir: &Path, target_dir: &Path) -> Result<(), io::Error> {
+    let output = Command::new("cargo")
+        .args(&["metadata", "--offline", "--no-deps", "--format-version=1"])
+        .current_dir(dir)
+        .output()?;
+    let metadata: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap_or_default();
+    let packages: Vec<_> = metadata["packages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|package| package["name"].as_str())
+        .flat_map(|name| vec!["-p", name])
+        .collect();
+    if packages.is_empty() {
+        return Ok(());
+    }
+    Command::new("cargo")
+        .args(&["clean", "--offline"])
+        .args(&packages)
+        .current_dir(dir)
+        .env("CARGO_TARGET_DIR", target_dir)
+        .status()?;
+    Ok(())
+}
+
+/// Reads a text or JSON report as the lines of a text report.
+fn read_report(path: &Path) -> Result<BTreeSet<String>, io::Error> {
+    let content = fs::read_to_string(path).map_err(|e| context(e, path))?;
+    if path.extension().map_or(false, |ext| ext == "json") {
+        let warnings: Vec<Warning> = serde_json::from_str(&content)
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, format!("{}: {}", path.display(), e)))?;
+        Ok(warnings.iter().map(ToString::to_string).collect())
+    } else {
+        Ok(content.lines().map(ToString::to_string).collect())
+    }
+}
+
+/// The warnings added and removed, and how many of them each lint has.
+fn report_diff(old: &BTreeSet<String>, new: &BTreeSet<String>) -> String {
+    let mut diff = String::new();
+    let mut per_lint: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+    for line in new.difference(old) {
+        diff.push_str(&format!("+ {}\n", line));
+        per_lint.entry(lint_of(line)).or_default().0 += 1;
+    }
+    for line in old.difference(new) {
+        diff.push_str(&format!("- {}\n", line));
+        per_lint.entry(lint_of(line)).or_default().1 += 1;
+    }
+
+    if per_lint.is_empty() {
+        diff.push_str("no changes\n");
+    } else {
+        diff.push('\n');
+        for (lint, (added, removed)) in per_lint {
+            diff.push_str(&format!("{}: +{} -{}\n", lint, added, removed));
+        }
+    }
+    diff
+}
+
+/// The lint of a line of a text report, its second field.
+fn lint_of(line: &str) -> &str {
+    line.splitn(3, ' ').nth(1).unwrap_or_default()
+}
+
+#[cfg(test)]
+fn warning(file: &str, line: u64, lint: &str) -> Warning {
+    Warning {
+        krate: "regex".to_string(),
+        file: file.to_string(),
+        line,
+        column: 5,
+        lint: lint.to_string(),
+        message: "a \"message\"".to_string(),
+    }
+}
+
+#[test]
+fn test_warning_display() {
+    assert_eq!(
+        warning("src/my file.rs", 10, "clippy::needless_return").to_string(),
+        r#"regex clippy::needless_return src/my file.rs:10:5 "a \"message\"""#
+    );
+}
+
+#[test]
+fn test_lint_of() {
+    let line = warning("src/my file.rs", 10, "clippy::needless_return").to_string();
+    assert_eq!(lint_of(&line), "clippy::needless_return");
+    assert_eq!(lint_of("regex"), "");
+}
+
+#[test]
+fn test_read_report() {
+    let warnings = vec![
+        warning("src/lib.rs", 1, "clippy::a"),
+        warning("src/my file.rs", 2, "clippy::b"),
+    ];
+    let expected: BTreeSet<String> = warnings.iter().map(ToString::to_string).collect();
+    let dir = std::env::temp_dir().join(format!("lintcheck-test-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    let json = dir.join("report.json");
+    fs::write(&json, serde_json::to_string_pretty(&warnings).unwrap()).unwrap();
+    assert_eq!(read_report(&json).unwrap(), expected);
+
+    let text = dir.join("report.txt");
+    fs::write(
+        &text,
+        warnings
+            .iter()
+            .map(|warning| format!("{}\n", warning))
+            .collect::<String>(),
+    )
+    .unwrap();
+    assert_eq!(read_report(&text).unwrap(), expected);
+
+    assert!(read_report(&dir.join("missing.txt")).is_err());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_diff() {
+    let report = |warnings: &[Warning]| warnings.iter().map(ToString::to_string).collect::<BTreeSet<_>>();
+    let kept = warning("src/lib.rs", 1, "clippy::a");
+    let added = warning("src/my file.rs", 2, "clippy::b");
+    let removed = warning("src/lib.rs", 3, "clippy::a");
+
+    assert_eq!(
+        report_diff(
+            &report(&[kept.clone(), removed.clone()]),
+            &report(&[kept.clone(), added.clone()])
+        ),
+        format!("+ {}\n- {}\n\nclippy::a: +0 -1\nclippy::b: +1 -0\n", added, removed)
+    );
+    assert_eq!(report_diff(&report(&[kept.clone()]), &report(&[kept])), "no changes\n");
+}
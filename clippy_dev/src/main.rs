@@ -4,8 +4,11 @@ extern crate regex;
 
 use clap::{App, Arg, SubCommand};
 use clippy_dev::*;
+use std::path::Path;
 
 mod fmt;
+mod lintcheck;
+mod new_lint;
 mod stderr_length_check;
 
 #[derive(PartialEq)]
@@ -53,6 +56,78 @@ fn main() {
                         .help("Checks that util/dev update_lints has been run. Used on CI."),
                 ),
         )
+        .subcommand(
+            SubCommand::with_name("new_lint")
+                .about("Create a new lint and run `util/dev update_lints`")
+                .arg(
+                    Arg::with_name("pass")
+                        .short("p")
+                        .long("pass")
+                        .help("Specify whether the lint runs during the early or late pass")
+                        .takes_value(true)
+                        .possible_values(&["early", "late"])
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("name")
+                        .short("n")
+                        .long("name")
+                        .help("Name of the new lint in snake case, ex: fn_too_long")
+                        .takes_value(true)
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("category")
+                        .short("c")
+                        .long("category")
+                        .help("What category the lint belongs to")
+                        .default_value("nursery")
+                        .possible_values(&[
+                            "style",
+                            "correctness",
+                            "complexity",
+                            "perf",
+                            "pedantic",
+                            "restriction",
+                            "cargo",
+                            "nursery",
+                            "internal",
+                            "internal_warn",
+                        ])
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("lintcheck")
+                .about("Run Clippy on a list of local crates and report their warnings")
+                .arg(
+                    Arg::with_name("crates")
+                        .long("crates")
+                        .help("The TOML file listing the crates to check")
+                        .takes_value(true)
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .short("o")
+                        .long("output")
+                        .help("Where to write the report, as JSON if it ends with `.json`")
+                        .default_value("../target/lintcheck/report.txt")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("diff")
+                        .long("diff")
+                        .help("Print the warnings added and removed since this report")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("clippy_args")
+                        .help("Arguments passed to Clippy, e.g. `-- -W clippy::pedantic`")
+                        .multiple(true)
+                        .last(true),
+                ),
+        )
         .arg(
             Arg::with_name("limit-stderr-length")
                 .long("limit-stderr-length")
@@ -68,6 +143,31 @@ fn main() {
         ("fmt", Some(matches)) => {
             fmt::run(matches.is_present("check"), matches.is_present("verbose"));
         },
+        ("new_lint", Some(matches)) => {
+            match new_lint::create(
+                matches.value_of("pass").expect("`pass` is required"),
+                matches.value_of("name").expect("`name` is required"),
+                matches.value_of("category").expect("`category` has a default value"),
+            ) {
+                Ok(_) => update_lints(&UpdateMode::Change),
+                Err(e) => {
+                    eprintln!("Unable to create lint: {}", e);
+                    std::process::exit(1);
+                },
+            }
+        },
+        ("lintcheck", Some(matches)) => {
+            let clippy_args: Vec<&str> = matches.values_of("clippy_args").into_iter().flatten().collect();
+            if let Err(e) = lintcheck::run(
+                Path::new(matches.value_of("crates").expect("`crates` is required")),
+                &clippy_args,
+                Path::new(matches.value_of("output").expect("`output` has a default value")),
+                matches.value_of("diff").map(Path::new),
+            ) {
+                eprintln!("error: {}", e);
+                std::process::exit(1);
+            }
+        },
         ("update_lints", Some(matches)) => {
             if matches.is_present("print-only") {
                 print_lints();
@@ -116,25 +216,13 @@ fn update_lints(update_mode: &UpdateMode) {
     let usable_lints: Vec<Lint> = Lint::usable_lints(lint_list.clone().into_iter()).collect();
     let lint_count = usable_lints.len();
 
-    let mut sorted_usable_lints = usable_lints.clone();
-    sorted_usable_lints.sort_by_key(|lint| lint.name.clone());
-
     let mut file_change = replace_region_in_file(
         "../src/lintlist/mod.rs",
         "begin lint list",
         "end lint list",
         false,
         update_mode == &UpdateMode::Change,
-        || {
-            format!(
-                "pub const ALL_LINTS: [Lint; {}] = {:#?};",
-                sorted_usable_lints.len(),
-                sorted_usable_lints
-            )
-            .lines()
-            .map(ToString::to_string)
-            .collect::<Vec<_>>()
-        },
+        || gen_lint_list(&lint_list),
     )
     .changed;
 
@@ -0,0 +1,273 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, ErrorKind, Write};
+use std::path::Path;
+
+/// Creates the files of a new lint: its module with a skeleton lint pass, and a ui test. The pass
+/// is registered in `clippy_lints/src/lib.rs`, the rest of the registration is left to
+/// `update_lints`.
+///
+/// Nothing is left behind on failure: the paths are checked before anything is written, and the
+/// created files are removed if a later write fails.
+///
+/// # Errors
+///
+/// Returns an error if the name isn't in snake case, if the lint already exists, or if a file
+/// can't be written.
+pub fn create(pass: &str, lint_name: &str, category: &str) -> Result<(), io::Error> {
+    let lint_name = lint_name.replace('-', "_");
+    if !is_snake_case(&lint_name) {
+        return Err(io::Error::new(
+            ErrorKind::InvalidInput,
+            format!("`{}` is not a valid lint name, use snake case", lint_name),
+        ));
+    }
+    let camel_name = camel_case(&lint_name);
+
+    let version = clippy_version()?;
+    let lib_path = "../clippy_lints/src/lib.rs";
+    let lib = fs::read_to_string(lib_path)
+        .and_then(|lib| register_pass(&lib, pass, &lint_name, &camel_name))
+        .map_err(|e| context(e, lib_path))?;
+
+    let module = format!("../clippy_lints/src/{}.rs", lint_name);
+    let test = format!("../tests/ui/{}.rs", lint_name);
+    for path in &[&module, &test] {
+        if Path::new(path).exists() {
+            return Err(context(
+                io::Error::new(ErrorKind::AlreadyExists, "the lint already exists"),
+                path,
+            ));
+        }
+    }
+
+    write_new_files(&[
+        (
+            &module,
+            get_lint_file_contents(pass, &lint_name, &camel_name, category, &version),
+        ),
+        (&test, get_test_file_contents(&lint_name)),
+    ])?;
+    fs::write(lib_path, lib).map_err(|e| {
+        remove_files(&[&module, &test]);
+        context(e, lib_path)
+    })
+}
+
+/// Whether `name` is a valid lint name: lowercase ASCII words separated by single underscores. Keep
+/// in sync with `clippy_lints/src/utils/author.rs`, which generates lints with the same rules.
+fn is_snake_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && !name.ends_with('_')
+        && !name.contains("__")
+}
+
+/// Converts `foo_bar` to `FooBar`, the name of the lint pass. Keep in sync with
+/// `clippy_lints/src/utils/author.rs`.
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// The version of `clippy_lints`, the version the new lint is added in.
+fn clippy_version() -> Result<String, io::Error> {
+    let manifest = fs::read_to_string("../clippy_lints/Cargo.toml")?;
+    manifest
+        .lines()
+        .find(|line| line.starts_with("version = "))
+        .map(|line| line["version = ".len()..].trim_matches('"').to_string())
+        .ok_or_else(|| io::Error::new(ErrorKind::Other, "could not find the version of `clippy_lints`"))
+}
+
+fn context(error: io::Error, path: &str) -> io::Error {
+    io::Error::new(error.kind(), format!("{}: {}", path, error))
+}
+
+fn write_new_file(path: &str, contents: &str) -> Result<(), io::Error> {
+    // `create_new` fails if the file exists, so an existing lint is never overwritten.
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(Path::new(path))?
+        .write_all(contents.as_bytes())
+}
+
+/// Writes the new `files`, removing the ones already written if one of them can't be.
+fn write_new_files(files: &[(&str, String)]) -> Result<(), io::Error> {
+    for (i, (path, contents)) in files.iter().enumerate() {
+        if let Err(e) = write_new_file(path, contents) {
+            // a partially written file is removed too, but not one that already existed
+            let written = files[..i].iter().map(|(path, _)| *path);
+            if e.kind() == ErrorKind::AlreadyExists {
+                remove_files(&written.collect::<Vec<_>>());
+            } else {
+                remove_files(&written.chain(Some(*path)).collect::<Vec<_>>());
+            }
+            return Err(context(e, path));
+        }
+    }
+    Ok(())
+}
+
+/// Removes the files created for the new lint, reporting the ones that can't be removed.
+fn remove_files(paths: &[&str]) {
+    for path in paths {
+        if let Err(e) = fs::remove_file(path) {
+            if e.kind() != ErrorKind::NotFound {
+                eprintln!("error: could not remove `{}`: {}", path, e);
+            }
+        }
+    }
+}
+
+/// Registers the pass after the last pass registered in `register_plugins`, returning the new
+/// content of `lib.rs`.
+fn register_pass(lib: &str, pass: &str, lint_name: &str, camel_name: &str) -> Result<String, io::Error> {
+    let registration = format!(
+        "    store.register_{}_pass(|| box {}::{});\n",
+        pass, lint_name, camel_name
+    );
+
+    let groups = lib
+        .find("\n    store.register_group(true,")
+        .ok_or_else(|| io::Error::new(ErrorKind::Other, "could not find the lint groups in `register_plugins`"))?;
+    let last_pass = lib[..groups]
+        .trim_end()
+        .rfind('\n')
+        .expect("the lint groups aren't on the first line")
+        + 1;
+    let end_of_last_pass = last_pass
+        + lib[last_pass..]
+            .find('\n')
+            .expect("the lint groups follow the last pass")
+        + 1;
+
+    let mut new_lib = String::with_capacity(lib.len() + registration.len());
+    new_lib.push_str(&lib[..end_of_last_pass]);
+    new_lib.push_str(&registration);
+    new_lib.push_str(&lib[end_of_last_pass..]);
+    Ok(new_lib)
+}
+
+fn get_test_file_contents(lint_name: &str) -> String {
+    format!(
+        "#![warn(clippy::{})]
+
+fn main() {{
+    // test code goes here
+}}
+",
+        lint_name
+    )
+}
+
+fn get_lint_file_contents(pass: &str, lint_name: &str, camel_name: &str, category: &str, version: &str) -> String {
+    let (imports, impl_header) = match pass {
+        "early" => (
+            "use rustc::lint::{EarlyLintPass, LintArray, LintPass};
+use rustc::{declare_lint_pass, declare_tool_lint};
+use syntax::ast::*;",
+            format!("impl EarlyLintPass for {}", camel_name),
+        ),
+        _ => (
+            "use rustc::hir::*;
+use rustc::lint::{LateLintPass, LintArray, LintPass};
+use rustc::{declare_lint_pass, declare_tool_lint};",
+            format!("impl<'a, 'tcx> LateLintPass<'a, 'tcx> for {}", camel_name),
+        ),
+    };
+
+    format!(
+        "{imports}
+
+declare_clippy_lint! {{
+    /// **What it does:**
+    ///
+    /// **Why is this bad?**
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// // example code
+    /// ```
+    #[clippy::version = \"{version}\"]
+    pub {name_upper},
+    {category},
+    \"default lint description\"
+}}
+
+declare_lint_pass!({name_camel} => [{name_upper}]);
+
+{impl_header} {{}}
+",
+        imports = imports,
+        name_upper = lint_name.to_uppercase(),
+        category = category,
+        name_camel = camel_name,
+        impl_header = impl_header,
+        version = version,
+    )
+}
+
+#[test]
+fn test_camel_case() {
+    assert_eq!(camel_case("foo"), "Foo");
+    assert_eq!(camel_case("foo_bar2"), "FooBar2");
+    assert!(is_snake_case("foo_bar2"));
+    assert!(!is_snake_case("FooBar"));
+    assert!(!is_snake_case("foo__bar"));
+    assert!(!is_snake_case("_foo"));
+}
+
+#[test]
+fn test_get_lint_file_contents() {
+    let result = get_lint_file_contents("early", "foo_bar", "FooBar", "style", "0.0.212");
+    assert!(result.starts_with("use rustc::lint::{EarlyLintPass, LintArray, LintPass};\n"));
+    assert!(result.contains("    #[clippy::version = \"0.0.212\"]\n    pub FOO_BAR,\n    style,\n"));
+    assert!(result.contains("declare_lint_pass!(FooBar => [FOO_BAR]);"));
+    assert!(result.ends_with("impl EarlyLintPass for FooBar {}\n"));
+
+    let result = get_lint_file_contents("late", "foo_bar", "FooBar", "pedantic", "0.0.212");
+    assert!(result.starts_with("use rustc::hir::*;\nuse rustc::lint::{LateLintPass, LintArray, LintPass};\n"));
+    assert!(result.ends_with("impl<'a, 'tcx> LateLintPass<'a, 'tcx> for FooBar {}\n"));
+}
+
+#[test]
+fn test_register_pass() {
+    let lib = "    store.register_late_pass(|| box a::A);
+    store.register_early_pass(|| box b::B);
+
+    store.register_group(true, \"clippy::all\", Some(\"clippy\"), vec![
+";
+    let expected = "    store.register_late_pass(|| box a::A);
+    store.register_early_pass(|| box b::B);
+    store.register_late_pass(|| box foo_bar::FooBar);
+
+    store.register_group(true, \"clippy::all\", Some(\"clippy\"), vec![
+";
+    assert_eq!(register_pass(lib, "late", "foo_bar", "FooBar").unwrap(), expected);
+    assert!(register_pass("", "late", "foo_bar", "FooBar").is_err());
+}
+
+#[test]
+fn test_write_new_files() {
+    let dir = std::env::temp_dir().join(format!("clippy_dev_write_new_files_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = |name: &str| dir.join(name).to_str().unwrap().to_string();
+    fs::write(path("existing.rs"), "").unwrap();
+
+    let result = write_new_files(&[(&path("new.rs"), String::new()), (&path("existing.rs"), String::new())]);
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::AlreadyExists);
+    assert!(!Path::new(&path("new.rs")).exists());
+    assert!(Path::new(&path("existing.rs")).exists());
+    fs::remove_dir_all(&dir).unwrap();
+}
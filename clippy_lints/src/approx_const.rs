@@ -25,6 +25,7 @@ declare_clippy_lint! {
     /// ```rust
     /// let x = 3.14;
     /// ```
+    #[clippy::version = "0.0.47"]
     pub APPROX_CONSTANT,
     correctness,
     "the approximate of a known float constant (in `std::fXX::consts`)"
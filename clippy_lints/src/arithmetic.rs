@@ -19,6 +19,7 @@ declare_clippy_lint! {
     /// # let a = 0;
     /// a + 1;
     /// ```
+    #[clippy::version = "0.0.65"]
     pub INTEGER_ARITHMETIC,
     restriction,
     "any integer arithmetic statement"
@@ -37,6 +38,7 @@ declare_clippy_lint! {
     /// # let a = 0.0;
     /// a + 1.0;
     /// ```
+    #[clippy::version = "0.0.65"]
     pub FLOAT_ARITHMETIC,
     restriction,
     "any floating-point arithmetic statement"
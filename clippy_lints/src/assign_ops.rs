@@ -26,6 +26,7 @@ declare_clippy_lint! {
     /// // ...
     /// a = a + b;
     /// ```
+    #[clippy::version = "0.0.66"]
     pub ASSIGN_OP_PATTERN,
     style,
     "assigning the result of an operation on a variable to that same variable"
@@ -49,6 +50,7 @@ declare_clippy_lint! {
     /// // ...
     /// a += a + b;
     /// ```
+    #[clippy::version = "0.0.80"]
     pub MISREFACTORED_ASSIGN_OP,
     complexity,
     "having a variable on both sides of an assign op"
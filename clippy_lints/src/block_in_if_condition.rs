@@ -18,6 +18,7 @@ declare_clippy_lint! {
     /// ```rust
     /// if { true } { /* ... */ }
     /// ```
+    #[clippy::version = "0.0.47"]
     pub BLOCK_IN_IF_CONDITION_EXPR,
     style,
     "braces that can be eliminated in conditions, e.g., `if { true } ...`"
@@ -37,6 +38,7 @@ declare_clippy_lint! {
     /// // or
     /// if somefunc(|x| { x == 47 }) {}
     /// ```
+    #[clippy::version = "0.0.47"]
     pub BLOCK_IN_IF_CONDITION_STMT,
     style,
     "complex blocks in conditions, e.g., `if { let x = true; x } ...`"
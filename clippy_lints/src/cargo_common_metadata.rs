@@ -2,6 +2,7 @@
 
 use std::path::PathBuf;
 
+use crate::utils::manifest::Manifest;
 use crate::utils::span_lint;
 use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
 use rustc::{declare_lint_pass, declare_tool_lint};
@@ -31,6 +32,7 @@ declare_clippy_lint! {
     /// keywords = ["clippy", "lint", "plugin"]
     /// categories = ["development-tools", "development-tools::cargo-plugins"]
     /// ```
+    #[clippy::version = "0.0.212"]
     pub CARGO_COMMON_METADATA,
     cargo,
     "common metadata is defined in `Cargo.toml`"
@@ -40,9 +42,22 @@ fn warning(cx: &EarlyContext<'_>, message: &str) {
     span_lint(cx, CARGO_COMMON_METADATA, DUMMY_SP, message);
 }
 
-fn missing_warning(cx: &EarlyContext<'_>, package: &cargo_metadata::Package, field: &str) {
+/// Points at the first of `keys` in `[package]` if it is empty, at `[package]` otherwise.
+fn missing_warning(
+    cx: &EarlyContext<'_>,
+    package: &cargo_metadata::Package,
+    manifest: Option<&Manifest>,
+    keys: &[&str],
+    field: &str,
+) {
     let message = format!("package `{}` is missing `{}` metadata", package.name, field);
-    warning(cx, &message);
+    let span = manifest.map_or(DUMMY_SP, |manifest| {
+        keys.iter()
+            .find_map(|key| manifest.span(&["package", key]))
+            .or_else(|| manifest.span(&["package"]))
+            .unwrap_or_else(|| manifest.start_span())
+    });
+    span_lint(cx, CARGO_COMMON_METADATA, span, &message);
 }
 
 fn is_empty_str(value: &Option<String>) -> bool {
@@ -70,32 +85,41 @@ impl EarlyLintPass for CargoCommonMetadata {
         };
 
         for package in metadata.packages {
+            let manifest = Manifest::load(cx.sess, &package.manifest_path);
+            let manifest = manifest.as_ref();
+
             if is_empty_vec(&package.authors) {
-                missing_warning(cx, &package, "package.authors");
+                missing_warning(cx, &package, manifest, &["authors"], "package.authors");
             }
 
             if is_empty_str(&package.description) {
-                missing_warning(cx, &package, "package.description");
+                missing_warning(cx, &package, manifest, &["description"], "package.description");
             }
 
             if is_empty_str(&package.license) && is_empty_path(&package.license_file) {
-                missing_warning(cx, &package, "either package.license or package.license_file");
+                missing_warning(
+                    cx,
+                    &package,
+                    manifest,
+                    &["license", "license-file"],
+                    "either package.license or package.license_file",
+                );
             }
 
             if is_empty_str(&package.repository) {
-                missing_warning(cx, &package, "package.repository");
+                missing_warning(cx, &package, manifest, &["repository"], "package.repository");
             }
 
             if is_empty_path(&package.readme) {
-                missing_warning(cx, &package, "package.readme");
+                missing_warning(cx, &package, manifest, &["readme"], "package.readme");
             }
 
             if is_empty_vec(&package.keywords) {
-                missing_warning(cx, &package, "package.keywords");
+                missing_warning(cx, &package, manifest, &["keywords"], "package.keywords");
             }
 
             if is_empty_vec(&package.categories) {
-                missing_warning(cx, &package, "package.categories");
+                missing_warning(cx, &package, manifest, &["categories"], "package.categories");
             }
         }
     }
@@ -19,6 +19,7 @@ declare_clippy_lint! {
     /// complexity.
     ///
     /// **Example:** No. You'll see it when you get the warning.
+    #[clippy::version = "0.0.47"]
     pub COGNITIVE_COMPLEXITY,
     complexity,
     "functions that should be split up into multiple functions"
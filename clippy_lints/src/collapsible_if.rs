@@ -66,6 +66,7 @@ declare_clippy_lint! {
     ///     …
     /// }
     /// ```
+    #[clippy::version = "0.0.78"]
     pub COLLAPSIBLE_IF,
     style,
     "`if`s that can be collapsed (e.g., `if x { if y { ... } }` and `else { if x { ... } }`)"
@@ -35,6 +35,7 @@ declare_clippy_lint! {
     ///     …
     /// }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub IFS_SAME_COND,
     correctness,
     "consecutive `ifs` with the same condition"
@@ -56,6 +57,7 @@ declare_clippy_lint! {
     ///     42
     /// };
     /// ```
+    #[clippy::version = "0.0.212"]
     pub IF_SAME_THEN_ELSE,
     correctness,
     "if with the same *then* and *else* blocks"
@@ -97,6 +99,7 @@ declare_clippy_lint! {
     ///     Quz => quz(),
     /// }
     /// ```
+    #[clippy::version = "0.0.59"]
     pub MATCH_SAME_ARMS,
     pedantic,
     "`match` with identical arm bodies"
@@ -22,6 +22,7 @@ declare_clippy_lint! {
     /// // Good
     /// true
     /// ```
+    #[clippy::version = "0.0.212"]
     pub DBG_MACRO,
     restriction,
     "`dbg!` macro is intended as a debugging tool"
@@ -0,0 +1,469 @@
+//! lint on dependencies with licenses or sources that the configured policy disallows
+
+use crate::utils::manifest::{current_package, DependencyGraph, Manifest};
+use crate::utils::{is_allowed_early, span_lint, span_lint_and_then};
+use cargo_metadata::{Metadata, Package};
+use rustc::lint::{EarlyContext, EarlyLintPass, Lint, LintArray, LintPass};
+use rustc::{declare_tool_lint, impl_lint_pass};
+use rustc_data_structures::fx::FxHashSet;
+use std::iter::Peekable;
+use syntax::ast::*;
+use syntax::source_map::{Span, DUMMY_SP};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks the licenses of the dependencies built into the package, direct
+    /// or not, against the `allowed-licenses` and `denied-licenses` configuration. A dependency
+    /// is allowed if one of the alternatives of its SPDX license expression only has allowed
+    /// licenses, e.g. `MIT OR GPL-3.0` is allowed with `allowed-licenses = ["MIT"]`.
+    ///
+    /// **Why is this bad?** The licenses of the dependencies may not be compatible with the
+    /// license of the package, or with how it is distributed.
+    ///
+    /// **Known problems:** A license also matches its `-only`, `-or-later` and `+` variants, e.g.
+    /// `GPL-3.0` matches `GPL-3.0-or-later`. The dependencies without a `license` in their
+    /// `Cargo.toml`, or with an empty one, like those only having a `license-file`, are reported
+    /// when `allowed-licenses` is set.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// # with `denied-licenses = ["GPL-3.0"]` in `clippy.toml`, if `gpl-crate` is
+    /// # licensed under `GPL-3.0-or-later`
+    /// [dependencies]
+    /// gpl-crate = "1"
+    /// ```
+    #[clippy::version = "0.0.212"]
+    pub DISALLOWED_LICENSES,
+    cargo,
+    "dependencies with a license the configuration disallows"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for dependencies built into the package, direct or not, that
+    /// don't come from crates.io or from the workspace, unless their source starts with one of
+    /// the `allowed-sources` configuration, e.g. `"https://github.com/rust-lang/"` for the git
+    /// repositories of an organization, or the directory of path dependencies.
+    ///
+    /// **Why is this bad?** The git and path dependencies aren't published and reviewed like the
+    /// crates of the registry, and may change without the lockfile noticing for the paths.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// [dependencies]
+    /// regex = { git = "https://github.com/rust-lang/regex" }
+    /// ```
+    #[clippy::version = "0.0.212"]
+    pub DISALLOWED_SOURCES,
+    cargo,
+    "dependencies from a git repository or path that the configuration doesn't allow"
+}
+
+#[derive(Clone)]
+pub struct DependencyPolicy {
+    allowed_licenses: FxHashSet<String>,
+    denied_licenses: FxHashSet<String>,
+    allowed_sources: Vec<String>,
+}
+
+impl DependencyPolicy {
+    pub fn new(allowed_licenses: &[String], denied_licenses: &[String], allowed_sources: &[String]) -> Self {
+        Self {
+            allowed_licenses: allowed_licenses
+                .iter()
+                .map(String::as_str)
+                .map(normalize_license)
+                .collect(),
+            denied_licenses: denied_licenses
+                .iter()
+                .map(String::as_str)
+                .map(normalize_license)
+                .collect(),
+            allowed_sources: allowed_sources.to_vec(),
+        }
+    }
+
+    /// The reason `package` is disallowed by the license policy, if it is.
+    fn license_violation(&self, package: &Package) -> Option<String> {
+        if self.allowed_licenses.is_empty() && self.denied_licenses.is_empty() {
+            return None;
+        }
+        // an empty `license` is as good as none
+        let license = match package.license {
+            Some(ref license) if !license.trim().is_empty() => license,
+            _ if self.allowed_licenses.is_empty() => return None,
+            _ => {
+                return Some(match package.license_file {
+                    Some(ref file) => format!("only has a license file, `{}`", file.display()),
+                    None => "has no license".to_string(),
+                })
+            },
+        };
+
+        let is_allowed = |license: &String| {
+            !self.denied_licenses.contains(license)
+                && (self.allowed_licenses.is_empty() || self.allowed_licenses.contains(license))
+        };
+        if license_alternatives(license)
+            .iter()
+            .any(|licenses| licenses.iter().all(is_allowed))
+        {
+            None
+        } else {
+            Some(format!("is licensed under `{}`", license))
+        }
+    }
+
+    /// The reason `package` is disallowed by the source policy, if it is.
+    fn source_violation(&self, metadata: &Metadata, package: &Package) -> Option<String> {
+        let is_allowed = |source: &str| self.allowed_sources.iter().any(|allowed| source.starts_with(allowed));
+        match package.source {
+            Some(ref source) if source.is_crates_io() => None,
+            Some(ref source) => {
+                let source = source.to_string();
+                // the kind of source, like `git+https://...`
+                let url = source.splitn(2, '+').last().unwrap_or_default();
+                if is_allowed(url) {
+                    None
+                } else {
+                    Some(format!("comes from `{}`", source))
+                }
+            },
+            None => {
+                let dir = package.manifest_path.parent()?;
+                if dir.starts_with(&metadata.workspace_root) || is_allowed(&dir.to_string_lossy()) {
+                    None
+                } else {
+                    Some(format!(
+                        "is a path dependency outside of the workspace, `{}`",
+                        dir.display()
+                    ))
+                }
+            },
+        }
+    }
+}
+
+impl_lint_pass!(DependencyPolicy => [DISALLOWED_LICENSES, DISALLOWED_SOURCES]);
+
+impl EarlyLintPass for DependencyPolicy {
+    fn check_crate(&mut self, cx: &EarlyContext<'_>, _: &Crate) {
+        let check_licenses = !(self.allowed_licenses.is_empty() && self.denied_licenses.is_empty())
+            && !is_allowed_early(cx, DISALLOWED_LICENSES);
+        let check_sources = !is_allowed_early(cx, DISALLOWED_SOURCES);
+        if !check_licenses && !check_sources {
+            return;
+        }
+
+        // the lockfile is up to date once cargo runs the compiler
+        let metadata = if let Ok(metadata) = cargo_metadata::MetadataCommand::new()
+            .other_options(vec!["--frozen".to_string()])
+            .exec()
+        {
+            metadata
+        } else {
+            let lint = if check_licenses {
+                DISALLOWED_LICENSES
+            } else {
+                DISALLOWED_SOURCES
+            };
+            span_lint(cx, lint, DUMMY_SP, "could not read cargo metadata");
+            return;
+        };
+
+        let root = if let Some(package) = current_package(&metadata) {
+            package
+        } else {
+            return;
+        };
+        let manifest = Manifest::load(cx.sess, &root.manifest_path);
+        let graph = DependencyGraph::without_dev_dependencies(&metadata, &root.id);
+
+        let mut packages: Vec<&Package> = graph
+            .parents
+            .keys()
+            .map(|id| graph.packages[id])
+            .filter(|package| !metadata.workspace_members.contains(&package.id))
+            .collect();
+        packages.sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+
+        for package in packages {
+            let violations = [
+                (DISALLOWED_LICENSES, check_licenses, self.license_violation(package)),
+                (
+                    DISALLOWED_SOURCES,
+                    check_sources,
+                    self.source_violation(&metadata, package),
+                ),
+            ];
+            for (lint, check, violation) in &violations {
+                if let (true, Some(violation)) = (*check, violation) {
+                    // the direct dependency of the package that brings it in
+                    let span = manifest.as_ref().map_or(DUMMY_SP, |manifest| {
+                        graph
+                            .direct_dependency(&package.id)
+                            .and_then(|dep| manifest.dependency_span(dep))
+                            .or_else(|| manifest.span(&["dependencies"]))
+                            .unwrap_or_else(|| manifest.start_span())
+                    });
+                    report(cx, *lint, span, &graph, package, violation);
+                }
+            }
+        }
+    }
+}
+
+fn report(
+    cx: &EarlyContext<'_>,
+    lint: &'static Lint,
+    span: Span,
+    graph: &DependencyGraph<'_>,
+    package: &Package,
+    violation: &str,
+) {
+    span_lint_and_then(
+        cx,
+        lint,
+        span,
+        &format!("dependency `{} {}` {}", package.name, package.version, violation),
+        |db| {
+            db.note(&format!("it comes from `{}`", graph.path_to(&package.id)));
+        },
+    );
+}
+
+/// Compares licenses ignoring the case and whether later versions are allowed.
+fn normalize_license(license: &str) -> String {
+    let license = license.trim().to_ascii_lowercase();
+    for suffix in &["+", "-only", "-or-later"] {
+        if license.ends_with(suffix) {
+            return license[..license.len() - suffix.len()].to_string();
+        }
+    }
+    license
+}
+
+/// The alternatives of an SPDX license expression, each with the licenses that all apply. The
+/// `/` of the older `MIT/Apache-2.0` expressions means `OR`, and the exceptions of `WITH` are
+/// ignored.
+fn license_alternatives(expression: &str) -> Vec<Vec<String>> {
+    let expression = expression.replace('(', " ( ").replace(')', " ) ").replace('/', " OR ");
+    parse_or(&mut expression.split_whitespace().peekable())
+}
+
+fn parse_or<'a, I: Iterator<Item = &'a str>>(tokens: &mut Peekable<I>) -> Vec<Vec<String>> {
+    let mut alternatives = parse_and(tokens);
+    while tokens.peek().map_or(false, |token| token.eq_ignore_ascii_case("or")) {
+        tokens.next();
+        alternatives.extend(parse_and(tokens));
+    }
+    alternatives
+}
+
+fn parse_and<'a, I: Iterator<Item = &'a str>>(tokens: &mut Peekable<I>) -> Vec<Vec<String>> {
+    let mut alternatives = parse_license(tokens);
+    while tokens.peek().map_or(false, |token| token.eq_ignore_ascii_case("and")) {
+        tokens.next();
+        let right = parse_license(tokens);
+        // a missing operand, like in `MIT AND`, doesn't change the alternatives
+        if right.is_empty() {
+            continue;
+        }
+        alternatives = alternatives
+            .iter()
+            .flat_map(|left| {
+                right
+                    .iter()
+                    .map(move |right| left.iter().chain(right).cloned().collect())
+            })
+            .collect();
+    }
+    alternatives
+}
+
+fn parse_license<'a, I: Iterator<Item = &'a str>>(tokens: &mut Peekable<I>) -> Vec<Vec<String>> {
+    match tokens.next() {
+        Some("(") => {
+            let alternatives = parse_or(tokens);
+            if tokens.peek() == Some(&")") {
+                tokens.next();
+            }
+            alternatives
+        },
+        Some(license) => {
+            if tokens.peek().map_or(false, |token| token.eq_ignore_ascii_case("with")) {
+                tokens.next();
+                tokens.next();
+            }
+            vec![vec![normalize_license(license)]]
+        },
+        None => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{license_alternatives, DependencyPolicy};
+    use crate::utils::manifest::test::{metadata, package};
+    use cargo_metadata::Package;
+    use serde_json::{json, Value};
+    use std::path::PathBuf;
+
+    fn strings(strings: &[&str]) -> Vec<String> {
+        strings.iter().map(ToString::to_string).collect()
+    }
+
+    fn policy(allowed_licenses: &[&str], denied_licenses: &[&str], allowed_sources: &[&str]) -> DependencyPolicy {
+        DependencyPolicy::new(
+            &strings(allowed_licenses),
+            &strings(denied_licenses),
+            &strings(allowed_sources),
+        )
+    }
+
+    /// A package of `cargo metadata` with the fields of `fields` replaced.
+    fn package_with(fields: Value) -> Package {
+        let mut package = package("dep", "1.0.0", Vec::new());
+        for (field, value) in fields.as_object().unwrap() {
+            package[field] = value.clone();
+        }
+        serde_json::from_value(package).unwrap()
+    }
+
+    fn alternatives(expression: &str) -> Vec<String> {
+        license_alternatives(expression)
+            .iter()
+            .map(|licenses| licenses.join(" & "))
+            .collect()
+    }
+
+    #[test]
+    fn denied_licenses() {
+        let policy = policy(&["MIT", "GPL-3.0"], &["GPL-3.0-or-later"], &[]);
+        let violation = |license: &str| policy.license_violation(&package_with(json!({ "license": license })));
+
+        assert_eq!(violation("MIT"), None);
+        assert_eq!(violation("MIT OR GPL-3.0"), None);
+        assert_eq!(violation("GPL-3.0"), Some("is licensed under `GPL-3.0`".to_string()));
+        assert_eq!(
+            violation("MIT AND GPL-3.0+"),
+            Some("is licensed under `MIT AND GPL-3.0+`".to_string())
+        );
+        assert_eq!(
+            violation("Apache-2.0"),
+            Some("is licensed under `Apache-2.0`".to_string())
+        );
+    }
+
+    #[test]
+    fn missing_licenses() {
+        let license_file = package_with(json!({ "license": null, "license_file": "LICENSE.txt" }));
+        let empty = package_with(json!({ "license": "" }));
+        let missing = package_with(json!({ "license": null }));
+
+        let allowed = policy(&["MIT"], &[], &[]);
+        assert_eq!(
+            allowed.license_violation(&license_file),
+            Some("only has a license file, `LICENSE.txt`".to_string())
+        );
+        assert_eq!(allowed.license_violation(&empty), Some("has no license".to_string()));
+        assert_eq!(allowed.license_violation(&missing), Some("has no license".to_string()));
+
+        // only the denied licenses are known to be disallowed
+        let denied = policy(&[], &["GPL-3.0"], &[]);
+        assert_eq!(denied.license_violation(&license_file), None);
+        assert_eq!(denied.license_violation(&empty), None);
+    }
+
+    #[test]
+    fn sources() {
+        let mut metadata = metadata(&[package("app", "0.1.0", Vec::new())], &[]);
+        metadata.workspace_root = PathBuf::from("/ws");
+        let git = package_with(json!({ "source": "git+https://github.com/rust-lang/regex#0123abc" }));
+        let registry = package_with(json!({ "source": "registry+https://registry.example.com/index" }));
+        let inside = package_with(json!({ "source": null, "manifest_path": "/ws/dep/Cargo.toml" }));
+        let outside = package_with(json!({ "source": null, "manifest_path": "/vendor/dep/Cargo.toml" }));
+
+        let allowed = policy(&[], &[], &["https://github.com/rust-lang/", "/vendor/"]);
+        assert_eq!(allowed.source_violation(&metadata, &package_with(json!({}))), None);
+        assert_eq!(allowed.source_violation(&metadata, &git), None);
+        assert_eq!(
+            allowed.source_violation(&metadata, &registry),
+            Some("comes from `registry+https://registry.example.com/index`".to_string())
+        );
+        assert_eq!(allowed.source_violation(&metadata, &inside), None);
+        assert_eq!(allowed.source_violation(&metadata, &outside), None);
+
+        let strict = policy(&[], &[], &[]);
+        assert_eq!(
+            strict.source_violation(&metadata, &git),
+            Some("comes from `git+https://github.com/rust-lang/regex#0123abc`".to_string())
+        );
+        assert_eq!(
+            strict.source_violation(&metadata, &outside),
+            Some("is a path dependency outside of the workspace, `/vendor/dep`".to_string())
+        );
+    }
+
+    #[test]
+    fn single() {
+        assert_eq!(alternatives("MIT"), ["mit"]);
+        assert_eq!(alternatives(" Apache-2.0 "), ["apache-2.0"]);
+        assert_eq!(alternatives("GPL-3.0+"), ["gpl-3.0"]);
+        assert_eq!(alternatives("GPL-3.0-or-later"), ["gpl-3.0"]);
+        assert_eq!(alternatives("LGPL-2.1-only"), ["lgpl-2.1"]);
+    }
+
+    #[test]
+    fn or() {
+        assert_eq!(alternatives("MIT OR Apache-2.0"), ["mit", "apache-2.0"]);
+        assert_eq!(alternatives("MIT/Apache-2.0"), ["mit", "apache-2.0"]);
+        assert_eq!(alternatives("MIT or Unlicense/Zlib"), ["mit", "unlicense", "zlib"]);
+    }
+
+    #[test]
+    fn and() {
+        assert_eq!(alternatives("MIT AND BSD-3-Clause"), ["mit & bsd-3-clause"]);
+        // `AND` binds tighter than `OR`
+        assert_eq!(
+            alternatives("MIT OR Apache-2.0 AND BSD-3-Clause"),
+            ["mit", "apache-2.0 & bsd-3-clause"]
+        );
+    }
+
+    #[test]
+    fn parentheses() {
+        assert_eq!(
+            alternatives("(MIT OR Apache-2.0) AND Unicode-DFS-2016"),
+            ["mit & unicode-dfs-2016", "apache-2.0 & unicode-dfs-2016"]
+        );
+        assert_eq!(
+            alternatives("(MIT OR Apache-2.0) AND (BSD-2-Clause OR Zlib)"),
+            [
+                "mit & bsd-2-clause",
+                "mit & zlib",
+                "apache-2.0 & bsd-2-clause",
+                "apache-2.0 & zlib"
+            ]
+        );
+        assert_eq!(alternatives("((MIT))"), ["mit"]);
+        // a missing closing parenthesis closes at the end
+        assert_eq!(alternatives("(MIT OR Zlib"), ["mit", "zlib"]);
+    }
+
+    #[test]
+    fn with() {
+        assert_eq!(alternatives("Apache-2.0 WITH LLVM-exception"), ["apache-2.0"]);
+        assert_eq!(
+            alternatives("GPL-2.0+ WITH Classpath-exception-2.0 OR MIT"),
+            ["gpl-2.0", "mit"]
+        );
+    }
+
+    #[test]
+    fn missing_operand() {
+        assert_eq!(alternatives("MIT AND"), ["mit"]);
+        assert_eq!(alternatives("MIT OR"), ["mit"]);
+        assert!(alternatives("").is_empty());
+    }
+}
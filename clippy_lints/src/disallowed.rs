@@ -0,0 +1,299 @@
+use crate::utils::conf::DisallowedPath;
+use crate::utils::{match_def_path, path_to_res, span_lint_and_then};
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, Lint, LintArray, LintPass};
+use rustc::{declare_tool_lint, impl_lint_pass};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use syntax::source_map::Span;
+use syntax::symbol::Symbol;
+use syntax_expand::base::MacroKind;
+use syntax_expand::hygiene::ExpnKind;
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for uses of the functions and methods listed in the
+    /// `disallowed-methods` configuration.
+    ///
+    /// **Why is this bad?** Some functions shouldn't be used in a project, e.g. `std::env::set_var`
+    /// in multithreaded code, or `std::time::Instant::now` in code that must be deterministic.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// With this configuration:
+    /// ```toml
+    /// disallowed-methods = [
+    ///     "std::env::set_var",
+    ///     { path = "std::time::Instant::now", reason = "use the simulated clock" },
+    /// ]
+    /// ```
+    ///
+    /// ```rust
+    /// let start = std::time::Instant::now();
+    /// ```
+    #[clippy::version = "0.0.212"]
+    pub DISALLOWED_METHOD,
+    style,
+    "use of a function or method disallowed in the configuration"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for uses of the types listed in the `disallowed-types`
+    /// configuration.
+    ///
+    /// **Why is this bad?** Some types shouldn't be used in a project, e.g.
+    /// `std::collections::HashMap` when the iteration order must be deterministic.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// With this configuration:
+    /// ```toml
+    /// disallowed-types = [
+    ///     { path = "std::collections::HashMap", reason = "the iteration order is random" },
+    /// ]
+    /// ```
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// ```
+    #[clippy::version = "0.0.212"]
+    pub DISALLOWED_TYPE,
+    style,
+    "use of a type disallowed in the configuration"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for uses of the macros listed in the `disallowed-macros`
+    /// configuration.
+    ///
+    /// **Why is this bad?** Some macros shouldn't be used in a project, e.g. `println!` in a
+    /// library that logs its output.
+    ///
+    /// **Known problems:** Macros of the current crate can't be resolved from their path, they are
+    /// matched by their name only.
+    ///
+    /// **Example:**
+    ///
+    /// With this configuration:
+    /// ```toml
+    /// disallowed-macros = [{ path = "std::println", reason = "use the logger" }]
+    /// ```
+    ///
+    /// ```rust
+    /// println!("done");
+    /// ```
+    #[clippy::version = "0.0.212"]
+    pub DISALLOWED_MACRO,
+    style,
+    "use of a macro disallowed in the configuration"
+}
+
+/// The entries of one of the `disallowed-*` lists.
+#[derive(Clone, Debug)]
+struct DisallowedPaths {
+    entries: Vec<DisallowedPath>,
+    /// The definitions the entries resolve to, with the index of their entry. Resolving the paths
+    /// finds the items through their re-exports, e.g. `std::collections::HashMap`.
+    def_ids: FxHashMap<DefId, usize>,
+}
+
+impl DisallowedPaths {
+    fn new(entries: Vec<DisallowedPath>) -> Self {
+        Self {
+            entries,
+            def_ids: FxHashMap::default(),
+        }
+    }
+
+    fn resolve(&mut self, cx: &LateContext<'_, '_>) {
+        for (index, entry) in self.entries.iter().enumerate() {
+            let segments: Vec<&str> = entry.path().split("::").collect();
+            if let Some(def_id) = path_to_res(cx, &segments).and_then(|res| res.opt_def_id()) {
+                self.def_ids.insert(def_id, index);
+            }
+        }
+    }
+
+    /// Finds the entry of `def_id`, either from the resolved paths or from its definition path,
+    /// which names the associated items of a type, e.g. `std::time::Instant::now`.
+    fn find(&self, cx: &LateContext<'_, '_>, def_id: DefId) -> Option<&DisallowedPath> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        if let Some(&index) = self.def_ids.get(&def_id) {
+            return Some(&self.entries[index]);
+        }
+
+        self.entries.iter().find(|entry| {
+            let segments: Vec<&str> = entry.path().split("::").collect();
+            match_def_path(cx, def_id, &segments)
+        })
+    }
+}
+
+fn emit(cx: &LateContext<'_, '_>, lint: &'static Lint, span: Span, kind: &str, entry: &DisallowedPath) {
+    span_lint_and_then(
+        cx,
+        lint,
+        span,
+        &format!("use of a disallowed {} `{}`", kind, entry.path()),
+        |db| {
+            if let Some(reason) = entry.reason() {
+                db.note(reason);
+            }
+        },
+    );
+}
+
+#[derive(Clone, Debug)]
+pub struct DisallowedMethod {
+    paths: DisallowedPaths,
+}
+
+impl DisallowedMethod {
+    pub fn new(disallowed: Vec<DisallowedPath>) -> Self {
+        Self {
+            paths: DisallowedPaths::new(disallowed),
+        }
+    }
+}
+
+impl_lint_pass!(DisallowedMethod => [DISALLOWED_METHOD]);
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DisallowedMethod {
+    fn check_crate(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        self.paths.resolve(cx);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        let def_id = match expr.kind {
+            ExprKind::MethodCall(..) => cx.tables.type_dependent_def_id(expr.hir_id),
+            ExprKind::Path(ref qpath) => cx.tables.qpath_res(qpath, expr.hir_id).opt_def_id(),
+            _ => None,
+        };
+        if let Some(entry) = def_id.and_then(|def_id| self.paths.find(cx, def_id)) {
+            emit(cx, DISALLOWED_METHOD, expr.span, "method", entry);
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DisallowedType {
+    paths: DisallowedPaths,
+}
+
+impl DisallowedType {
+    pub fn new(disallowed: Vec<DisallowedPath>) -> Self {
+        Self {
+            paths: DisallowedPaths::new(disallowed),
+        }
+    }
+
+    fn check_path(&self, cx: &LateContext<'_, '_>, path: &Path) {
+        if let Some(entry) = path.res.opt_def_id().and_then(|def_id| self.paths.find(cx, def_id)) {
+            emit(cx, DISALLOWED_TYPE, path.span, "type", entry);
+        }
+    }
+}
+
+impl_lint_pass!(DisallowedType => [DISALLOWED_TYPE]);
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DisallowedType {
+    fn check_crate(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        self.paths.resolve(cx);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if let ItemKind::Use(ref path, UseKind::Single) = item.kind {
+            self.check_path(cx, path);
+        }
+    }
+
+    fn check_ty(&mut self, cx: &LateContext<'a, 'tcx>, ty: &'tcx Ty) {
+        if let TyKind::Path(QPath::Resolved(_, ref path)) = ty.kind {
+            self.check_path(cx, path);
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DisallowedMacro {
+    paths: DisallowedPaths,
+    /// The call sites already linted, as every node of an expansion leads to the same call.
+    linted: FxHashSet<Span>,
+}
+
+impl DisallowedMacro {
+    pub fn new(disallowed: Vec<DisallowedPath>) -> Self {
+        Self {
+            paths: DisallowedPaths::new(disallowed),
+            linted: FxHashSet::default(),
+        }
+    }
+
+    /// Lints the disallowed macros `span` was expanded from.
+    fn check(&mut self, cx: &LateContext<'_, '_>, mut span: Span) {
+        if self.paths.entries.is_empty() {
+            return;
+        }
+
+        while span.from_expansion() {
+            let data = span.ctxt().outer_expn_data();
+            if let ExpnKind::Macro(MacroKind::Bang, name) = data.kind {
+                if let Some(entry) = self.find(cx, name, data.def_site) {
+                    if self.linted.insert(data.call_site) {
+                        emit(cx, DISALLOWED_MACRO, data.call_site, "macro", entry);
+                    }
+                }
+            }
+            span = data.call_site;
+        }
+    }
+
+    /// Finds the entry of the macro `name` defined at `def_site`. The macros don't have a `DefId`
+    /// here, so the resolved paths are matched by the span of their definition.
+    fn find(&self, cx: &LateContext<'_, '_>, name: Symbol, def_site: Span) -> Option<&DisallowedPath> {
+        self.paths.entries.iter().enumerate().find_map(|(index, entry)| {
+            if entry.path().rsplit("::").next() != Some(&*name.as_str()) {
+                return None;
+            }
+            let mut def_ids = self.paths.def_ids.iter().filter(|&(_, &i)| i == index).peekable();
+            if def_ids.peek().is_none() || def_ids.any(|(&def_id, _)| cx.tcx.def_span(def_id) == def_site) {
+                Some(entry)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl_lint_pass!(DisallowedMacro => [DISALLOWED_MACRO]);
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DisallowedMacro {
+    fn check_crate(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        self.paths.resolve(cx);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        self.check(cx, item.span);
+    }
+
+    fn check_stmt(&mut self, cx: &LateContext<'a, 'tcx>, stmt: &'tcx Stmt) {
+        self.check(cx, stmt.span);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        self.check(cx, expr.span);
+    }
+
+    fn check_pat(&mut self, cx: &LateContext<'a, 'tcx>, pat: &'tcx Pat) {
+        self.check(cx, pat.span);
+    }
+
+    fn check_ty(&mut self, cx: &LateContext<'a, 'tcx>, ty: &'tcx Ty) {
+        self.check(cx, ty.span);
+    }
+}
@@ -18,6 +18,7 @@ declare_clippy_lint! {
     /// ```rust
     /// enum Test {}
     /// ```
+    #[clippy::version = "0.0.212"]
     pub EMPTY_ENUM,
     pedantic,
     "enum with no variants"
@@ -20,6 +20,7 @@ declare_clippy_lint! {
     /// ```rust
     /// use std::cmp::Ordering::*;
     /// ```
+    #[clippy::version = "0.0.47"]
     pub ENUM_GLOB_USE,
     pedantic,
     "use items that import all variants of an enum"
@@ -21,6 +21,7 @@ declare_clippy_lint! {
     /// # let x = 1;
     /// if x + 1 == x + 1 {}
     /// ```
+    #[clippy::version = "0.0.47"]
     pub EQ_OP,
     correctness,
     "equal operands on both sides of a comparison or bitwise combination (e.g., `x == x`)"
@@ -39,6 +40,7 @@ declare_clippy_lint! {
     /// ```ignore
     /// &x == y
     /// ```
+    #[clippy::version = "0.0.122"]
     pub OP_REF,
     style,
     "taking a reference to satisfy the type constraints on `==`"
@@ -33,6 +33,7 @@ declare_clippy_lint! {
     /// foo(*x);
     /// println!("{}", *x);
     /// ```
+    #[clippy::version = "0.0.212"]
     pub BOXED_LOCAL,
     perf,
     "using `Box<T>` where unnecessary"
@@ -26,6 +26,7 @@ declare_clippy_lint! {
     /// } + x;
     /// // Unclear whether a is 1 or 2.
     /// ```
+    #[clippy::version = "0.0.81"]
     pub EVAL_ORDER_DEPENDENCE,
     complexity,
     "whether a variable read occurs before a write depends on sub-expression evaluation order"
@@ -50,6 +51,7 @@ declare_clippy_lint! {
     /// let x = (a, b, c, panic!());
     /// // can simply be replaced by `panic!()`
     /// ```
+    #[clippy::version = "0.0.212"]
     pub DIVERGING_SUB_EXPRESSION,
     complexity,
     "whether an expression contains a diverging sub expression"
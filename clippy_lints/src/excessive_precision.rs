@@ -30,6 +30,7 @@ declare_clippy_lint! {
     /// let v: f64 = 0.123_456_789_9;
     /// println!("{}", v); //  0.123_456_789_9
     /// ```
+    #[clippy::version = "0.0.212"]
     pub EXCESSIVE_PRECISION,
     style,
     "excessive precision for float literal"
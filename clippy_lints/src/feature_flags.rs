@@ -0,0 +1,320 @@
+//! lint on the features of `Cargo.toml` and the `cfg(feature = "...")` attributes checking them
+
+use crate::utils::manifest::{current_package, is_only_target, Manifest};
+use crate::utils::{is_allowed_early, span_help_and_lint, span_lint};
+use cargo_metadata::Package;
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, impl_lint_pass};
+use rustc_data_structures::fx::FxHashSet;
+use syntax::ast::*;
+use syntax::source_map::{Span, DUMMY_SP};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for `cfg(feature = "...")` and `cfg_attr(feature = "...", ..)`
+    /// attributes with features that the `Cargo.toml` of the package doesn't declare, neither in
+    /// `[features]` nor as an optional dependency.
+    ///
+    /// **Why is this bad?** The feature can never be enabled, so the code is never compiled.
+    /// It's usually a typo, or a feature that was renamed.
+    ///
+    /// **Known problems:** The features in `cfg!` macros and the crate level `cfg_attr`
+    /// attributes aren't checked.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// // with `[features] serde-support = ["serde"]` in `Cargo.toml`
+    /// #[cfg(feature = "serde_support")]
+    /// mod serialize;
+    /// ```
+    #[clippy::version = "0.0.212"]
+    pub UNDECLARED_FEATURES,
+    cargo,
+    "`cfg` attributes with features that `Cargo.toml` doesn't declare"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for features of `Cargo.toml` that no `cfg` attribute of the
+    /// crate checks and that enable nothing else, i.e. with an empty list like `foo = []`.
+    ///
+    /// **Why is this bad?** Enabling the feature does nothing.
+    ///
+    /// **Known problems:** Only the packages with a single library or binary target are checked,
+    /// as each target is a different crate. The features checked by `cfg!` macros, by the build
+    /// script, or only in the files of modules that another `cfg` disables, are reported too.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// # the crate has no `cfg(feature = "nightly")`
+    /// [features]
+    /// nightly = []
+    /// ```
+    #[clippy::version = "0.0.212"]
+    pub UNUSED_FEATURES,
+    cargo,
+    "features that no `cfg` attribute checks and that enable nothing"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for features named like `no-std` or `no_foo`, that disable
+    /// something instead of enabling it.
+    ///
+    /// **Why is this bad?** Features are additive: cargo enables a feature of a crate if any
+    /// crate of the dependency graph enables it, so a crate can't rely on a feature not being
+    /// enabled. Disabling `std` with a `no-std` feature breaks the crates that need it, a
+    /// default `std` feature doesn't.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// [features]
+    /// no-std = []
+    /// ```
+    /// Could be written as:
+    /// ```toml
+    /// [features]
+    /// default = ["std"]
+    /// std = []
+    /// ```
+    #[clippy::version = "0.0.212"]
+    pub NEGATIVE_FEATURE_NAMES,
+    cargo,
+    "features named like `no-std`, that disable something"
+}
+
+const NEGATIVE_PREFIXES: [&str; 4] = ["no-", "no_", "not-", "not_"];
+
+#[derive(Default)]
+pub struct FeatureFlags {
+    /// The features of the package and its optional dependencies, `None` outside of cargo.
+    declared: Option<FxHashSet<String>>,
+    /// The features to report if no `cfg` checks them, with their span in `Cargo.toml`.
+    unused: Vec<(String, Span)>,
+    used: FxHashSet<String>,
+}
+
+impl_lint_pass!(FeatureFlags => [UNDECLARED_FEATURES, UNUSED_FEATURES, NEGATIVE_FEATURE_NAMES]);
+
+impl EarlyLintPass for FeatureFlags {
+    fn check_crate(&mut self, cx: &EarlyContext<'_>, _: &Crate) {
+        if [UNDECLARED_FEATURES, UNUSED_FEATURES, NEGATIVE_FEATURE_NAMES]
+            .iter()
+            .all(|&lint| is_allowed_early(cx, lint))
+        {
+            return;
+        }
+
+        let metadata = if let Ok(metadata) = cargo_metadata::MetadataCommand::new().no_deps().exec() {
+            metadata
+        } else {
+            span_lint(cx, UNDECLARED_FEATURES, DUMMY_SP, "could not read cargo metadata");
+            return;
+        };
+        let package = if let Some(package) = current_package(&metadata) {
+            package
+        } else {
+            return;
+        };
+        let manifest = Manifest::load(cx.sess, &package.manifest_path);
+        let span = |feature: &str| {
+            manifest.as_ref().map_or(DUMMY_SP, |manifest| {
+                manifest
+                    .span(&["features", feature])
+                    .or_else(|| manifest.span(&["features"]))
+                    .unwrap_or_else(|| manifest.start_span())
+            })
+        };
+
+        let only_target = is_only_target(cx.sess, package);
+        let mut features: Vec<_> = package.features.iter().collect();
+        features.sort();
+        for (feature, enables) in features {
+            if is_negative(feature) {
+                span_help_and_lint(
+                    cx,
+                    NEGATIVE_FEATURE_NAMES,
+                    span(feature),
+                    &format!("the name of feature `{}` is negative", feature),
+                    "features should enable something, consider a feature enabling it by default instead",
+                );
+            }
+            if enables.is_empty() && feature != "default" && only_target {
+                self.unused.push((feature.clone(), span(feature)));
+            }
+        }
+
+        self.declared = Some(declared_features(package));
+    }
+
+    fn check_attribute(&mut self, cx: &EarlyContext<'_>, attr: &Attribute) {
+        for (feature, span) in checked_features(attr) {
+            if self
+                .declared
+                .as_ref()
+                .map_or(false, |declared| !declared.contains(&feature))
+            {
+                span_lint(
+                    cx,
+                    UNDECLARED_FEATURES,
+                    span,
+                    &format!("feature `{}` isn't declared in `Cargo.toml`", feature),
+                );
+            }
+            self.used.insert(feature);
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &EarlyContext<'_>, _: &Crate) {
+        for (feature, span) in &self.unused {
+            if !self.used.contains(feature) {
+                span_lint(
+                    cx,
+                    UNUSED_FEATURES,
+                    *span,
+                    &format!("feature `{}` is never checked and enables nothing", feature),
+                );
+            }
+        }
+    }
+}
+
+/// The features of `package`, with its optional dependencies, as an optional dependency is a
+/// feature enabling it.
+fn declared_features(package: &Package) -> FxHashSet<String> {
+    let optional = package
+        .dependencies
+        .iter()
+        .filter(|dependency| dependency.optional)
+        .map(|dependency| dependency.rename.as_ref().unwrap_or(&dependency.name));
+    package.features.keys().chain(optional).cloned().collect()
+}
+
+/// Returns `true` if the name of the feature says that it disables something, like `no-std`.
+fn is_negative(feature: &str) -> bool {
+    NEGATIVE_PREFIXES.iter().any(|prefix| feature.starts_with(prefix))
+}
+
+/// The features that a `cfg` or `cfg_attr` attribute checks, with the spans of their predicates.
+fn checked_features(attr: &Attribute) -> Vec<(String, Span)> {
+    /// Collects the features of a predicate, like `all(unix, feature = "foo")`.
+    fn collect(item: &NestedMetaItem, features: &mut Vec<(String, Span)>) {
+        let item = if let Some(item) = item.meta_item() {
+            item
+        } else {
+            return;
+        };
+        if item.check_name(sym!(feature)) {
+            if let Some(feature) = item.value_str() {
+                features.push((feature.to_string(), item.span));
+            }
+        } else if let Some(items) = item.meta_item_list() {
+            for item in items {
+                collect(item, features);
+            }
+        }
+    }
+
+    let mut features = Vec::new();
+    if let Some(items) = attr.meta_item_list() {
+        if attr.check_name(sym!(cfg)) {
+            for item in &items {
+                collect(item, &mut features);
+            }
+        } else if attr.check_name(sym!(cfg_attr)) {
+            // the other items are the attributes to apply
+            if let Some(item) = items.first() {
+                collect(item, &mut features);
+            }
+        }
+    }
+    features
+}
+
+#[cfg(test)]
+mod test {
+    use super::{checked_features, declared_features, is_negative};
+    use crate::utils::manifest::test::{dependency, package};
+    use cargo_metadata::Package;
+    use serde_json::json;
+    use syntax::ast::{Attribute, Ident, NestedMetaItem};
+    use syntax::attr;
+    use syntax::source_map::DUMMY_SP;
+    use syntax::symbol::Symbol;
+
+    #[test]
+    fn checked() {
+        syntax::with_default_globals(|| {
+            let ident = Ident::from_str;
+            let feature = |name| {
+                NestedMetaItem::MetaItem(attr::mk_name_value_item_str(
+                    ident("feature"),
+                    Symbol::intern(name),
+                    DUMMY_SP,
+                ))
+            };
+            let word = |name| attr::mk_nested_word_item(ident(name));
+            let list = |name, items| NestedMetaItem::MetaItem(attr::mk_list_item(ident(name), items));
+            let features = |name, items| {
+                let attribute: Attribute = attr::mk_attr_outer(attr::mk_list_item(ident(name), items));
+                checked_features(&attribute)
+                    .into_iter()
+                    .map(|(feature, _)| feature)
+                    .collect::<Vec<_>>()
+            };
+
+            assert_eq!(features("cfg", vec![feature("a")]), ["a"]);
+            assert_eq!(
+                features(
+                    "cfg",
+                    vec![list(
+                        "all",
+                        vec![
+                            word("unix"),
+                            list("not", vec![list("any", vec![feature("b"), feature("c")])])
+                        ]
+                    )]
+                ),
+                ["b", "c"]
+            );
+            // only the predicate of `cfg_attr`, not the attributes it applies
+            assert_eq!(
+                features("cfg_attr", vec![feature("d"), list("cfg", vec![feature("e")])]),
+                ["d"]
+            );
+            assert_eq!(
+                features("cfg_attr", vec![list("not", vec![feature("f")]), word("inline")]),
+                ["f"]
+            );
+            assert!(features("cfg", vec![word("test")]).is_empty());
+            assert!(features("allow", vec![feature("g")]).is_empty());
+        });
+    }
+
+    #[test]
+    fn declared() {
+        let mut optional = dependency("serde", "^1");
+        optional["optional"] = json!(true);
+        let mut renamed = dependency("serde_json", "^1");
+        renamed["optional"] = json!(true);
+        renamed["rename"] = json!("json");
+        let mut package = package("a", "0.1.0", vec![dependency("regex", "^1"), optional, renamed]);
+        package["features"] = json!({ "default": ["std"], "std": [], "derive": ["serde"] });
+        let package: Package = serde_json::from_value(package).unwrap();
+
+        let mut declared: Vec<_> = declared_features(&package).into_iter().collect();
+        declared.sort();
+        assert_eq!(declared, ["default", "derive", "json", "serde", "std"]);
+    }
+
+    #[test]
+    fn negative() {
+        assert!(is_negative("no-std"));
+        assert!(is_negative("no_panic"));
+        assert!(is_negative("not-send"));
+        assert!(is_negative("not_sync"));
+        assert!(!is_negative("std"));
+        assert!(!is_negative("nostd"));
+        assert!(!is_negative("notify"));
+        assert!(!is_negative("nonblocking"));
+    }
+}
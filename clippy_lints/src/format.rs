@@ -29,6 +29,7 @@ declare_clippy_lint! {
     /// format!("foo");
     /// format!("{}", foo);
     /// ```
+    #[clippy::version = "0.0.212"]
     pub USELESS_FORMAT,
     complexity,
     "useless use of `format!`"
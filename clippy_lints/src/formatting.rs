@@ -17,6 +17,7 @@ declare_clippy_lint! {
     /// ```rust,ignore
     /// a =- 42; // confusing, should it be `a -= 42` or `a = -42`?
     /// ```
+    #[clippy::version = "0.0.212"]
     pub SUSPICIOUS_ASSIGNMENT_FORMATTING,
     style,
     "suspicious formatting of `*=`, `-=` or `!=`"
@@ -39,6 +40,7 @@ declare_clippy_lint! {
     /// if foo &&! bar { // this should be `foo && !bar` but looks like a different operator
     /// }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub SUSPICIOUS_UNARY_OP_FORMATTING,
     style,
     "suspicious formatting of unary `-` or `!` on the RHS of a BinOp"
@@ -75,6 +77,7 @@ declare_clippy_lint! {
     /// if bar { // this is the `else` block of the previous `if`, but should it be?
     /// }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub SUSPICIOUS_ELSE_FORMATTING,
     style,
     "suspicious formatting of `else`"
@@ -95,6 +98,7 @@ declare_clippy_lint! {
     ///     -4, -5, -6
     /// ];
     /// ```
+    #[clippy::version = "0.0.212"]
     pub POSSIBLE_MISSING_COMMA,
     correctness,
     "possible missing comma in array"
@@ -18,6 +18,7 @@ declare_clippy_lint! {
     ///
     /// iter::repeat(1_u8).collect::<Vec<_>>();
     /// ```
+    #[clippy::version = "0.0.155"]
     pub INFINITE_ITER,
     correctness,
     "infinite iteration"
@@ -37,6 +38,7 @@ declare_clippy_lint! {
     /// let infinite_iter = 0..;
     /// [0..].iter().zip(infinite_iter.take_while(|x| *x > 5));
     /// ```
+    #[clippy::version = "0.0.155"]
     pub MAYBE_INFINITE_ITER,
     pedantic,
     "possible infinite iteration"
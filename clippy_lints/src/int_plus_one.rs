@@ -29,6 +29,7 @@ declare_clippy_lint! {
     /// # let y = 1;
     /// if x > y {}
     /// ```
+    #[clippy::version = "0.0.164"]
     pub INT_PLUS_ONE,
     complexity,
     "instead of using x >= y + 1, use x > y"
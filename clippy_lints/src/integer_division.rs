@@ -20,6 +20,7 @@ declare_clippy_lint! {
     ///     println!("{}", x);
     /// }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub INTEGER_DIVISION,
     restriction,
     "integer division may cause loss of precision"
@@ -24,6 +24,7 @@ declare_clippy_lint! {
     ///     B([i32; 8000]),
     /// }
     /// ```
+    #[clippy::version = "0.0.113"]
     pub LARGE_ENUM_VARIANT,
     perf,
     "large size difference between variants on an enum"
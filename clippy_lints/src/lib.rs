@@ -29,6 +29,8 @@ extern crate rustc_errors;
 #[allow(unused_extern_crates)]
 extern crate rustc_index;
 #[allow(unused_extern_crates)]
+extern crate rustc_metadata;
+#[allow(unused_extern_crates)]
 extern crate rustc_mir;
 #[allow(unused_extern_crates)]
 extern crate rustc_target;
@@ -171,7 +173,9 @@ pub mod copies;
 pub mod copy_iterator;
 pub mod dbg_macro;
 pub mod default_trait_access;
+pub mod dependency_policy;
 pub mod derive;
+pub mod disallowed;
 pub mod doc;
 pub mod double_comparison;
 pub mod double_parens;
@@ -192,6 +196,7 @@ pub mod eval_order_dependence;
 pub mod excessive_precision;
 pub mod explicit_write;
 pub mod fallible_impl_from;
+pub mod feature_flags;
 pub mod format;
 pub mod formatting;
 pub mod functions;
@@ -281,6 +286,7 @@ pub mod try_err;
 pub mod types;
 pub mod unicode;
 pub mod unsafe_removed_from_name;
+pub mod unused_dependencies;
 pub mod unused_io_amount;
 pub mod unused_label;
 pub mod unused_self;
@@ -288,11 +294,13 @@ pub mod unwrap;
 pub mod use_self;
 pub mod vec;
 pub mod wildcard_dependencies;
+pub mod workspace;
 pub mod write;
 pub mod zero_div_zero;
 // end lints modules, do not remove this comment, it’s used in `update_lints`
 
 pub use crate::utils::conf::Conf;
+pub use crate::utils::plugins::PLUGIN_VERSION;
 
 mod reexport {
     crate use syntax::ast::Name;
@@ -307,6 +315,7 @@ mod reexport {
 ///
 /// Used in `./src/driver.rs`.
 pub fn register_pre_expansion_lints(store: &mut rustc::lint::LintStore, conf: &Conf) {
+    let store = &mut utils::profile::ProfilingStore::new(store);
     store.register_pre_expansion_pass(|| box write::Write);
     store.register_pre_expansion_pass(|| box redundant_field_names::RedundantFieldNames);
     let single_char_binding_names_threshold = conf.single_char_binding_names_threshold;
@@ -315,6 +324,19 @@ pub fn register_pre_expansion_lints(store: &mut rustc::lint::LintStore, conf: &C
     });
     store.register_pre_expansion_pass(|| box attrs::DeprecatedCfgAttribute);
     store.register_pre_expansion_pass(|| box dbg_macro::DbgMacro);
+    store.register_pre_expansion_pass(|| box feature_flags::FeatureFlags::default());
+}
+
+/// Register the pass writing the facts of the workspace lints to `dir`, which `cargo clippy` only
+/// sets when one of these lints is enabled.
+///
+/// Used in `./src/driver.rs`.
+pub fn register_workspace_facts(store: &mut rustc::lint::LintStore, sess: &Session, dir: std::path::PathBuf) {
+    // only the crates of the workspace, not the dependencies with capped lints
+    if sess.opts.lint_cap != Some(lint::Level::Allow) {
+        let store = &mut utils::profile::ProfilingStore::new(store);
+        store.register_late_pass(move || box workspace::WorkspaceFacts::new(dir.clone()));
+    }
 }
 
 #[doc(hidden)]
@@ -323,6 +345,7 @@ pub fn read_conf(args: &[syntax::ast::NestedMetaItem], sess: &Session) -> Conf {
         Ok(file_name) => {
             // if the user specified a file, it must exist, otherwise default to `clippy.toml` but
             // do not require the file to exist
+            let from_attribute = file_name.is_some();
             let file_name = if let Some(file_name) = file_name {
                 Some(file_name)
             } else {
@@ -336,19 +359,13 @@ pub fn read_conf(args: &[syntax::ast::NestedMetaItem], sess: &Session) -> Conf {
                 }
             };
 
-            let file_name = file_name.map(|file_name| {
-                if file_name.is_relative() {
-                    sess.local_crate_source_file
-                        .as_ref()
-                        .and_then(|file| std::path::Path::new(&file).parent().map(std::path::Path::to_path_buf))
-                        .unwrap_or_default()
-                        .join(file_name)
-                } else {
-                    file_name
-                }
-            });
+            let file_name =
+                file_name.map(|file_name| resolve_conf_path(file_name, sess.local_crate_source_file.as_ref()));
 
-            let (conf, errors) = utils::conf::read(file_name.as_ref().map(std::convert::AsRef::as_ref));
+            let (conf, errors) = utils::conf::read(
+                file_name.as_ref().map(std::convert::AsRef::as_ref),
+                sess.local_crate_source_file.as_ref().map(std::convert::AsRef::as_ref),
+            );
 
             // all conf errors are non-fatal, we just use the default conf in case of error
             for error in errors {
@@ -360,6 +377,29 @@ pub fn read_conf(args: &[syntax::ast::NestedMetaItem], sess: &Session) -> Conf {
                 .emit();
             }
 
+            // lint levels are registered by `register_conf_lint_levels` before the crate is parsed,
+            // so they can only come from the file found by `lookup_conf_file`
+            if from_attribute
+                && (conf.lints.values().any(|lint| lint.level().is_some()) || conf.max_lint_version.is_some())
+            {
+                sess.struct_err(&format!(
+                    "lint levels in the `[lints]` table and `max-lint-version` of `{}` are ignored: \
+                     they are not supported in a file set with `#![clippy::conf_file]`",
+                    file_name.as_ref().and_then(|p| p.to_str()).unwrap_or("")
+                ))
+                .emit();
+            }
+
+            if std::env::var("CLIPPY_PRINT_CONF_SOURCES").is_ok() {
+                for (key, source) in &conf.sources {
+                    sess.note_without_error(&format!(
+                        "Clippy configuration key `{}` is set in `{}`",
+                        key,
+                        source.display()
+                    ));
+                }
+            }
+
             conf
         },
         Err((err, span)) => {
@@ -371,12 +411,111 @@ pub fn read_conf(args: &[syntax::ast::NestedMetaItem], sess: &Session) -> Conf {
     }
 }
 
+/// Reads the baseline of known warnings set by `cargo clippy --baseline`.
+///
+/// Used in `./src/driver.rs`.
+pub fn read_baseline(sess: &Session) {
+    utils::baseline::read(sess);
+}
+
+/// Prints the time spent in each lint pass if `CLIPPY_PROFILE` is set.
+///
+/// Used in `./src/driver.rs`.
+pub fn print_profile(crate_name: &str) {
+    if utils::profile::enabled() {
+        utils::profile::print_report(crate_name);
+    }
+}
+
+/// Loads the lint plugins passed with `--lint-plugin` or listed in the `lint-plugins`
+/// configuration key, and registers their lints after Clippy's.
+///
+/// Used in `./src/driver.rs`.
+pub fn register_lint_plugins(store: &mut lint::LintStore, sess: &Session, conf: &Conf, paths: &[std::path::PathBuf]) {
+    if let Err(e) = utils::plugins::register(store, conf, &utils::plugins::paths(conf, paths)) {
+        sess.err(&e);
+    }
+}
+
+/// Returns a store with only the lints of the lint plugins, for `-W help`. The configuration is
+/// read from the `clippy.toml` of the current directory.
+///
+/// Used in `./src/driver.rs`.
+pub fn plugin_lint_store(paths: &[std::path::PathBuf]) -> Result<lint::LintStore, String> {
+    let conf_file = utils::conf::lookup_conf_file().map_err(|e| e.to_string())?;
+    let (conf, _) = utils::conf::read(conf_file.as_ref().map(std::convert::AsRef::as_ref), None);
+    let mut store = lint::LintStore::new();
+    utils::plugins::register(&mut store, &conf, &utils::plugins::paths(&conf, paths))?;
+    Ok(store)
+}
+
+/// Makes a relative configuration file path relative to the directory of the crate root.
+fn resolve_conf_path(
+    file_name: std::path::PathBuf,
+    local_crate_source_file: Option<&std::path::PathBuf>,
+) -> std::path::PathBuf {
+    if file_name.is_relative() {
+        local_crate_source_file
+            .and_then(|file| file.parent().map(std::path::Path::to_path_buf))
+            .unwrap_or_default()
+            .join(file_name)
+    } else {
+        file_name
+    }
+}
+
+/// Register the lint levels of the `[lints]` table in Clippy's configuration file.
+///
+/// The levels are prepended to the command line lint options, so `-A`/`-W`/`-D`/`-F` flags and
+/// lint attributes still take precedence over the configuration file. rustc can't tell them apart
+/// from the flags, so its notes about the level of a lint say it was requested on the command
+/// line. This has to happen before the session is created, errors in the configuration file are
+/// reported later by `read_conf`. A file set with `#![clippy::conf_file]` is only known once the
+/// crate is parsed, so its lint levels are rejected by `read_conf`.
+///
+/// With `max-lint-version`, the lints of `lint_versions` added after that version are allowed
+/// after the other options, so that enabling their group doesn't enable them, unless they are
+/// named in the `[lints]` table or on the command line.
+///
+/// Used in `./src/driver.rs`.
+pub fn register_conf_lint_levels(
+    opts: &mut rustc::session::config::Options,
+    input_path: Option<&std::path::PathBuf>,
+    lint_versions: &[(&str, &str)],
+) {
+    let file_name = match utils::conf::lookup_conf_file() {
+        Ok(Some(file_name)) => resolve_conf_path(file_name, input_path),
+        _ => return,
+    };
+
+    let (conf, _) = utils::conf::read(Some(&file_name), input_path.map(std::convert::AsRef::as_ref));
+    let levels: Vec<(String, lint::Level)> = conf
+        .lints
+        .iter()
+        .filter_map(|(name, lint)| lint.level().map(|level| (utils::conf::lint_opt_name(name), level.into())))
+        .collect();
+
+    opts.lint_opts.splice(0..0, levels);
+
+    if let Some(max_version) = conf.max_lint_version.as_ref().and_then(|v| utils::conf::parse_version(v)) {
+        let newer: Vec<(String, lint::Level)> = lint_versions
+            .iter()
+            .filter(|(_, version)| utils::conf::parse_version(version).map_or(false, |v| v > max_version))
+            .map(|(name, _)| format!("clippy::{}", name))
+            .filter(|name| !opts.lint_opts.iter().any(|(opt, _)| opt.replace('-', "_") == *name))
+            .map(|name| (name, lint::Level::Allow))
+            .collect();
+        opts.lint_opts.extend(newer);
+    }
+}
+
 /// Register all lints and lint groups with the rustc plugin registry
 ///
 /// Used in `./src/driver.rs`.
 #[allow(clippy::too_many_lines)]
 #[rustfmt::skip]
 pub fn register_plugins(store: &mut lint::LintStore, sess: &Session, conf: &Conf) {
+    let store = &mut utils::profile::ProfilingStore::new(store);
     register_removed_non_tool_lints(store);
 
     // begin deprecated lints, do not remove this comment, it’s used in `update_lints`
@@ -468,8 +607,13 @@ pub fn register_plugins(store: &mut lint::LintStore, sess: &Session, conf: &Conf
         &copy_iterator::COPY_ITERATOR,
         &dbg_macro::DBG_MACRO,
         &default_trait_access::DEFAULT_TRAIT_ACCESS,
+        &dependency_policy::DISALLOWED_LICENSES,
+        &dependency_policy::DISALLOWED_SOURCES,
         &derive::DERIVE_HASH_XOR_EQ,
         &derive::EXPL_IMPL_CLONE_ON_COPY,
+        &disallowed::DISALLOWED_MACRO,
+        &disallowed::DISALLOWED_METHOD,
+        &disallowed::DISALLOWED_TYPE,
         &doc::DOC_MARKDOWN,
         &doc::MISSING_SAFETY_DOC,
         &doc::NEEDLESS_DOCTEST_MAIN,
@@ -501,6 +645,9 @@ pub fn register_plugins(store: &mut lint::LintStore, sess: &Session, conf: &Conf
         &excessive_precision::EXCESSIVE_PRECISION,
         &explicit_write::EXPLICIT_WRITE,
         &fallible_impl_from::FALLIBLE_IMPL_FROM,
+        &feature_flags::NEGATIVE_FEATURE_NAMES,
+        &feature_flags::UNDECLARED_FEATURES,
+        &feature_flags::UNUSED_FEATURES,
         &format::USELESS_FORMAT,
         &formatting::POSSIBLE_MISSING_COMMA,
         &formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING,
@@ -751,6 +898,8 @@ pub fn register_plugins(store: &mut lint::LintStore, sess: &Session, conf: &Conf
         &unicode::UNICODE_NOT_NFC,
         &unicode::ZERO_WIDTH_SPACE,
         &unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME,
+        &unused_dependencies::TEST_ONLY_DEPENDENCIES,
+        &unused_dependencies::UNUSED_DEPENDENCIES,
         &unused_io_amount::UNUSED_IO_AMOUNT,
         &unused_label::UNUSED_LABEL,
         &unused_self::UNUSED_SELF,
@@ -759,6 +908,8 @@ pub fn register_plugins(store: &mut lint::LintStore, sess: &Session, conf: &Conf
         &use_self::USE_SELF,
         &vec::USELESS_VEC,
         &wildcard_dependencies::WILDCARD_DEPENDENCIES,
+        &workspace::INCONSISTENT_WORKSPACE_EDITION,
+        &workspace::UNUSED_WORKSPACE_ITEM,
         &write::PRINTLN_EMPTY_STRING,
         &write::PRINT_LITERAL,
         &write::PRINT_STDOUT,
@@ -771,10 +922,13 @@ pub fn register_plugins(store: &mut lint::LintStore, sess: &Session, conf: &Conf
     ]);
     // end register lints, do not remove this comment, it’s used in `update_lints`
 
+    let msrv = conf.msrv.as_ref().and_then(|v| utils::msrvs::RustcVersion::parse(v));
+
     store.register_late_pass(|| box serde_api::SerdeAPI);
     store.register_late_pass(|| box utils::internal_lints::CompilerLintFunctions::new());
     store.register_late_pass(|| box utils::internal_lints::LintWithoutLintPass::default());
     store.register_late_pass(|| box utils::internal_lints::OuterExpnDataPass);
+    store.register_late_pass(|| box utils::internal_lints::PathConstants::default());
     store.register_late_pass(|| box utils::inspector::DeepCodeInspector);
     store.register_late_pass(|| box utils::author::Author);
     store.register_late_pass(|| box types::Types);
@@ -802,7 +956,7 @@ pub fn register_plugins(store: &mut lint::LintStore, sess: &Session, conf: &Conf
     store.register_late_pass(|| box strings::StringAdd);
     store.register_late_pass(|| box implicit_return::ImplicitReturn);
     store.register_late_pass(|| box methods::Methods);
-    store.register_late_pass(|| box map_clone::MapClone);
+    store.register_late_pass(move || box map_clone::MapClone::new(msrv));
     store.register_late_pass(|| box shadow::Shadow);
     store.register_late_pass(|| box types::LetUnitValue);
     store.register_late_pass(|| box types::UnitCmp);
@@ -810,7 +964,7 @@ pub fn register_plugins(store: &mut lint::LintStore, sess: &Session, conf: &Conf
     store.register_late_pass(|| box main_recursion::MainRecursion::default());
     store.register_late_pass(|| box lifetimes::Lifetimes);
     store.register_late_pass(|| box entry::HashMapPass);
-    store.register_late_pass(|| box ranges::Ranges);
+    store.register_late_pass(move || box ranges::Ranges::new(msrv));
     store.register_late_pass(|| box types::Casts);
     let type_complexity_threshold = conf.type_complexity_threshold;
     store.register_late_pass(move || box types::TypeComplexity::new(type_complexity_threshold));
@@ -824,7 +978,7 @@ pub fn register_plugins(store: &mut lint::LintStore, sess: &Session, conf: &Conf
     store.register_late_pass(|| box needless_borrowed_ref::NeedlessBorrowedRef);
     store.register_late_pass(|| box no_effect::NoEffect);
     store.register_late_pass(|| box temporary_assignment::TemporaryAssignment);
-    store.register_late_pass(|| box transmute::Transmute);
+    store.register_late_pass(move || box transmute::Transmute::new(msrv));
     let cognitive_complexity_threshold = conf.cognitive_complexity_threshold;
     store.register_late_pass(move || box cognitive_complexity::CognitiveComplexity::new(cognitive_complexity_threshold));
     let too_large_for_stack = conf.too_large_for_stack;
@@ -896,19 +1050,19 @@ pub fn register_plugins(store: &mut lint::LintStore, sess: &Session, conf: &Conf
     store.register_late_pass(|| box inherent_impl::MultipleInherentImpl::default());
     store.register_late_pass(|| box neg_cmp_op_on_partial_ord::NoNegCompOpForPartialOrd);
     store.register_late_pass(|| box unwrap::Unwrap);
-    store.register_late_pass(|| box duration_subsec::DurationSubsec);
+    store.register_late_pass(move || box duration_subsec::DurationSubsec::new(msrv));
     store.register_late_pass(|| box default_trait_access::DefaultTraitAccess);
     store.register_late_pass(|| box indexing_slicing::IndexingSlicing);
     store.register_late_pass(|| box non_copy_const::NonCopyConst);
-    store.register_late_pass(|| box ptr_offset_with_cast::PtrOffsetWithCast);
+    store.register_late_pass(move || box ptr_offset_with_cast::PtrOffsetWithCast::new(msrv));
     store.register_late_pass(|| box redundant_clone::RedundantClone);
     store.register_late_pass(|| box slow_vector_initialization::SlowVectorInit);
     store.register_late_pass(|| box types::RefToMut);
     store.register_late_pass(|| box assertions_on_constants::AssertionsOnConstants);
-    store.register_late_pass(|| box missing_const_for_fn::MissingConstForFn);
+    store.register_late_pass(move || box missing_const_for_fn::MissingConstForFn::new(msrv));
     store.register_late_pass(|| box transmuting_null::TransmutingNull);
     store.register_late_pass(|| box path_buf_push_overwrite::PathBufPushOverwrite);
-    store.register_late_pass(|| box checked_conversions::CheckedConversions);
+    store.register_late_pass(move || box checked_conversions::CheckedConversions::new(msrv));
     store.register_late_pass(|| box integer_division::IntegerDivision);
     store.register_late_pass(|| box inherent_to_string::InherentToString);
     store.register_late_pass(|| box trait_bounds::TraitBounds);
@@ -930,7 +1084,17 @@ pub fn register_plugins(store: &mut lint::LintStore, sess: &Session, conf: &Conf
     store.register_early_pass(|| box needless_continue::NeedlessContinue);
     store.register_early_pass(|| box redundant_static_lifetimes::RedundantStaticLifetimes);
     store.register_early_pass(|| box cargo_common_metadata::CargoCommonMetadata);
-    store.register_early_pass(|| box multiple_crate_versions::MultipleCrateVersions);
+    let multiple_crate_versions = multiple_crate_versions::MultipleCrateVersions::new(
+        &conf.allowed_duplicate_crates,
+        conf.duplicate_crates_direct_only,
+    );
+    store.register_early_pass(move || box multiple_crate_versions.clone());
+    let dependency_policy = dependency_policy::DependencyPolicy::new(
+        &conf.allowed_licenses,
+        &conf.denied_licenses,
+        &conf.allowed_sources,
+    );
+    store.register_early_pass(move || box dependency_policy.clone());
     store.register_early_pass(|| box wildcard_dependencies::WildcardDependencies);
     store.register_early_pass(|| box literal_representation::LiteralDigitGrouping);
     let literal_representation_threshold = conf.literal_representation_threshold;
@@ -940,6 +1104,13 @@ pub fn register_plugins(store: &mut lint::LintStore, sess: &Session, conf: &Conf
     store.register_early_pass(move || box enum_variants::EnumVariantNames::new(enum_variant_name_threshold));
     store.register_late_pass(|| box unused_self::UnusedSelf);
     store.register_late_pass(|| box mutable_debug_assertion::DebugAssertWithMutCall);
+    let disallowed_methods = conf.disallowed_methods.clone();
+    store.register_late_pass(move || box disallowed::DisallowedMethod::new(disallowed_methods.clone()));
+    let disallowed_types = conf.disallowed_types.clone();
+    store.register_late_pass(move || box disallowed::DisallowedType::new(disallowed_types.clone()));
+    let disallowed_macros = conf.disallowed_macros.clone();
+    store.register_late_pass(move || box disallowed::DisallowedMacro::new(disallowed_macros.clone()));
+    store.register_late_pass(|| box unused_dependencies::UnusedDependencies::default());
 
     store.register_group(true, "clippy::restriction", Some("clippy_restriction"), vec![
         LintId::of(&arithmetic::FLOAT_ARITHMETIC),
@@ -970,6 +1141,7 @@ pub fn register_plugins(store: &mut lint::LintStore, sess: &Session, conf: &Conf
         LintId::of(&shadow::SHADOW_REUSE),
         LintId::of(&shadow::SHADOW_SAME),
         LintId::of(&strings::STRING_ADD),
+        LintId::of(&workspace::UNUSED_WORKSPACE_ITEM),
         LintId::of(&write::PRINT_STDOUT),
         LintId::of(&write::USE_DEBUG),
     ]);
@@ -1029,8 +1201,10 @@ pub fn register_plugins(store: &mut lint::LintStore, sess: &Session, conf: &Conf
     store.register_group(true, "clippy::internal", Some("clippy_internal"), vec![
         LintId::of(&utils::internal_lints::CLIPPY_LINTS_INTERNAL),
         LintId::of(&utils::internal_lints::COMPILER_LINT_FUNCTIONS),
+        LintId::of(&utils::internal_lints::INVALID_PATHS),
         LintId::of(&utils::internal_lints::LINT_WITHOUT_LINT_PASS),
         LintId::of(&utils::internal_lints::OUTER_EXPN_EXPN_DATA),
+        LintId::of(&utils::internal_lints::UNUSED_PATHS),
     ]);
 
     store.register_group(true, "clippy::all", Some("clippy"), vec![
@@ -1057,6 +1231,9 @@ pub fn register_plugins(store: &mut lint::LintStore, sess: &Session, conf: &Conf
         LintId::of(&copies::IFS_SAME_COND),
         LintId::of(&copies::IF_SAME_THEN_ELSE),
         LintId::of(&derive::DERIVE_HASH_XOR_EQ),
+        LintId::of(&disallowed::DISALLOWED_MACRO),
+        LintId::of(&disallowed::DISALLOWED_METHOD),
+        LintId::of(&disallowed::DISALLOWED_TYPE),
         LintId::of(&doc::MISSING_SAFETY_DOC),
         LintId::of(&doc::NEEDLESS_DOCTEST_MAIN),
         LintId::of(&double_comparison::DOUBLE_COMPARISONS),
@@ -1290,6 +1467,9 @@ pub fn register_plugins(store: &mut lint::LintStore, sess: &Session, conf: &Conf
         LintId::of(&block_in_if_condition::BLOCK_IN_IF_CONDITION_STMT),
         LintId::of(&collapsible_if::COLLAPSIBLE_IF),
         LintId::of(&comparison_chain::COMPARISON_CHAIN),
+        LintId::of(&disallowed::DISALLOWED_MACRO),
+        LintId::of(&disallowed::DISALLOWED_METHOD),
+        LintId::of(&disallowed::DISALLOWED_TYPE),
         LintId::of(&doc::MISSING_SAFETY_DOC),
         LintId::of(&doc::NEEDLESS_DOCTEST_MAIN),
         LintId::of(&enum_variants::ENUM_VARIANT_NAMES),
@@ -1538,8 +1718,16 @@ pub fn register_plugins(store: &mut lint::LintStore, sess: &Session, conf: &Conf
 
     store.register_group(true, "clippy::cargo", Some("clippy_cargo"), vec![
         LintId::of(&cargo_common_metadata::CARGO_COMMON_METADATA),
+        LintId::of(&dependency_policy::DISALLOWED_LICENSES),
+        LintId::of(&dependency_policy::DISALLOWED_SOURCES),
+        LintId::of(&feature_flags::NEGATIVE_FEATURE_NAMES),
+        LintId::of(&feature_flags::UNDECLARED_FEATURES),
+        LintId::of(&feature_flags::UNUSED_FEATURES),
         LintId::of(&multiple_crate_versions::MULTIPLE_CRATE_VERSIONS),
+        LintId::of(&unused_dependencies::TEST_ONLY_DEPENDENCIES),
+        LintId::of(&unused_dependencies::UNUSED_DEPENDENCIES),
         LintId::of(&wildcard_dependencies::WILDCARD_DEPENDENCIES),
+        LintId::of(&workspace::INCONSISTENT_WORKSPACE_EDITION),
     ]);
 
     store.register_group(true, "clippy::nursery", Some("clippy_nursery"), vec![
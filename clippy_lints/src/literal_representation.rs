@@ -22,6 +22,7 @@ declare_clippy_lint! {
     /// ```rust
     /// let x: u64 = 61864918973511;
     /// ```
+    #[clippy::version = "0.0.148"]
     pub UNREADABLE_LITERAL,
     style,
     "long integer literal without underscores"
@@ -42,6 +43,7 @@ declare_clippy_lint! {
     /// ```rust
     /// 2_32;
     /// ```
+    #[clippy::version = "0.0.212"]
     pub MISTYPED_LITERAL_SUFFIXES,
     correctness,
     "mistyped literal suffix"
@@ -61,6 +63,7 @@ declare_clippy_lint! {
     /// ```rust
     /// let x: u64 = 618_64_9189_73_511;
     /// ```
+    #[clippy::version = "0.0.148"]
     pub INCONSISTENT_DIGIT_GROUPING,
     style,
     "integer literals with digits grouped inconsistently"
@@ -80,6 +83,7 @@ declare_clippy_lint! {
     /// ```rust
     /// let x: u64 = 6186491_8973511;
     /// ```
+    #[clippy::version = "0.0.148"]
     pub LARGE_DIGIT_GROUPS,
     pedantic,
     "grouping digits into groups that are too large"
@@ -98,6 +102,7 @@ declare_clippy_lint! {
     /// `255` => `0xFF`
     /// `65_535` => `0xFFFF`
     /// `4_042_322_160` => `0xF0F0_F0F0`
+    #[clippy::version = "0.0.182"]
     pub DECIMAL_LITERAL_REPRESENTATION,
     restriction,
     "using decimal representation when hexadecimal would be better"
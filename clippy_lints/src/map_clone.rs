@@ -1,3 +1,4 @@
+use crate::utils::msrvs::{self, meets_msrv, RustcVersion};
 use crate::utils::paths;
 use crate::utils::{
     is_copy, match_trait_method, match_type, remove_blocks, snippet_with_applicability, span_lint_and_sugg,
@@ -6,7 +7,7 @@ use if_chain::if_chain;
 use rustc::hir;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
 use rustc::ty;
-use rustc::{declare_lint_pass, declare_tool_lint};
+use rustc::{declare_tool_lint, impl_lint_pass};
 use rustc_errors::Applicability;
 use syntax::ast::Ident;
 use syntax::source_map::Span;
@@ -34,14 +35,28 @@ declare_clippy_lint! {
     /// let y = x.iter();
     /// let z = y.cloned();
     /// ```
+    #[clippy::version = "0.0.212"]
     pub MAP_CLONE,
     style,
     "using `iterator.map(|x| x.clone())`, or dereferencing closures for `Copy` types"
 }
 
-declare_lint_pass!(MapClone => [MAP_CLONE]);
+pub struct MapClone {
+    msrv: Option<RustcVersion>,
+}
+
+impl MapClone {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(MapClone => [MAP_CLONE]);
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MapClone {
+    extract_msrv_attr!(LateContext);
+
     fn check_expr(&mut self, cx: &LateContext<'_, '_>, e: &hir::Expr) {
         if e.span.from_expansion() {
             return;
@@ -62,14 +77,14 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MapClone {
                         hir::BindingAnnotation::Unannotated, .., name, None
                     ) = inner.kind {
                         if ident_eq(name, closure_expr) {
-                            lint(cx, e.span, args[0].span, true);
+                            lint(cx, e.span, args[0].span, true, self.msrv);
                         }
                     },
                     hir::PatKind::Binding(hir::BindingAnnotation::Unannotated, .., name, None) => {
                         match closure_expr.kind {
                             hir::ExprKind::Unary(hir::UnOp::UnDeref, ref inner) => {
                                 if ident_eq(name, inner) && !cx.tables.expr_ty(inner).is_box() {
-                                    lint(cx, e.span, args[0].span, true);
+                                    lint(cx, e.span, args[0].span, true, self.msrv);
                                 }
                             },
                             hir::ExprKind::MethodCall(ref method, _, ref obj) => {
@@ -79,7 +94,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MapClone {
                                     let obj_ty = cx.tables.expr_ty(&obj[0]);
                                     if let ty::Ref(_, ty, _) = obj_ty.kind {
                                         let copy = is_copy(cx, ty);
-                                        lint(cx, e.span, args[0].span, copy);
+                                        lint(cx, e.span, args[0].span, copy, self.msrv);
                                     } else {
                                         lint_needless_cloning(cx, e.span, args[0].span);
                                     }
@@ -115,33 +130,30 @@ fn lint_needless_cloning(cx: &LateContext<'_, '_>, root: Span, receiver: Span) {
     )
 }
 
-fn lint(cx: &LateContext<'_, '_>, replace: Span, root: Span, copied: bool) {
+fn lint(cx: &LateContext<'_, '_>, replace: Span, root: Span, copied: bool, msrv: Option<RustcVersion>) {
     let mut applicability = Applicability::MachineApplicable;
-    if copied {
-        span_lint_and_sugg(
-            cx,
-            MAP_CLONE,
-            replace,
-            "You are using an explicit closure for copying elements",
-            "Consider calling the dedicated `copied` method",
-            format!(
-                "{}.copied()",
-                snippet_with_applicability(cx, root, "..", &mut applicability)
-            ),
-            applicability,
-        )
+    let message = if copied {
+        "You are using an explicit closure for copying elements"
     } else {
-        span_lint_and_sugg(
-            cx,
-            MAP_CLONE,
-            replace,
-            "You are using an explicit closure for cloning elements",
-            "Consider calling the dedicated `cloned` method",
-            format!(
-                "{}.cloned()",
-                snippet_with_applicability(cx, root, "..", &mut applicability)
-            ),
-            applicability,
-        )
-    }
+        "You are using an explicit closure for cloning elements"
+    };
+    // `cloned` also copies, for the crates supporting a Rust without `copied`
+    let method = if copied && meets_msrv(msrv, msrvs::ITERATOR_COPIED) {
+        "copied"
+    } else {
+        "cloned"
+    };
+    span_lint_and_sugg(
+        cx,
+        MAP_CLONE,
+        replace,
+        message,
+        &format!("Consider calling the dedicated `{}` method", method),
+        format!(
+            "{}.{}()",
+            snippet_with_applicability(cx, root, "..", &mut applicability),
+            method
+        ),
+        applicability,
+    )
 }
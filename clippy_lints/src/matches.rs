@@ -34,6 +34,7 @@ declare_clippy_lint! {
     ///     _ => (),
     /// }
     /// ```
+    #[clippy::version = "0.0.47"]
     pub SINGLE_MATCH,
     style,
     "a match statement with a single nontrivial arm (i.e., where the other arm is `_ => {}`) instead of `if let`"
@@ -73,6 +74,7 @@ declare_clippy_lint! {
     ///     bar(&other_ref);
     /// }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub SINGLE_MATCH_ELSE,
     pedantic,
     "a match statement with two arms where the second arm's pattern is a placeholder instead of a specific match pattern"
@@ -96,6 +98,7 @@ declare_clippy_lint! {
     ///     _ => frob(&x),
     /// }
     /// ```
+    #[clippy::version = "0.0.47"]
     pub MATCH_REF_PATS,
     style,
     "a match or `if let` with all arms prefixed with `&` instead of deref-ing the match expression"
@@ -130,6 +133,7 @@ declare_clippy_lint! {
     ///     bar();
     /// }
     /// ```
+    #[clippy::version = "0.0.47"]
     pub MATCH_BOOL,
     style,
     "a match on a boolean expression instead of an `if..else` block"
@@ -152,6 +156,7 @@ declare_clippy_lint! {
     ///     _ => (),
     /// }
     /// ```
+    #[clippy::version = "0.0.88"]
     pub MATCH_OVERLAPPING_ARM,
     style,
     "a match with overlapping arms"
@@ -174,6 +179,7 @@ declare_clippy_lint! {
     ///     Err(_) => panic!("err"),
     /// }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub MATCH_WILD_ERR_ARM,
     style,
     "a match with `Err(_)` arm and take drastic actions"
@@ -195,6 +201,7 @@ declare_clippy_lint! {
     ///     Some(ref v) => Some(v),
     /// };
     /// ```
+    #[clippy::version = "0.0.177"]
     pub MATCH_AS_REF,
     complexity,
     "a match on an Option value instead of using `as_ref()` or `as_mut`"
@@ -217,6 +224,7 @@ declare_clippy_lint! {
     ///     _ => {},
     /// }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub WILDCARD_ENUM_MATCH_ARM,
     restriction,
     "a wildcard enum match arm using `_`"
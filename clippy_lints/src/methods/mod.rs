@@ -54,6 +54,7 @@ declare_clippy_lint! {
     /// let opt = Some(1);
     /// opt.expect("more helpful message");
     /// ```
+    #[clippy::version = "0.0.47"]
     pub OPTION_UNWRAP_USED,
     restriction,
     "using `Option.unwrap()`, which should at least get a better message using `expect()`"
@@ -86,6 +87,7 @@ declare_clippy_lint! {
     /// let res: Result<usize, ()> = Ok(1);
     /// res.expect("more helpful message");
     /// ```
+    #[clippy::version = "0.0.47"]
     pub RESULT_UNWRAP_USED,
     restriction,
     "using `Result.unwrap()`, which might be better handled"
@@ -116,6 +118,7 @@ declare_clippy_lint! {
     /// opt?;
     /// # Some::<()>(())
     /// ```
+    #[clippy::version = "0.0.212"]
     pub OPTION_EXPECT_USED,
     restriction,
     "using `Option.expect()`, which might be better handled"
@@ -145,6 +148,7 @@ declare_clippy_lint! {
     /// res?;
     /// # Ok::<(), ()>(())
     /// ```
+    #[clippy::version = "0.0.212"]
     pub RESULT_EXPECT_USED,
     restriction,
     "using `Result.expect()`, which might be better handled"
@@ -173,6 +177,7 @@ declare_clippy_lint! {
     ///     }
     /// }
     /// ```
+    #[clippy::version = "0.0.47"]
     pub SHOULD_IMPLEMENT_TRAIT,
     style,
     "defining a method that should be implementing a std trait"
@@ -204,6 +209,7 @@ declare_clippy_lint! {
     ///     }
     /// }
     /// ```
+    #[clippy::version = "0.0.81"]
     pub WRONG_SELF_CONVENTION,
     style,
     "defining a method named with an established prefix (like \"into_\") that takes `self` with the wrong convention"
@@ -228,6 +234,7 @@ declare_clippy_lint! {
     ///     }
     /// }
     /// ```
+    #[clippy::version = "0.0.47"]
     pub WRONG_PUB_SELF_CONVENTION,
     restriction,
     "defining a public method named with an established prefix (like \"into_\") that takes `self` with the wrong convention"
@@ -245,6 +252,7 @@ declare_clippy_lint! {
     /// ```ignore
     /// x.ok().expect("why did I do this again?")
     /// ```
+    #[clippy::version = "0.0.47"]
     pub OK_EXPECT,
     style,
     "using `ok().expect()`, which gives worse error messages than calling `expect` directly on the Result"
@@ -263,6 +271,7 @@ declare_clippy_lint! {
     /// # let x = Some(1);
     /// x.map(|a| a + 1).unwrap_or(0);
     /// ```
+    #[clippy::version = "0.0.93"]
     pub OPTION_MAP_UNWRAP_OR,
     pedantic,
     "using `Option.map(f).unwrap_or(a)`, which is more succinctly expressed as `map_or(a, f)`"
@@ -282,6 +291,7 @@ declare_clippy_lint! {
     /// # fn some_function() -> usize { 1 }
     /// x.map(|a| a + 1).unwrap_or_else(some_function);
     /// ```
+    #[clippy::version = "0.0.93"]
     pub OPTION_MAP_UNWRAP_OR_ELSE,
     pedantic,
     "using `Option.map(f).unwrap_or_else(g)`, which is more succinctly expressed as `map_or_else(g, f)`"
@@ -301,6 +311,7 @@ declare_clippy_lint! {
     /// # fn some_function(foo: ()) -> usize { 1 }
     /// x.map(|a| a + 1).unwrap_or_else(some_function);
     /// ```
+    #[clippy::version = "0.0.169"]
     pub RESULT_MAP_UNWRAP_OR_ELSE,
     pedantic,
     "using `Result.map(f).unwrap_or_else(g)`, which is more succinctly expressed as `.ok().map_or_else(g, f)`"
@@ -318,6 +329,7 @@ declare_clippy_lint! {
     /// ```ignore
     /// opt.map_or(None, |a| a + 1)
     /// ```
+    #[clippy::version = "0.0.166"]
     pub OPTION_MAP_OR_NONE,
     style,
     "using `Option.map_or(None, f)`, which is more succinctly expressed as `and_then(f)`"
@@ -344,6 +356,7 @@ declare_clippy_lint! {
     /// let x = Some("foo");
     /// let _ = x.map(|s| s.len());
     /// ```
+    #[clippy::version = "0.0.212"]
     pub OPTION_AND_THEN_SOME,
     complexity,
     "using `Option.and_then(|x| Some(y))`, which is more succinctly expressed as `map(|x| y)`"
@@ -367,6 +380,7 @@ declare_clippy_lint! {
     /// # let vec = vec![1];
     /// vec.iter().find(|x| **x == 0);
     /// ```
+    #[clippy::version = "0.0.88"]
     pub FILTER_NEXT,
     complexity,
     "using `filter(p).next()`, which is more succinctly expressed as `.find(p)`"
@@ -385,6 +399,7 @@ declare_clippy_lint! {
     /// let vec = vec![vec![1]];
     /// vec.iter().map(|x| x.iter()).flatten();
     /// ```
+    #[clippy::version = "0.0.212"]
     pub MAP_FLATTEN,
     pedantic,
     "using combinations of `flatten` and `map` which can usually be written as a single method call"
@@ -405,6 +420,7 @@ declare_clippy_lint! {
     /// let vec = vec![1];
     /// vec.iter().filter(|x| **x == 0).map(|x| *x * 2);
     /// ```
+    #[clippy::version = "0.0.78"]
     pub FILTER_MAP,
     pedantic,
     "using combinations of `filter`, `map`, `filter_map` and `flat_map` which can usually be written as a single method call"
@@ -427,6 +443,7 @@ declare_clippy_lint! {
     /// ```rust
     ///  (0..3).find_map(|x| if x == 2 { Some(x) } else { None });
     /// ```
+    #[clippy::version = "0.0.212"]
     pub FILTER_MAP_NEXT,
     pedantic,
     "using combination of `filter_map` and `next` which can usually be written as a single method call"
@@ -449,6 +466,7 @@ declare_clippy_lint! {
     /// # let iter = vec![vec![0]].into_iter();
     /// iter.flatten();
     /// ```
+    #[clippy::version = "0.0.212"]
     pub FLAT_MAP_IDENTITY,
     complexity,
     "call to `flat_map` where `flatten` is sufficient"
@@ -471,6 +489,7 @@ declare_clippy_lint! {
     /// ```rust
     ///  (0..3).find_map(|x| if x == 2 { Some(x * 2) } else { None });
     /// ```
+    #[clippy::version = "0.0.212"]
     pub FIND_MAP,
     pedantic,
     "using a combination of `find` and `map` can usually be written as a single method call"
@@ -495,6 +514,7 @@ declare_clippy_lint! {
     /// # let vec = vec![1];
     /// vec.iter().any(|x| *x == 0);
     /// ```
+    #[clippy::version = "0.0.47"]
     pub SEARCH_IS_SOME,
     complexity,
     "using an iterator search followed by `is_some()`, which is more succinctly expressed as a call to `any()`"
@@ -519,6 +539,7 @@ declare_clippy_lint! {
     /// let name = "foo";
     /// if name.starts_with('_') {};
     /// ```
+    #[clippy::version = "0.0.212"]
     pub CHARS_NEXT_CMP,
     complexity,
     "using `.chars().next()` to check if a string starts with a char"
@@ -550,6 +571,7 @@ declare_clippy_lint! {
     /// # let foo = Some(String::new());
     /// foo.unwrap_or_default();
     /// ```
+    #[clippy::version = "0.0.212"]
     pub OR_FUN_CALL,
     perf,
     "using any `*or` method with a function call, which suggests `*or_else`"
@@ -585,6 +607,7 @@ declare_clippy_lint! {
     /// # let err_msg = "I'm a teapot";
     /// foo.unwrap_or_else(|| panic!("Err {}: {}", err_code, err_msg));
     /// ```
+    #[clippy::version = "0.0.212"]
     pub EXPECT_FUN_CALL,
     perf,
     "using any `expect` method with a function call"
@@ -602,6 +625,7 @@ declare_clippy_lint! {
     /// ```rust
     /// 42u64.clone();
     /// ```
+    #[clippy::version = "0.0.81"]
     pub CLONE_ON_COPY,
     complexity,
     "using `clone` on a `Copy` type"
@@ -622,6 +646,7 @@ declare_clippy_lint! {
     /// let x = Rc::new(1);
     /// x.clone();
     /// ```
+    #[clippy::version = "0.0.159"]
     pub CLONE_ON_REF_PTR,
     restriction,
     "using 'clone' on a ref-counted pointer"
@@ -644,6 +669,7 @@ declare_clippy_lint! {
     ///     println!("{:p} {:p}", *y, z); // prints out the same pointer
     /// }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub CLONE_DOUBLE_REF,
     correctness,
     "using `clone` on `&&T`"
@@ -667,6 +693,7 @@ declare_clippy_lint! {
     /// // OK, the specialized impl is used
     /// ["foo", "bar"].iter().map(|&s| s.to_string());
     /// ```
+    #[clippy::version = "0.0.212"]
     pub INEFFICIENT_TO_STRING,
     perf,
     "using `to_string` on `&&T` where `T: ToString`"
@@ -687,6 +714,7 @@ declare_clippy_lint! {
     ///     }
     /// }
     /// ```
+    #[clippy::version = "0.0.47"]
     pub NEW_RET_NO_SELF,
     style,
     "not returning `Self` in a `new` method"
@@ -703,6 +731,7 @@ declare_clippy_lint! {
     ///
     /// **Example:**
     /// `_.split("x")` could be `_.split('x')`
+    #[clippy::version = "0.0.212"]
     pub SINGLE_CHAR_PATTERN,
     perf,
     "using a single-character str where a char could be used, e.g., `_.split(\"x\")`"
@@ -731,6 +760,7 @@ declare_clippy_lint! {
     ///     call_some_ffi_func(c_str.as_ptr());
     /// }
     /// ```
+    #[clippy::version = "0.0.64"]
     pub TEMPORARY_CSTRING_AS_PTR,
     correctness,
     "getting the inner pointer of a temporary `CString`"
@@ -757,6 +787,7 @@ declare_clippy_lint! {
     /// let bad_vec = some_vec.get(3);
     /// let bad_slice = &some_vec[..].get(3);
     /// ```
+    #[clippy::version = "0.0.77"]
     pub ITER_NTH,
     perf,
     "using `.iter().nth()` on a standard library type with O(1) element access"
@@ -781,6 +812,7 @@ declare_clippy_lint! {
     /// let bad_vec = some_vec.iter().nth(3);
     /// let bad_slice = &some_vec[..].iter().nth(3);
     /// ```
+    #[clippy::version = "0.0.96"]
     pub ITER_SKIP_NEXT,
     style,
     "using `.skip(x).next()` on an iterator"
@@ -814,6 +846,7 @@ declare_clippy_lint! {
     /// let last = some_vec[3];
     /// some_vec[0] = 1;
     /// ```
+    #[clippy::version = "0.0.99"]
     pub GET_UNWRAP,
     restriction,
     "using `.get().unwrap()` or `.get_mut().unwrap()` when using `[]` would work instead"
@@ -843,6 +876,7 @@ declare_clippy_lint! {
     /// s.push_str(abc);
     /// s.push_str(&def);
     /// ```
+    #[clippy::version = "0.0.101"]
     pub STRING_EXTEND_CHARS,
     style,
     "using `x.extend(s.chars())` where s is a `&str` or `String`"
@@ -866,6 +900,7 @@ declare_clippy_lint! {
     /// let s = [1, 2, 3, 4, 5];
     /// let s2: Vec<isize> = s.to_vec();
     /// ```
+    #[clippy::version = "0.0.212"]
     pub ITER_CLONED_COLLECT,
     style,
     "using `.cloned().collect()` on slice to create a `Vec`"
@@ -884,6 +919,7 @@ declare_clippy_lint! {
     /// ```ignore
     /// name.chars().last() == Some('_') || name.chars().next_back() == Some('-')
     /// ```
+    #[clippy::version = "0.0.162"]
     pub CHARS_LAST_CMP,
     style,
     "using `.chars().last()` or `.chars().next_back()` to check if a string ends with a char"
@@ -909,6 +945,7 @@ declare_clippy_lint! {
     /// let x: &[i32] = &[1, 2, 3, 4, 5];
     /// do_stuff(x);
     /// ```
+    #[clippy::version = "0.0.167"]
     pub USELESS_ASREF,
     complexity,
     "using `as_ref` where the types before and after the call are the same"
@@ -932,6 +969,7 @@ declare_clippy_lint! {
     /// ```rust
     /// let _ = (0..3).any(|x| x > 2);
     /// ```
+    #[clippy::version = "0.0.181"]
     pub UNNECESSARY_FOLD,
     style,
     "using `fold` when a more succinct alternative exists"
@@ -963,6 +1001,7 @@ declare_clippy_lint! {
     /// ```rust
     /// let _ = (0..4).map(i32::checked_abs);
     /// ```
+    #[clippy::version = "0.0.212"]
     pub UNNECESSARY_FILTER_MAP,
     complexity,
     "using `filter_map` when a more succinct alternative exists"
@@ -991,6 +1030,7 @@ declare_clippy_lint! {
     /// ```rust
     /// let _ = [1, 2, 3].iter().map(|x| *x).collect::<Vec<u32>>();
     /// ```
+    #[clippy::version = "0.0.212"]
     pub INTO_ITER_ON_ARRAY,
     correctness,
     "using `.into_iter()` on an array"
@@ -1011,6 +1051,7 @@ declare_clippy_lint! {
     /// ```rust
     /// let _ = (&vec![3, 4, 5]).into_iter();
     /// ```
+    #[clippy::version = "0.0.212"]
     pub INTO_ITER_ON_REF,
     style,
     "using `.into_iter()` on a reference"
@@ -1029,6 +1070,7 @@ declare_clippy_lint! {
     /// ```rust
     /// let _ = (0..3).map(|x| x + 2).count();
     /// ```
+    #[clippy::version = "0.0.212"]
     pub SUSPICIOUS_MAP,
     complexity,
     "suspicious usage of map"
@@ -1061,6 +1103,7 @@ declare_clippy_lint! {
     ///     MaybeUninit::uninit().assume_init()
     /// };
     /// ```
+    #[clippy::version = "0.0.212"]
     pub UNINIT_ASSUMED_INIT,
     correctness,
     "`MaybeUninit::uninit().assume_init()`"
@@ -1088,6 +1131,7 @@ declare_clippy_lint! {
     /// let add = x.saturating_add(y);
     /// let sub = x.saturating_sub(y);
     /// ```
+    #[clippy::version = "0.0.212"]
     pub MANUAL_SATURATING_ARITHMETIC,
     style,
     "`.chcked_add/sub(x).unwrap_or(MAX/MIN)`"
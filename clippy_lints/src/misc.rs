@@ -40,6 +40,7 @@ declare_clippy_lint! {
     ///     true
     /// }
     /// ```
+    #[clippy::version = "0.0.47"]
     pub TOPLEVEL_REF_ARG,
     style,
     "an entire binding declared as `ref`, in a function argument or a `let` statement"
@@ -60,6 +61,7 @@ declare_clippy_lint! {
     ///
     /// if x == NAN { }
     /// ```
+    #[clippy::version = "0.0.47"]
     pub CMP_NAN,
     correctness,
     "comparisons to NAN, which will always return false, probably not intended"
@@ -84,6 +86,7 @@ declare_clippy_lint! {
     /// if y == 1.23f64 { }
     /// if y != x {} // where both are floats
     /// ```
+    #[clippy::version = "0.0.47"]
     pub FLOAT_CMP,
     correctness,
     "using `==` or `!=` on float values instead of comparing difference with an epsilon"
@@ -111,6 +114,7 @@ declare_clippy_lint! {
     /// # let y = String::from("foo");
     /// if x == y {}
     /// ```
+    #[clippy::version = "0.0.47"]
     pub CMP_OWNED,
     perf,
     "creating owned instances for comparing with others, e.g., `x == \"foo\".to_string()`"
@@ -131,6 +135,7 @@ declare_clippy_lint! {
     /// # let x = 1;
     /// let a = x % 1;
     /// ```
+    #[clippy::version = "0.0.47"]
     pub MODULO_ONE,
     correctness,
     "taking a number modulo 1, which always returns 0"
@@ -153,6 +158,7 @@ declare_clippy_lint! {
     /// let y = _x + 1; // Here we are using `_x`, even though it has a leading
     ///                 // underscore. We should rename `_x` to `x`
     /// ```
+    #[clippy::version = "0.0.69"]
     pub USED_UNDERSCORE_BINDING,
     pedantic,
     "using a binding which is prefixed with an underscore"
@@ -173,6 +179,7 @@ declare_clippy_lint! {
     /// ```rust,ignore
     /// f() && g(); // We should write `if f() { g(); }`.
     /// ```
+    #[clippy::version = "0.0.107"]
     pub SHORT_CIRCUIT_STATEMENT,
     complexity,
     "using a short circuit boolean condition as a statement"
@@ -191,6 +198,7 @@ declare_clippy_lint! {
     /// ```rust
     /// let a = 0 as *const u32;
     /// ```
+    #[clippy::version = "0.0.115"]
     pub ZERO_PTR,
     style,
     "using 0 as *{const, mut} T"
@@ -214,6 +222,7 @@ declare_clippy_lint! {
     /// const ONE: f64 = 1.00;
     /// x == ONE;  // where both are floats
     /// ```
+    #[clippy::version = "0.0.212"]
     pub FLOAT_CMP_CONST,
     restriction,
     "using `==` or `!=` on float constants instead of comparing difference with an epsilon"
@@ -23,6 +23,7 @@ declare_clippy_lint! {
     /// ```ignore
     /// let { a: _, b: ref b, c: _ } = ..
     /// ```
+    #[clippy::version = "0.0.47"]
     pub UNNEEDED_FIELD_PATTERN,
     style,
     "struct fields bound to a wildcard instead of using `..`"
@@ -40,6 +41,7 @@ declare_clippy_lint! {
     /// ```rust
     /// fn foo(a: i32, _a: i32) {}
     /// ```
+    #[clippy::version = "0.0.47"]
     pub DUPLICATE_UNDERSCORE_ARGUMENT,
     style,
     "function arguments having names which only differ by an underscore"
@@ -58,6 +60,7 @@ declare_clippy_lint! {
     /// ```rust,ignore
     /// (|| 42)()
     /// ```
+    #[clippy::version = "0.0.47"]
     pub REDUNDANT_CLOSURE_CALL,
     complexity,
     "throwaway closures called in the expression they are defined"
@@ -76,6 +79,7 @@ declare_clippy_lint! {
     /// let mut x = 3;
     /// --x;
     /// ```
+    #[clippy::version = "0.0.78"]
     pub DOUBLE_NEG,
     style,
     "`--x`, which is a double negation of `x` and not a pre-decrement as in C/C++"
@@ -93,6 +97,7 @@ declare_clippy_lint! {
     /// ```rust
     /// let y = 0x1a9BAcD;
     /// ```
+    #[clippy::version = "0.0.81"]
     pub MIXED_CASE_HEX_LITERALS,
     style,
     "hex literals whose letter digits are not consistently upper- or lowercased"
@@ -110,6 +115,7 @@ declare_clippy_lint! {
     /// ```rust
     /// let y = 123832i32;
     /// ```
+    #[clippy::version = "0.0.81"]
     pub UNSEPARATED_LITERAL_SUFFIX,
     pedantic,
     "literals whose suffix is not separated by an underscore"
@@ -148,6 +154,7 @@ declare_clippy_lint! {
     /// ```
     ///
     /// prints `83` (as `83 == 0o123` while `123 == 0o173`).
+    #[clippy::version = "0.0.86"]
     pub ZERO_PREFIXED_LITERAL,
     complexity,
     "integer literals starting with `0`"
@@ -169,6 +176,7 @@ declare_clippy_lint! {
     ///     }
     /// }
     /// ```
+    #[clippy::version = "0.0.87"]
     pub BUILTIN_TYPE_SHADOW,
     style,
     "shadowing a builtin type"
@@ -191,6 +199,7 @@ declare_clippy_lint! {
     ///     y @ _ => (), // easier written as `y`,
     /// }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub REDUNDANT_PATTERN,
     style,
     "using `name @ _` in a pattern"
@@ -229,6 +238,7 @@ declare_clippy_lint! {
     ///     _ => (),
     /// }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub UNNEEDED_WILDCARD_PATTERN,
     complexity,
     "tuple patterns with a wildcard pattern (`_`) is next to a rest pattern (`..`)"
@@ -1,9 +1,10 @@
+use crate::utils::msrvs::{self, meets_msrv, RustcVersion};
 use crate::utils::{has_drop, is_entrypoint_fn, span_lint, trait_ref_of_method};
 use rustc::hir;
 use rustc::hir::intravisit::FnKind;
 use rustc::hir::{Body, Constness, FnDecl, HirId, HirVec};
 use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintPass};
-use rustc::{declare_lint_pass, declare_tool_lint};
+use rustc::{declare_tool_lint, impl_lint_pass};
 use rustc_mir::transform::qualify_min_const_fn::is_min_const_fn;
 use rustc_typeck::hir_ty_to_ty;
 use syntax_pos::Span;
@@ -63,14 +64,28 @@ declare_clippy_lint! {
     /// }
     /// # }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub MISSING_CONST_FOR_FN,
     nursery,
     "Lint functions definitions that could be made `const fn`"
 }
 
-declare_lint_pass!(MissingConstForFn => [MISSING_CONST_FOR_FN]);
+pub struct MissingConstForFn {
+    msrv: Option<RustcVersion>,
+}
+
+impl MissingConstForFn {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(MissingConstForFn => [MISSING_CONST_FOR_FN]);
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MissingConstForFn {
+    extract_msrv_attr!(LateContext);
+
     fn check_fn(
         &mut self,
         cx: &LateContext<'_, '_>,
@@ -80,6 +95,10 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MissingConstForFn {
         span: Span,
         hir_id: HirId,
     ) {
+        if !meets_msrv(self.msrv, msrvs::CONST_FN) {
+            return;
+        }
+
         let def_id = cx.tcx.hir().local_def_id(hir_id);
 
         if in_external_macro(cx.tcx.sess, span) || is_entrypoint_fn(cx, def_id) {
@@ -25,6 +25,7 @@ declare_clippy_lint! {
     /// This lint fixes that.
     ///
     /// **Known problems:** None.
+    #[clippy::version = "0.0.86"]
     pub MISSING_DOCS_IN_PRIVATE_ITEMS,
     restriction,
     "detects missing documentation for public and private members"
@@ -34,6 +34,7 @@ declare_clippy_lint! {
     /// # let c = 0_f32;
     /// let foo = a.mul_add(b, c);
     /// ```
+    #[clippy::version = "0.0.212"]
     pub MANUAL_MUL_ADD,
     perf,
     "Using `a.mul_add(b, c)` for floating points has higher numerical precision than `a * b + c`"
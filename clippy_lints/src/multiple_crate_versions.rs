@@ -1,11 +1,14 @@
 //! lint on multiple versions of a crate being used
 
-use crate::utils::span_lint;
+use crate::utils::manifest::{current_package, DependencyGraph, Manifest};
+use crate::utils::{span_lint, span_lint_and_then};
 use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
-use rustc::{declare_lint_pass, declare_tool_lint};
+use rustc::{declare_tool_lint, impl_lint_pass};
+use rustc_data_structures::fx::FxHashSet;
+use std::collections::BTreeMap;
 use syntax::{ast::*, source_map::DUMMY_SP};
 
-use cargo_metadata;
+use cargo_metadata::{Dependency, Package};
 use itertools::Itertools;
 
 declare_clippy_lint! {
@@ -17,7 +20,12 @@ declare_clippy_lint! {
     /// between different versions of a crate.
     ///
     /// **Known problems:** Because this can be caused purely by the dependencies
-    /// themselves, it's not always possible to fix this issue.
+    /// themselves, it's not always possible to fix this issue. The crates in the
+    /// `allowed-duplicate-crates` configuration aren't reported, and with
+    /// `duplicate-crates-direct-only = true` only the crates the package depends
+    /// on directly are. Only the dependencies of the checked package are
+    /// considered, including its dev-dependencies, not those of the other
+    /// packages of the workspace.
     ///
     /// **Example:**
     /// ```toml
@@ -26,12 +34,28 @@ declare_clippy_lint! {
     /// ctrlc = "3.1.0"
     /// ansi_term = "0.11.0"
     /// ```
+    #[clippy::version = "0.0.212"]
     pub MULTIPLE_CRATE_VERSIONS,
     cargo,
     "multiple versions of the same crate being used"
 }
 
-declare_lint_pass!(MultipleCrateVersions => [MULTIPLE_CRATE_VERSIONS]);
+#[derive(Clone)]
+pub struct MultipleCrateVersions {
+    allowed: FxHashSet<String>,
+    direct_only: bool,
+}
+
+impl MultipleCrateVersions {
+    pub fn new(allowed: &[String], direct_only: bool) -> Self {
+        Self {
+            allowed: allowed.iter().cloned().collect(),
+            direct_only,
+        }
+    }
+}
+
+impl_lint_pass!(MultipleCrateVersions => [MULTIPLE_CRATE_VERSIONS]);
 
 impl EarlyLintPass for MultipleCrateVersions {
     fn check_crate(&mut self, cx: &EarlyContext<'_>, _: &Crate) {
@@ -43,22 +67,150 @@ impl EarlyLintPass for MultipleCrateVersions {
             return;
         };
 
-        let mut packages = metadata.packages;
-        packages.sort_by(|a, b| a.name.cmp(&b.name));
+        let root = if let Some(package) = current_package(&metadata) {
+            package
+        } else {
+            return;
+        };
+        let manifest = Manifest::load(cx.sess, &root.manifest_path);
+        let graph = DependencyGraph::new(&metadata, &root.id);
 
-        for (name, group) in &packages.into_iter().group_by(|p| p.name.clone()) {
-            let group: Vec<cargo_metadata::Package> = group.collect();
+        for duplicate in self.duplicates(&graph) {
+            // the direct dependency if there is one, the dependencies of the package otherwise
+            let span = manifest.as_ref().map_or(DUMMY_SP, |manifest| {
+                duplicate
+                    .direct
+                    .and_then(|dep| manifest.dependency_span(dep))
+                    .or_else(|| manifest.span(&["dependencies"]))
+                    .unwrap_or_else(|| manifest.start_span())
+            });
 
-            if group.len() > 1 {
-                let versions = group.into_iter().map(|p| p.version).join(", ");
+            let versions = duplicate.packages.iter().map(|package| &package.version).join(", ");
+            span_lint_and_then(
+                cx,
+                MULTIPLE_CRATE_VERSIONS,
+                span,
+                &format!("multiple versions for dependency `{}`: {}", duplicate.name, versions),
+                |db| {
+                    for package in &duplicate.packages {
+                        db.note(&format!(
+                            "`{} {}` comes from `{}`",
+                            package.name,
+                            package.version,
+                            graph.path_to(&package.id)
+                        ));
+                    }
+                },
+            );
+        }
+    }
+}
 
-                span_lint(
-                    cx,
-                    MULTIPLE_CRATE_VERSIONS,
-                    DUMMY_SP,
-                    &format!("multiple versions for dependency `{}`: {}", name, versions),
-                );
+/// A crate the package depends on in several versions.
+struct Duplicate<'a> {
+    name: &'a str,
+    /// The versions, the oldest first.
+    packages: Vec<&'a Package>,
+    /// The dependency of the package on one of the versions, if any.
+    direct: Option<&'a Dependency>,
+}
+
+impl MultipleCrateVersions {
+    /// The crates of `graph` in several versions, by name, except the allowed ones.
+    fn duplicates<'a>(&self, graph: &DependencyGraph<'a>) -> Vec<Duplicate<'a>> {
+        let mut by_name: BTreeMap<&str, Vec<&Package>> = BTreeMap::new();
+        for &package in graph.packages.values() {
+            if graph.parents.contains_key(&package.id) {
+                by_name.entry(&package.name).or_default().push(package);
             }
         }
+
+        by_name
+            .into_iter()
+            .filter(|(name, packages)| packages.len() > 1 && !self.allowed.contains(*name))
+            .filter_map(|(name, mut packages)| {
+                packages.sort_by(|a, b| a.version.cmp(&b.version));
+                let direct = packages.iter().find(|package| graph.is_direct(&package.id));
+                if self.direct_only && direct.is_none() {
+                    return None;
+                }
+                Some(Duplicate {
+                    name,
+                    direct: direct.and_then(|package| graph.direct_dependency(&package.id)),
+                    packages,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MultipleCrateVersions;
+    use crate::utils::manifest::test::{dependency, id, metadata, package};
+    use crate::utils::manifest::DependencyGraph;
+    use itertools::Itertools;
+
+    fn duplicates(lint: &MultipleCrateVersions) -> Vec<String> {
+        let metadata = metadata(
+            &[
+                package(
+                    "a",
+                    "0.1.0",
+                    vec![dependency("b", "^1"), dependency("c", "^1"), dependency("d", "^1")],
+                ),
+                package("b", "1.0.0", vec![dependency("c", "^2"), dependency("e", "^2")]),
+                package("c", "1.0.0", vec![]),
+                package("c", "2.0.0", vec![]),
+                package("d", "1.0.0", vec![dependency("e", "^1")]),
+                package("e", "1.0.0", vec![]),
+                package("e", "2.0.0", vec![]),
+            ],
+            &[
+                ("a 0.1.0", "b 1.0.0", "b"),
+                ("a 0.1.0", "c 1.0.0", "c"),
+                ("a 0.1.0", "d 1.0.0", "d"),
+                ("b 1.0.0", "c 2.0.0", "c"),
+                ("b 1.0.0", "e 2.0.0", "e"),
+                ("d 1.0.0", "e 1.0.0", "e"),
+            ],
+        );
+        let root = id("a 0.1.0");
+        let graph = DependencyGraph::new(&metadata, &root);
+        lint.duplicates(&graph)
+            .iter()
+            .map(|duplicate| {
+                format!(
+                    "{} {} {:?}",
+                    duplicate.name,
+                    duplicate.packages.iter().map(|package| &package.version).join(", "),
+                    duplicate.direct.map(|dep| dep.req.to_string())
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn all() {
+        assert_eq!(
+            duplicates(&MultipleCrateVersions::new(&[], false)),
+            ["c 1.0.0, 2.0.0 Some(\"^1\")", "e 1.0.0, 2.0.0 None"]
+        );
+    }
+
+    #[test]
+    fn allowed_duplicate_crates() {
+        assert_eq!(
+            duplicates(&MultipleCrateVersions::new(&["c".to_string()], false)),
+            ["e 1.0.0, 2.0.0 None"]
+        );
+    }
+
+    #[test]
+    fn direct_only() {
+        assert_eq!(
+            duplicates(&MultipleCrateVersions::new(&[], true)),
+            ["c 1.0.0, 2.0.0 Some(\"^1\")"]
+        );
     }
 }
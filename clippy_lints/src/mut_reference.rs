@@ -18,6 +18,7 @@ declare_clippy_lint! {
     /// ```ignore
     /// my_vec.push(&mut value)
     /// ```
+    #[clippy::version = "0.0.47"]
     pub UNNECESSARY_MUT_PASSED,
     style,
     "an argument passed as a mutable reference although the callee only demands an immutable reference"
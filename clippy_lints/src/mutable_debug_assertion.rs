@@ -25,6 +25,7 @@ declare_clippy_lint! {
     /// fn take_a_mut_parameter(_: &mut u32) -> bool { unimplemented!() }
     /// debug_assert!(take_a_mut_parameter(&mut 5));
     /// ```
+    #[clippy::version = "0.0.212"]
     pub DEBUG_ASSERT_WITH_MUT_CALL,
     correctness,
     "mutable arguments in `debug_assert{,_ne,_eq}!`"
@@ -24,6 +24,7 @@ declare_clippy_lint! {
     /// ```
     ///
     /// **Known problems:** None.
+    #[clippy::version = "0.0.66"]
     pub NEEDLESS_BORROW,
     nursery,
     "taking a reference that is going to be automatically dereferenced"
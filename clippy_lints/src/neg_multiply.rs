@@ -18,6 +18,7 @@ declare_clippy_lint! {
     /// ```ignore
     /// x * -1
     /// ```
+    #[clippy::version = "0.0.212"]
     pub NEG_MULTIPLY,
     style,
     "multiplying integers with -1"
@@ -79,6 +79,7 @@ declare_clippy_lint! {
     /// ```
     ///
     /// You can also have `new()` call `Default::default()`.
+    #[clippy::version = "0.0.49"]
     pub NEW_WITHOUT_DEFAULT,
     style,
     "`fn new() -> Self` method without `Default` implementation"
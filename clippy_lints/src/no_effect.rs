@@ -19,6 +19,7 @@ declare_clippy_lint! {
     /// ```rust
     /// 0;
     /// ```
+    #[clippy::version = "0.0.47"]
     pub NO_EFFECT,
     complexity,
     "statements with no effect"
@@ -37,6 +38,7 @@ declare_clippy_lint! {
     /// ```rust,ignore
     /// compute_array()[0];
     /// ```
+    #[clippy::version = "0.0.68"]
     pub UNNECESSARY_OPERATION,
     complexity,
     "outer expressions with no effect"
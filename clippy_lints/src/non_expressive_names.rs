@@ -21,6 +21,7 @@ declare_clippy_lint! {
     /// let checked_exp = something;
     /// let checked_expr = something_else;
     /// ```
+    #[clippy::version = "0.0.56"]
     pub SIMILAR_NAMES,
     pedantic,
     "similarly named items and bindings"
@@ -39,6 +40,7 @@ declare_clippy_lint! {
     /// ```ignore
     /// let (a, b, c, d, e, f, g) = (...);
     /// ```
+    #[clippy::version = "0.0.56"]
     pub MANY_SINGLE_CHAR_NAMES,
     style,
     "too many single character bindings"
@@ -59,6 +61,7 @@ declare_clippy_lint! {
     /// let ___1 = 1;
     /// let __1___2 = 11;
     /// ```
+    #[clippy::version = "0.0.169"]
     pub JUST_UNDERSCORES_AND_DIGITS,
     style,
     "unclear name"
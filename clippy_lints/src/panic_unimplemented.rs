@@ -20,6 +20,7 @@ declare_clippy_lint! {
     /// ```no_run
     /// panic!("This `panic!` is probably missing a parameter there: {}");
     /// ```
+    #[clippy::version = "0.0.146"]
     pub PANIC_PARAMS,
     style,
     "missing parameters in `panic!` calls"
@@ -36,6 +37,7 @@ declare_clippy_lint! {
     /// ```no_run
     /// panic!("even with a good reason");
     /// ```
+    #[clippy::version = "0.0.212"]
     pub PANIC,
     restriction,
     "usage of the `panic!` macro"
@@ -52,6 +54,7 @@ declare_clippy_lint! {
     /// ```no_run
     /// unimplemented!();
     /// ```
+    #[clippy::version = "0.0.212"]
     pub UNIMPLEMENTED,
     restriction,
     "`unimplemented!` should not be present in production code"
@@ -68,6 +71,7 @@ declare_clippy_lint! {
     /// ```no_run
     /// todo!();
     /// ```
+    #[clippy::version = "0.0.212"]
     pub TODO,
     restriction,
     "`todo!` should not be present in production code"
@@ -84,6 +88,7 @@ declare_clippy_lint! {
     /// ```no_run
     /// unreachable!();
     /// ```
+    #[clippy::version = "0.0.212"]
     pub UNREACHABLE,
     restriction,
     "`unreachable!` should not be present in production code"
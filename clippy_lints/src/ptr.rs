@@ -47,6 +47,7 @@ declare_clippy_lint! {
     /// ```ignore
     /// fn foo(&Vec<u32>) { .. }
     /// ```
+    #[clippy::version = "0.0.162"]
     pub PTR_ARG,
     style,
     "fn arguments of the type `&Vec<...>` or `&String`, suggesting to use `&[...]` or `&str` instead, respectively"
@@ -67,6 +68,7 @@ declare_clippy_lint! {
     ///     ..
     /// }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub CMP_NULL,
     style,
     "comparing a pointer to a null pointer, suggesting to use `.is_null()` instead."
@@ -92,6 +94,7 @@ declare_clippy_lint! {
     /// ```ignore
     /// fn foo(&Foo) -> &mut Bar { .. }
     /// ```
+    #[clippy::version = "0.0.115"]
     pub MUT_FROM_REF,
     correctness,
     "fns that create mutable refs from immutable ref args"
@@ -1,7 +1,8 @@
 use crate::utils;
+use crate::utils::msrvs::{self, meets_msrv, RustcVersion};
 use rustc::hir::{Expr, ExprKind};
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
-use rustc::{declare_lint_pass, declare_tool_lint};
+use rustc::{declare_tool_lint, impl_lint_pass};
 use rustc_errors::Applicability;
 use std::fmt;
 
@@ -36,15 +37,33 @@ declare_clippy_lint! {
     ///     ptr.add(offset);
     /// }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub PTR_OFFSET_WITH_CAST,
     complexity,
     "unneeded pointer offset cast"
 }
 
-declare_lint_pass!(PtrOffsetWithCast => [PTR_OFFSET_WITH_CAST]);
+pub struct PtrOffsetWithCast {
+    msrv: Option<RustcVersion>,
+}
+
+impl PtrOffsetWithCast {
+    #[must_use]
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(PtrOffsetWithCast => [PTR_OFFSET_WITH_CAST]);
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for PtrOffsetWithCast {
+    extract_msrv_attr!(LateContext);
+
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if !meets_msrv(self.msrv, msrvs::POINTER_ADD_SUB) {
+            return;
+        }
+
         // Check if the expressions is a ptr.offset or ptr.wrapping_offset method call
         let (receiver_expr, arg_expr, method) = match expr_as_ptr_offset_call(cx, expr) {
             Some(call_arg) => call_arg,
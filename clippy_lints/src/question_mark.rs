@@ -29,6 +29,7 @@ declare_clippy_lint! {
     /// ```ignore
     /// option?;
     /// ```
+    #[clippy::version = "0.0.185"]
     pub QUESTION_MARK,
     style,
     "checks for expressions that could be replaced by the question mark operator"
@@ -37,6 +37,7 @@ declare_clippy_lint! {
     /// if Some(42).is_some() {}
     /// Ok::<i32, i32>(42).is_ok();
     /// ```
+    #[clippy::version = "0.0.212"]
     pub REDUNDANT_PATTERN_MATCHING,
     style,
     "use the proper utility function avoiding an `if let`"
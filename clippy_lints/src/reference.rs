@@ -19,6 +19,7 @@ declare_clippy_lint! {
     /// let a = f(*&mut b);
     /// let c = *&d;
     /// ```
+    #[clippy::version = "0.0.105"]
     pub DEREF_ADDROF,
     complexity,
     "use of `*&` or `*&mut` in an expression"
@@ -68,6 +69,7 @@ declare_clippy_lint! {
     /// let point = Point(30, 20);
     /// let x = (&point).0;
     /// ```
+    #[clippy::version = "0.0.212"]
     pub REF_IN_DEREF,
     complexity,
     "Use of reference in auto dereference expression."
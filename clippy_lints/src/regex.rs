@@ -23,6 +23,7 @@ declare_clippy_lint! {
     /// ```ignore
     /// Regex::new("|")
     /// ```
+    #[clippy::version = "0.0.70"]
     pub INVALID_REGEX,
     correctness,
     "invalid regular expressions"
@@ -42,6 +43,7 @@ declare_clippy_lint! {
     /// ```ignore
     /// Regex::new("^foobar")
     /// ```
+    #[clippy::version = "0.0.70"]
     pub TRIVIAL_REGEX,
     style,
     "trivial regular expressions"
@@ -61,6 +63,7 @@ declare_clippy_lint! {
     /// ```ignore
     /// regex!("foo|bar")
     /// ```
+    #[clippy::version = "0.0.212"]
     pub REGEX_MACRO,
     style,
     "use of `regex!(_)` instead of `Regex::new(_)`"
@@ -26,6 +26,7 @@ declare_clippy_lint! {
     /// # use core::sync::atomic::AtomicIsize;
     /// static FOO: AtomicIsize = AtomicIsize::new(0);
     /// ```
+    #[clippy::version = "0.0.212"]
     pub REPLACE_CONSTS,
     pedantic,
     "Lint usages of standard library `const`s that could be replaced by `const fn`s"
@@ -30,6 +30,7 @@ declare_clippy_lint! {
     ///     x
     /// }
     /// ```
+    #[clippy::version = "0.0.47"]
     pub NEEDLESS_RETURN,
     style,
     "using a return statement like `return expr;` where an expression would suffice"
@@ -57,6 +58,7 @@ declare_clippy_lint! {
     ///     String::new()
     /// }
     /// ```
+    #[clippy::version = "0.0.47"]
     pub LET_AND_RETURN,
     style,
     "creating a let-binding and then immediately returning it like `let x = expr; x` at the end of a block"
@@ -78,6 +80,7 @@ declare_clippy_lint! {
     ///     ()
     /// }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub UNUSED_UNIT,
     style,
     "needless unit expression"
@@ -13,6 +13,7 @@ declare_clippy_lint! {
     ///
     /// **Example:** Implementing `Visitor::visit_string` but not
     /// `Visitor::visit_str`.
+    #[clippy::version = "0.0.80"]
     pub SERDE_API_MISUSE,
     correctness,
     "various things that will negatively affect your serde experience"
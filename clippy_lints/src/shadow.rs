@@ -23,6 +23,7 @@ declare_clippy_lint! {
     /// # let x = 1;
     /// let x = &x;
     /// ```
+    #[clippy::version = "0.0.47"]
     pub SHADOW_SAME,
     restriction,
     "rebinding a name to itself, e.g., `let mut x = &mut x`"
@@ -50,6 +51,7 @@ declare_clippy_lint! {
     /// let x = 2;
     /// let y = x + 1;
     /// ```
+    #[clippy::version = "0.0.47"]
     pub SHADOW_REUSE,
     restriction,
     "rebinding a name to an expression that re-uses the original value, e.g., `let x = x + 1`"
@@ -75,6 +77,7 @@ declare_clippy_lint! {
     /// let x = y;
     /// let x = z; // shadows the earlier binding
     /// ```
+    #[clippy::version = "0.0.47"]
     pub SHADOW_UNRELATED,
     pedantic,
     "rebinding a name without even using the original value"
@@ -27,6 +27,7 @@ declare_clippy_lint! {
     /// let mut vec2 = Vec::with_capacity(len);
     /// vec2.extend(repeat(0).take(len))
     /// ```
+    #[clippy::version = "0.0.212"]
     pub SLOW_VECTOR_INITIALIZATION,
     perf,
     "slow vector initialization"
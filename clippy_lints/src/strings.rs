@@ -22,6 +22,7 @@ declare_clippy_lint! {
     /// let mut x = "Hello".to_owned();
     /// x = x + ", World";
     /// ```
+    #[clippy::version = "0.0.47"]
     pub STRING_ADD_ASSIGN,
     pedantic,
     "using `x = x + ..` where x is a `String` instead of `push_str()`"
@@ -50,6 +51,7 @@ declare_clippy_lint! {
     /// let x = "Hello".to_owned();
     /// x + ", World";
     /// ```
+    #[clippy::version = "0.0.47"]
     pub STRING_ADD,
     restriction,
     "using `x + ..` where x is a `String` instead of `push_str()`"
@@ -68,6 +70,7 @@ declare_clippy_lint! {
     /// ```rust
     /// let bs = "a byte string".as_bytes();
     /// ```
+    #[clippy::version = "0.0.212"]
     pub STRING_LIT_AS_BYTES,
     style,
     "calling `as_bytes` on a string literal instead of using a byte string literal"
@@ -23,6 +23,7 @@ declare_clippy_lint! {
     ///     }
     /// }
     /// ```
+    #[clippy::version = "0.0.187"]
     pub SUSPICIOUS_ARITHMETIC_IMPL,
     correctness,
     "suspicious use of operators in impl of arithmetic trait"
@@ -44,6 +45,7 @@ declare_clippy_lint! {
     ///     }
     /// }
     /// ```
+    #[clippy::version = "0.0.187"]
     pub SUSPICIOUS_OP_ASSIGN_IMPL,
     correctness,
     "suspicious use of operators in impl of OpAssign trait"
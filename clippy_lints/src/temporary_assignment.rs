@@ -18,6 +18,7 @@ declare_clippy_lint! {
     /// ```rust
     /// (0, 0).0 = 1
     /// ```
+    #[clippy::version = "0.0.47"]
     pub TEMPORARY_ASSIGNMENT,
     complexity,
     "assignments to temporaries"
@@ -35,6 +35,7 @@ declare_clippy_lint! {
     ///     Ok(0)
     /// }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub TRY_ERR,
     style,
     "return errors explicitly rather than hiding them behind a `?`"
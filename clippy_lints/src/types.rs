@@ -53,6 +53,7 @@ declare_clippy_lint! {
     ///     values: Vec<Foo>,
     /// }
     /// ```
+    #[clippy::version = "0.0.47"]
     pub BOX_VEC,
     perf,
     "usage of `Box<Vec<T>>`, vector elements are already on the heap"
@@ -81,6 +82,7 @@ declare_clippy_lint! {
     ///     values: Vec<i32>,
     /// }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub VEC_BOX,
     complexity,
     "usage of `Vec<Box<T>>` where T: Sized, vector elements are already on the heap"
@@ -102,6 +104,7 @@ declare_clippy_lint! {
     ///     None
     /// }
     /// ```
+    #[clippy::version = "0.0.181"]
     pub OPTION_OPTION,
     complexity,
     "usage of `Option<Option<T>>`"
@@ -138,6 +141,7 @@ declare_clippy_lint! {
     /// # use std::collections::LinkedList;
     /// let x: LinkedList<usize> = LinkedList::new();
     /// ```
+    #[clippy::version = "0.0.47"]
     pub LINKEDLIST,
     pedantic,
     "usage of LinkedList, usually a vector is faster, or a more specialized data structure like a VecDeque"
@@ -161,6 +165,7 @@ declare_clippy_lint! {
     /// ```rust,ignore
     /// fn foo(bar: &T) { ... }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub BORROWED_BOX,
     complexity,
     "a borrow of a boxed type"
@@ -453,6 +458,7 @@ declare_clippy_lint! {
     ///     1;
     /// };
     /// ```
+    #[clippy::version = "0.0.47"]
     pub LET_UNIT_VALUE,
     style,
     "creating a let binding to a value of unit type, which usually can't be used afterwards"
@@ -528,6 +534,7 @@ declare_clippy_lint! {
     /// assert_eq!({ foo(); }, { bar(); });
     /// ```
     /// will always succeed
+    #[clippy::version = "0.0.47"]
     pub UNIT_CMP,
     correctness,
     "comparing unit values"
@@ -601,6 +608,7 @@ declare_clippy_lint! {
     ///     baz(a);
     /// })
     /// ```
+    #[clippy::version = "0.0.181"]
     pub UNIT_ARG,
     complexity,
     "passing unit to a function"
@@ -701,6 +709,7 @@ declare_clippy_lint! {
     /// let x = std::u64::MAX;
     /// x as f64;
     /// ```
+    #[clippy::version = "0.0.47"]
     pub CAST_PRECISION_LOSS,
     pedantic,
     "casts that cause loss of precision, e.g., `x as f32` where `x: u64`"
@@ -722,6 +731,7 @@ declare_clippy_lint! {
     /// let y: i8 = -1;
     /// y as u128; // will return 18446744073709551615
     /// ```
+    #[clippy::version = "0.0.212"]
     pub CAST_SIGN_LOSS,
     pedantic,
     "casts from signed types to unsigned types, e.g., `x as u32` where `x: i32`"
@@ -744,6 +754,7 @@ declare_clippy_lint! {
     ///     x as u8
     /// }
     /// ```
+    #[clippy::version = "0.0.47"]
     pub CAST_POSSIBLE_TRUNCATION,
     pedantic,
     "casts that may cause truncation of the value, e.g., `x as u8` where `x: u32`, or `x as i32` where `x: f32`"
@@ -767,6 +778,7 @@ declare_clippy_lint! {
     /// ```rust
     /// std::u32::MAX as i32; // will yield a value of `-1`
     /// ```
+    #[clippy::version = "0.0.47"]
     pub CAST_POSSIBLE_WRAP,
     pedantic,
     "casts that may cause wrapping around the value, e.g., `x as i32` where `x: u32` and `x > i32::MAX`"
@@ -799,6 +811,7 @@ declare_clippy_lint! {
     ///     u64::from(x)
     /// }
     /// ```
+    #[clippy::version = "0.0.155"]
     pub CAST_LOSSLESS,
     pedantic,
     "casts using `as` that are known to be lossless, e.g., `x as u64` where `x: u8`"
@@ -815,6 +828,7 @@ declare_clippy_lint! {
     /// ```rust
     /// let _ = 2i32 as i32;
     /// ```
+    #[clippy::version = "0.0.212"]
     pub UNNECESSARY_CAST,
     complexity,
     "cast to the same type, e.g., `x as i32` where `x: i32`"
@@ -835,6 +849,7 @@ declare_clippy_lint! {
     /// let _ = (&1u8 as *const u8) as *const u16;
     /// let _ = (&mut 1u8 as *mut u8) as *mut u16;
     /// ```
+    #[clippy::version = "0.0.194"]
     pub CAST_PTR_ALIGNMENT,
     correctness,
     "cast from a pointer to a more-strictly-aligned pointer"
@@ -862,6 +877,7 @@ declare_clippy_lint! {
     /// fn fun2() -> i32 { 1 }
     /// let a = fun2 as usize;
     /// ```
+    #[clippy::version = "0.0.212"]
     pub FN_TO_NUMERIC_CAST,
     style,
     "casting a function pointer to a numeric type other than usize"
@@ -892,6 +908,7 @@ declare_clippy_lint! {
     /// let fn_ptr = fn2 as usize;
     /// let fn_ptr_truncated = fn_ptr as i32;
     /// ```
+    #[clippy::version = "0.0.212"]
     pub FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
     style,
     "casting a function pointer to a numeric type not wide enough to store the address"
@@ -1362,6 +1379,7 @@ declare_clippy_lint! {
     ///     inner: Rc<Vec<Vec<Box<(u32, u32, u32, u32)>>>>,
     /// }
     /// ```
+    #[clippy::version = "0.0.47"]
     pub TYPE_COMPLEXITY,
     complexity,
     "usage of very complex types that might be better factored into `type` definitions"
@@ -1531,6 +1549,7 @@ declare_clippy_lint! {
     /// ```rust,ignore
     /// b'x'
     /// ```
+    #[clippy::version = "0.0.47"]
     pub CHAR_LIT_AS_U8,
     complexity,
     "casting a character literal to u8 truncates"
@@ -1596,6 +1615,7 @@ declare_clippy_lint! {
     /// if vec.len() <= 0 {}
     /// if 100 > std::i32::MAX {}
     /// ```
+    #[clippy::version = "0.0.105"]
     pub ABSURD_EXTREME_COMPARISONS,
     correctness,
     "a comparison with a maximum or minimum value that is always true or false"
@@ -1766,6 +1786,7 @@ declare_clippy_lint! {
     /// let x: u8 = 1;
     /// (x as u32) > 300;
     /// ```
+    #[clippy::version = "0.0.61"]
     pub INVALID_UPCAST_COMPARISONS,
     pedantic,
     "a comparison involving an upcast which is always true or false"
@@ -2018,6 +2039,7 @@ declare_clippy_lint! {
     ///
     /// pub fn foo<S: BuildHasher>(map: &mut HashMap<i32, i32, S>) { }
     /// ```
+    #[clippy::version = "0.0.166"]
     pub IMPLICIT_HASHER,
     style,
     "missing generalization over different hashers"
@@ -2366,6 +2388,7 @@ declare_clippy_lint! {
     ///     }
     /// }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub CAST_REF_TO_MUT,
     correctness,
     "a cast of reference to a mutable pointer"
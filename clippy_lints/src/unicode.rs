@@ -17,6 +17,7 @@ declare_clippy_lint! {
     ///
     /// **Example:** You don't see it, but there may be a zero-width space
     /// somewhere in this text.
+    #[clippy::version = "0.0.47"]
     pub ZERO_WIDTH_SPACE,
     correctness,
     "using a zero-width space in a string literal, which is confusing"
@@ -41,6 +42,7 @@ declare_clippy_lint! {
     /// ```rust
     /// let x = String::from("\u{20ac}");
     /// ```
+    #[clippy::version = "0.0.47"]
     pub NON_ASCII_LITERAL,
     pedantic,
     "using any literal non-ASCII chars in a string literal instead of using the `\\u` escape"
@@ -58,6 +60,7 @@ declare_clippy_lint! {
     ///
     /// **Example:** You may not see it, but "à"" and "à"" aren't the same string. The
     /// former when escaped is actually `"a\u{300}"` while the latter is `"\u{e0}"`.
+    #[clippy::version = "0.0.47"]
     pub UNICODE_NOT_NFC,
     pedantic,
     "using a Unicode literal not in NFC normal form (see [Unicode tr15](http://www.unicode.org/reports/tr15/) for further information)"
@@ -0,0 +1,366 @@
+//! lint on dependencies the crate doesn't use, or only uses in its tests
+
+use crate::utils::manifest::{current_package, is_binary_of_library, is_library, is_only_target, Manifest};
+use crate::utils::{is_allowed, span_help_and_lint, span_lint};
+use cargo_metadata::{DependencyKind, Metadata, Package};
+use rustc::hir::def_id::{CrateNum, DefId, CRATE_DEF_INDEX};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_tool_lint, impl_lint_pass};
+use rustc_data_structures::fx::FxHashMap;
+use syntax::ast::{Attribute, NestedMetaItem};
+use syntax::source_map::{Span, DUMMY_SP};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for dependencies in `[dependencies]` that the crate never uses,
+    /// i.e. that rustc doesn't load to compile it.
+    ///
+    /// **Why is this bad?** Every dependency adds to the build time of the package and of the
+    /// packages depending on it.
+    ///
+    /// **Known problems:** Only the packages with a single library or binary target, or with a
+    /// library and a single binary, are checked, as each target is a different crate. The latter
+    /// are checked with the binary, which loads the crates the library depends on. The
+    /// dependencies that only the tests use are reported too when the tests aren't checked, see
+    /// `test_only_dependencies`, and so are those that only the integration tests, the examples or
+    /// the benchmarks use, which belong in `[dev-dependencies]`. The `extern crate` items of the
+    /// 2015 edition load the crate even if it is unused, `unused_extern_crates` reports those.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// # the crate never mentions `regex`
+    /// [dependencies]
+    /// regex = "1"
+    /// ```
+    #[clippy::version = "0.0.212"]
+    pub UNUSED_DEPENDENCIES,
+    cargo,
+    "dependencies that the crate doesn't use"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for dependencies in `[dependencies]` that the crate only uses in
+    /// `#[cfg(test)]` code. Only reported when the tests are checked, e.g. by
+    /// `cargo clippy --all-targets`.
+    ///
+    /// **Why is this bad?** They belong in `[dev-dependencies]`, which the packages depending on
+    /// this one don't build.
+    ///
+    /// **Known problems:** Only the packages with a single library or binary target are checked.
+    /// The `#[test]` functions outside of a `#[cfg(test)]` module don't count as tests.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// # `tempfile` is only used in `#[cfg(test)] mod tests`
+    /// [dependencies]
+    /// tempfile = "3"
+    /// ```
+    /// Could be written as:
+    /// ```toml
+    /// [dev-dependencies]
+    /// tempfile = "3"
+    /// ```
+    #[clippy::version = "0.0.212"]
+    pub TEST_ONLY_DEPENDENCIES,
+    cargo,
+    "dependencies only used by the tests, that belong in `[dev-dependencies]`"
+}
+
+/// A dependency of `[dependencies]` that cargo passed to rustc.
+struct Dependency {
+    /// The name of its crate, as the package may name its library differently.
+    krate: String,
+    /// The name of the dependency in `Cargo.toml`.
+    name: String,
+    span: Span,
+    used_in_tests: bool,
+    used_outside_tests: bool,
+}
+
+#[derive(Default)]
+pub struct UnusedDependencies {
+    dependencies: Vec<Dependency>,
+    /// The index in `dependencies` of the loaded crates.
+    crates: FxHashMap<CrateNum, usize>,
+    /// Whether the crate is the binary of a package with a library, whose dependencies it loads.
+    binary_of_library: bool,
+}
+
+impl_lint_pass!(UnusedDependencies => [UNUSED_DEPENDENCIES, TEST_ONLY_DEPENDENCIES]);
+
+impl UnusedDependencies {
+    fn check_use(&mut self, cx: &LateContext<'_, '_>, krate: Option<CrateNum>, hir_id: HirId) {
+        if let Some(&index) = krate.and_then(|krate| self.crates.get(&krate)) {
+            if is_in_cfg_test(cx, hir_id) {
+                self.dependencies[index].used_in_tests = true;
+            } else {
+                self.dependencies[index].used_outside_tests = true;
+            }
+        }
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnusedDependencies {
+    fn check_crate(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        if is_allowed(cx, UNUSED_DEPENDENCIES, CRATE_HIR_ID) && is_allowed(cx, TEST_ONLY_DEPENDENCIES, CRATE_HIR_ID) {
+            return;
+        }
+
+        let metadata = if let Ok(metadata) = cargo_metadata::MetadataCommand::new().exec() {
+            metadata
+        } else {
+            span_lint(cx, UNUSED_DEPENDENCIES, DUMMY_SP, "could not read cargo metadata");
+            return;
+        };
+        let package = match current_package(&metadata) {
+            Some(package) if is_only_target(cx.sess(), package) => package,
+            Some(package) if is_binary_of_library(cx.sess(), package) => {
+                self.binary_of_library = true;
+                package
+            },
+            _ => return,
+        };
+        let manifest = Manifest::load(cx.sess(), &package.manifest_path);
+
+        self.dependencies = declared_dependencies(&metadata, package)
+            .filter(|(name, _, _)| cx.sess().opts.externs.get(name).is_some())
+            .map(|(name, krate, dependency)| {
+                let span = manifest.as_ref().map_or(DUMMY_SP, |manifest| {
+                    manifest
+                        .dependency_span(dependency)
+                        .or_else(|| manifest.span(&["dependencies"]))
+                        .unwrap_or_else(|| manifest.start_span())
+                });
+                Dependency {
+                    krate,
+                    name,
+                    span,
+                    used_in_tests: false,
+                    used_outside_tests: false,
+                }
+            })
+            .collect();
+
+        for &cnum in cx.tcx.crates().iter() {
+            let name = cx.tcx.crate_name(cnum).as_str();
+            if let Some(index) = self
+                .dependencies
+                .iter()
+                .position(|dependency| *dependency.krate == *name)
+            {
+                self.crates.insert(cnum, index);
+            }
+        }
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if let ItemKind::ExternCrate(..) = item.kind {
+            let cnum = cx.tcx.extern_mod_stmt_cnum(cx.tcx.hir().local_def_id(item.hir_id));
+            self.check_use(cx, cnum, item.hir_id);
+        }
+    }
+
+    fn check_path(&mut self, cx: &LateContext<'a, 'tcx>, path: &'tcx Path, hir_id: HirId) {
+        self.check_use(cx, path.res.opt_def_id().map(|def_id| def_id.krate), hir_id);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        match expr.kind {
+            ExprKind::MethodCall(..) | ExprKind::Path(QPath::TypeRelative(..)) => {
+                let def_id = cx.tables.type_dependent_def_id(expr.hir_id);
+                self.check_use(cx, def_id.map(|def_id| def_id.krate), expr.hir_id);
+            },
+            _ => {},
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        let loaded = |index: usize| {
+            self.crates.iter().any(|(&cnum, &i)| {
+                i == index
+                    && cx
+                        .tcx
+                        .extern_crate(DefId {
+                            krate: cnum,
+                            index: CRATE_DEF_INDEX,
+                        })
+                        .map_or(false, |extern_crate| extern_crate.direct)
+            })
+        };
+
+        for (index, dependency) in self.dependencies.iter().enumerate() {
+            // the crates of the library are loaded whether the binary uses them or not
+            let unused = if self.binary_of_library {
+                !self.crates.values().any(|&i| i == index)
+            } else {
+                !loaded(index)
+            };
+            if unused {
+                // with `--all-targets`, the build without the tests already reports it
+                if !cx.sess().opts.test {
+                    span_help_and_lint(
+                        cx,
+                        UNUSED_DEPENDENCIES,
+                        dependency.span,
+                        &format!("unused dependency `{}`", dependency.name),
+                        "remove it from `[dependencies]`, or move it to `[dev-dependencies]` if only the tests, the \
+                         examples or the benchmarks use it",
+                    );
+                }
+            } else if !self.binary_of_library && dependency.used_in_tests && !dependency.used_outside_tests {
+                span_help_and_lint(
+                    cx,
+                    TEST_ONLY_DEPENDENCIES,
+                    dependency.span,
+                    &format!("dependency `{}` is only used by the tests", dependency.name),
+                    "move it to `[dev-dependencies]`",
+                );
+            }
+        }
+    }
+}
+
+/// The dependencies of `[dependencies]` of `package`, with their names as passed to rustc and the
+/// names of their crates.
+fn declared_dependencies<'a>(
+    metadata: &'a Metadata,
+    package: &'a Package,
+) -> impl Iterator<Item = (String, String, &'a cargo_metadata::Dependency)> + 'a {
+    let node = metadata
+        .resolve
+        .as_ref()
+        .and_then(|resolve| resolve.nodes.iter().find(|node| node.id == package.id));
+    node.into_iter()
+        .flat_map(|node| &node.deps)
+        .filter_map(move |node_dep| {
+            let target = metadata
+                .packages
+                .iter()
+                .find(|dependency| dependency.id == node_dep.pkg)?;
+            let krate = target
+                .targets
+                .iter()
+                .find(|target| is_library(target))?
+                .name
+                .replace('-', "_");
+            let dependency = package.dependencies.iter().find(|dependency| {
+                let name = dependency
+                    .rename
+                    .as_ref()
+                    .map_or_else(|| krate.clone(), |rename| rename.replace('-', "_"));
+                dependency.kind == DependencyKind::Normal && dependency.name == target.name && name == node_dep.name
+            })?;
+            Some((node_dep.name.clone(), krate, dependency))
+        })
+}
+
+/// Returns `true` if the node is in an item with `#[cfg(test)]`.
+fn is_in_cfg_test(cx: &LateContext<'_, '_>, mut hir_id: HirId) -> bool {
+    let map = cx.tcx.hir();
+    loop {
+        if map.attrs(hir_id).iter().any(is_cfg_test) {
+            return true;
+        }
+        let parent = map.get_parent_item(hir_id);
+        if parent == hir_id {
+            return false;
+        }
+        hir_id = parent;
+    }
+}
+
+/// Returns `true` for `#[cfg(test)]`, and for `#[cfg(all(test, ..))]`.
+fn is_cfg_test(attr: &Attribute) -> bool {
+    fn requires_test(items: &[NestedMetaItem]) -> bool {
+        items.iter().any(|item| {
+            item.check_name(sym!(test))
+                || (item.check_name(sym!(all)) && item.meta_item_list().map_or(false, requires_test))
+        })
+    }
+
+    attr.check_name(sym!(cfg)) && attr.meta_item_list().map_or(false, |items| requires_test(&items))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{declared_dependencies, is_cfg_test};
+    use crate::utils::manifest::test::{dependency, metadata, package};
+    use serde_json::json;
+    use syntax::ast::{Ident, NestedMetaItem};
+    use syntax::attr;
+
+    #[test]
+    fn declared() {
+        let mut renamed = dependency("foo-bar", "^1");
+        renamed["rename"] = json!("foo-baz");
+        let mut dev = dependency("tempfile", "^3");
+        dev["kind"] = json!("dev");
+        let mut md5 = package("md-5", "0.8.0", vec![]);
+        md5["targets"][0]["name"] = json!("md5");
+        let metadata = metadata(
+            &[
+                package(
+                    "a",
+                    "0.1.0",
+                    vec![
+                        dependency("regex-syntax", "^0.6"),
+                        dependency("md-5", "^0.8"),
+                        renamed,
+                        dev,
+                    ],
+                ),
+                package("regex-syntax", "0.6.0", vec![]),
+                md5,
+                package("foo-bar", "1.0.0", vec![]),
+                package("tempfile", "3.0.0", vec![]),
+            ],
+            &[
+                ("a 0.1.0", "regex-syntax 0.6.0", "regex_syntax"),
+                ("a 0.1.0", "md-5 0.8.0", "md5"),
+                ("a 0.1.0", "foo-bar 1.0.0", "foo_baz"),
+                ("a 0.1.0", "tempfile 3.0.0", "tempfile"),
+            ],
+        );
+
+        let declared: Vec<_> = declared_dependencies(&metadata, &metadata.packages[0])
+            .map(|(name, krate, dependency)| (name, krate, dependency.name.clone()))
+            .collect();
+        let expected = [
+            ("regex_syntax", "regex_syntax", "regex-syntax"),
+            ("md5", "md5", "md-5"),
+            ("foo_baz", "foo_bar", "foo-bar"),
+        ];
+        assert_eq!(
+            declared,
+            expected
+                .iter()
+                .map(|(name, krate, dependency)| (name.to_string(), krate.to_string(), dependency.to_string()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn cfg_test() {
+        syntax::with_default_globals(|| {
+            let word = |name| attr::mk_nested_word_item(Ident::from_str(name));
+            let list = |name, items| attr::mk_list_item(Ident::from_str(name), items);
+            let cfg = |items: Vec<NestedMetaItem>| attr::mk_attr_outer(list("cfg", items));
+
+            assert!(is_cfg_test(&cfg(vec![word("test")])));
+            assert!(is_cfg_test(&cfg(vec![NestedMetaItem::MetaItem(list(
+                "all",
+                vec![word("unix"), word("test")]
+            ))])));
+            assert!(!is_cfg_test(&cfg(vec![word("unix")])));
+            assert!(!is_cfg_test(&cfg(vec![NestedMetaItem::MetaItem(list(
+                "not",
+                vec![word("test")]
+            ))])));
+            assert!(!is_cfg_test(&cfg(vec![NestedMetaItem::MetaItem(list(
+                "any",
+                vec![word("unix"), word("test")]
+            ))])));
+            assert!(!is_cfg_test(&attr::mk_attr_outer(list("allow", vec![word("test")]))));
+        });
+    }
+}
@@ -25,6 +25,7 @@ declare_clippy_lint! {
     ///     Ok(())
     /// }
     /// ```
+    #[clippy::version = "0.0.107"]
     pub UNUSED_IO_AMOUNT,
     correctness,
     "unused written/read amount"
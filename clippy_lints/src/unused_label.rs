@@ -22,6 +22,7 @@ declare_clippy_lint! {
     ///         if i > 4 { continue }
     ///     }
     /// ```
+    #[clippy::version = "0.0.49"]
     pub UNUSED_LABEL,
     complexity,
     "unused labels"
@@ -31,6 +31,7 @@ declare_clippy_lint! {
     ///     fn method() {}
     /// }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub UNUSED_SELF,
     pedantic,
     "methods that contain a `self` argument but don't use it"
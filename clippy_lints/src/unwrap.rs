@@ -32,6 +32,7 @@ declare_clippy_lint! {
     ///     do_something_with(value)
     /// }
     /// ```
+    #[clippy::version = "0.0.212"]
     pub UNNECESSARY_UNWRAP,
     complexity,
     "checks for calls of unwrap[_err]() that cannot fail"
@@ -55,6 +56,7 @@ declare_clippy_lint! {
     /// ```
     ///
     /// This code will always panic. The if condition should probably be inverted.
+    #[clippy::version = "0.0.212"]
     pub PANICKING_UNWRAP,
     correctness,
     "checks for calls of unwrap[_err]() that will always fail"
@@ -21,6 +21,8 @@ pub const BUILTIN_ATTRIBUTES: &[(&str, DeprecationStatus)] = &[
         DeprecationStatus::Replaced("cognitive_complexity"),
     ),
     ("dump", DeprecationStatus::None),
+    ("msrv", DeprecationStatus::None),
+    ("version", DeprecationStatus::None),
 ];
 
 pub struct LimitStack {
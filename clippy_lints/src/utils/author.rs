@@ -9,7 +9,11 @@ use rustc::lint::{LateContext, LateLintPass, LintArray, LintContext, LintPass};
 use rustc::session::Session;
 use rustc::{declare_lint_pass, declare_tool_lint};
 use rustc_data_structures::fx::FxHashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
 use syntax::ast::{Attribute, LitKind};
+use syntax::source_map::Span;
 
 declare_clippy_lint! {
     /// **What it does:** Generates clippy code that detects the offending pattern
@@ -44,6 +48,15 @@ declare_clippy_lint! {
     ///     }
     /// }
     /// ```
+    ///
+    /// `#[clippy::author(generalize)]` matches any literal value, path and binding name instead
+    /// of the ones of the example.
+    ///
+    /// `#[clippy::author(lint = "my_lint")]` on an expression or a statement generates a whole
+    /// lint module `my_lint` checking the pattern, and a ui test with the annotated code. They
+    /// are printed, or written to `clippy_lints/src/my_lint.rs` and `tests/ui/my_lint.rs` in
+    /// the directory set in the `CLIPPY_AUTHOR_DIR` environment variable.
+    #[clippy::version = "0.0.212"]
     pub LINT_AUTHOR,
     internal_warn,
     "helper for writing lints"
@@ -51,106 +64,354 @@ declare_clippy_lint! {
 
 declare_lint_pass!(Author => [LINT_AUTHOR]);
 
-fn prelude() {
-    println!("if_chain! {{");
+/// The directory the lint modules are written to, instead of being printed.
+const AUTHOR_DIR_ENV: &str = "CLIPPY_AUTHOR_DIR";
+
+/// The arguments of `#[clippy::author]`.
+#[derive(Default)]
+struct Options {
+    /// The name of the lint module to generate, if any.
+    lint: Option<String>,
+    /// Whether to match any literal value, path and binding name.
+    generalize: bool,
 }
 
-fn done() {
-    println!("    then {{");
-    println!("        // report your lint here");
-    println!("    }}");
-    println!("}}");
+/// Like `print!`, to the output of a `PrintVisitor`.
+macro_rules! out {
+    ($visitor:expr, $($arg:tt)*) => {
+        $visitor.out.push_str(&format!($($arg)*))
+    };
+}
+
+/// Like `println!`, to the output of a `PrintVisitor`.
+macro_rules! outln {
+    ($visitor:expr, $($arg:tt)*) => {{
+        out!($visitor, $($arg)*);
+        $visitor.out.push('\n');
+    }};
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Author {
     fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::Item) {
-        if !has_attr(cx.sess(), &item.attrs) {
-            return;
+        if let Some(options) = author_options(cx.sess(), &item.attrs) {
+            let mut visitor = PrintVisitor::new("item", &options);
+            visitor.visit_item(item);
+            report(cx, &options, &visitor, item.span);
         }
-        prelude();
-        PrintVisitor::new("item").visit_item(item);
-        done();
     }
 
     fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::ImplItem) {
-        if !has_attr(cx.sess(), &item.attrs) {
-            return;
+        if let Some(options) = author_options(cx.sess(), &item.attrs) {
+            let mut visitor = PrintVisitor::new("item", &options);
+            visitor.visit_impl_item(item);
+            report(cx, &options, &visitor, item.span);
         }
-        prelude();
-        PrintVisitor::new("item").visit_impl_item(item);
-        done();
     }
 
     fn check_trait_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::TraitItem) {
-        if !has_attr(cx.sess(), &item.attrs) {
-            return;
+        if let Some(options) = author_options(cx.sess(), &item.attrs) {
+            let mut visitor = PrintVisitor::new("item", &options);
+            visitor.visit_trait_item(item);
+            report(cx, &options, &visitor, item.span);
         }
-        prelude();
-        PrintVisitor::new("item").visit_trait_item(item);
-        done();
     }
 
     fn check_variant(&mut self, cx: &LateContext<'a, 'tcx>, var: &'tcx hir::Variant) {
-        if !has_attr(cx.sess(), &var.attrs) {
-            return;
+        if let Some(options) = author_options(cx.sess(), &var.attrs) {
+            let mut visitor = PrintVisitor::new("var", &options);
+            visitor.visit_variant(var, &hir::Generics::empty(), hir::DUMMY_HIR_ID);
+            report(cx, &options, &visitor, var.span);
         }
-        prelude();
-        PrintVisitor::new("var").visit_variant(var, &hir::Generics::empty(), hir::DUMMY_HIR_ID);
-        done();
     }
 
     fn check_struct_field(&mut self, cx: &LateContext<'a, 'tcx>, field: &'tcx hir::StructField) {
-        if !has_attr(cx.sess(), &field.attrs) {
-            return;
+        if let Some(options) = author_options(cx.sess(), &field.attrs) {
+            let mut visitor = PrintVisitor::new("field", &options);
+            visitor.visit_struct_field(field);
+            report(cx, &options, &visitor, field.span);
         }
-        prelude();
-        PrintVisitor::new("field").visit_struct_field(field);
-        done();
     }
 
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx hir::Expr) {
-        if !has_attr(cx.sess(), &expr.attrs) {
-            return;
+        if let Some(options) = author_options(cx.sess(), &expr.attrs) {
+            let mut visitor = PrintVisitor::new("expr", &options);
+            visitor.visit_expr(expr);
+            report(cx, &options, &visitor, expr.span);
         }
-        prelude();
-        PrintVisitor::new("expr").visit_expr(expr);
-        done();
     }
 
     fn check_arm(&mut self, cx: &LateContext<'a, 'tcx>, arm: &'tcx hir::Arm) {
-        if !has_attr(cx.sess(), &arm.attrs) {
-            return;
+        if let Some(options) = author_options(cx.sess(), &arm.attrs) {
+            let mut visitor = PrintVisitor::new("arm", &options);
+            visitor.visit_arm(arm);
+            report(cx, &options, &visitor, arm.span);
         }
-        prelude();
-        PrintVisitor::new("arm").visit_arm(arm);
-        done();
     }
 
     fn check_stmt(&mut self, cx: &LateContext<'a, 'tcx>, stmt: &'tcx hir::Stmt) {
-        if !has_attr(cx.sess(), stmt.kind.attrs()) {
-            return;
+        if let Some(options) = author_options(cx.sess(), stmt.kind.attrs()) {
+            let mut visitor = PrintVisitor::new("stmt", &options);
+            visitor.visit_stmt(stmt);
+            report(cx, &options, &visitor, stmt.span);
         }
-        prelude();
-        PrintVisitor::new("stmt").visit_stmt(stmt);
-        done();
     }
 
     fn check_foreign_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::ForeignItem) {
-        if !has_attr(cx.sess(), &item.attrs) {
+        if let Some(options) = author_options(cx.sess(), &item.attrs) {
+            let mut visitor = PrintVisitor::new("item", &options);
+            visitor.visit_foreign_item(item);
+            report(cx, &options, &visitor, item.span);
+        }
+    }
+}
+
+/// Prints the conditions found by `visitor`, or generates the lint module checking them.
+fn report(cx: &LateContext<'_, '_>, options: &Options, visitor: &PrintVisitor, span: Span) {
+    let lint_name = match options.lint {
+        Some(ref lint_name) => lint_name,
+        None => {
+            print!("{}", if_chain(&visitor.out, "// report your lint here", ""));
             return;
+        },
+    };
+
+    let (method, ty) = match visitor.root {
+        "expr" => ("check_expr", "Expr"),
+        "stmt" => ("check_stmt", "Stmt"),
+        _ => {
+            cx.sess().span_err(
+                span,
+                "`#[clippy::author(lint = ..)]` only supports expressions and statements",
+            );
+            return;
+        },
+    };
+    let snippet = cx.sess().source_map().span_to_snippet(span).unwrap_or_default();
+    let column = cx.sess().source_map().lookup_char_pos(span.lo()).col.0;
+    let snippet = reindent(&snippet, column);
+
+    let module = lint_module(lint_name, method, ty, &visitor.out, &snippet);
+    let test = lint_test(lint_name, visitor.root, &snippet);
+    let module_path = format!("clippy_lints/src/{}.rs", lint_name);
+    let test_path = format!("tests/ui/{}.rs", lint_name);
+
+    if let Some(dir) = std::env::var_os(AUTHOR_DIR_ENV) {
+        let dir = Path::new(&dir);
+        let result =
+            write_new_file(&dir.join(&module_path), &module).and_then(|_| write_new_file(&dir.join(&test_path), &test));
+        match result {
+            Ok(()) => cx.sess().note_without_error(&format!(
+                "wrote the lint to `{}` and its test to `{}`, the pass still needs to be registered in \
+                 `clippy_lints/src/lib.rs`",
+                module_path, test_path
+            )),
+            Err(e) => cx
+                .sess()
+                .span_err(span, &format!("error writing the `{}` lint: {}", lint_name, e)),
+        }
+    } else {
+        print!("// {}\n{}\n// {}\n{}", module_path, module, test_path, test);
+    }
+}
+
+/// Reads the arguments of the `#[clippy::author]` attribute in `attrs`, if there is one.
+fn author_options(sess: &Session, attrs: &[Attribute]) -> Option<Options> {
+    let attr = get_attr(sess, attrs, "author").next()?;
+    let mut options = Options::default();
+    for arg in attr.meta_item_list().unwrap_or_default() {
+        if arg.check_name(sym!(generalize)) {
+            options.generalize = true;
+        } else if let (true, Some(lint_name)) = (arg.check_name(sym!(lint)), arg.value_str()) {
+            let lint_name = lint_name.to_string();
+            if is_snake_case(&lint_name) {
+                options.lint = Some(lint_name);
+            } else {
+                sess.span_err(arg.span(), "the lint name must be in snake case");
+            }
+        } else {
+            sess.span_err(
+                arg.span(),
+                "unknown argument of `clippy::author`, expected `lint = \"name\"` or `generalize`",
+            );
         }
-        prelude();
-        PrintVisitor::new("item").visit_foreign_item(item);
-        done();
     }
+    Some(options)
+}
+
+/// Whether `name` is a valid lint name: lowercase ASCII words separated by single underscores. Keep
+/// in sync with `clippy_dev/src/new_lint.rs`, the generated lint is added with the same rules.
+fn is_snake_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && !name.ends_with('_')
+        && !name.contains("__")
+}
+
+/// Converts `foo_bar` to `FooBar`, the name of the lint pass. Keep in sync with
+/// `clippy_dev/src/new_lint.rs`.
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Removes the indentation `column` of the first line from the next lines of `snippet`.
+fn reindent(snippet: &str, column: usize) -> String {
+    let indent = " ".repeat(column);
+    snippet
+        .lines()
+        .map(|line| {
+            if line.starts_with(&indent) {
+                line[indent.len()..].trim_end()
+            } else {
+                line.trim()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Wraps `conditions` in an `if_chain!` running `then`, indented by `indent`.
+fn if_chain(conditions: &str, then: &str, indent: &str) -> String {
+    let mut chain = format!("{}if_chain! {{\n", indent);
+    for condition in conditions.lines() {
+        chain.push_str(indent);
+        chain.push_str(condition);
+        chain.push('\n');
+    }
+    chain.push_str(&format!(
+        "{0}    then {{\n{0}        {1}\n{0}    }}\n{0}}}\n",
+        indent, then
+    ));
+    chain
+}
+
+/// The module of the lint `lint_name`, checking the `conditions` in its `method`.
+fn lint_module(lint_name: &str, method: &str, ty: &str, conditions: &str, snippet: &str) -> String {
+    let lint_upper = lint_name.to_uppercase();
+    let root = ty.to_lowercase();
+
+    let mut utils = vec!["span_lint"];
+    if conditions.contains("higher::") {
+        utils.push("higher");
+    }
+    if conditions.contains("match_qpath(") {
+        utils.push("match_qpath");
+    }
+    utils.sort();
+    let utils = if utils.len() == 1 {
+        utils[0].to_string()
+    } else {
+        format!("{{{}}}", utils.join(", "))
+    };
+    let lit_kind = if conditions.contains("LitKind::") {
+        "\nuse syntax::ast::LitKind;"
+    } else {
+        ""
+    };
+    let example: String = snippet
+        .lines()
+        .map(|line| format!("    /// {}\n", line).replace("///  \n", "///\n"))
+        .collect();
+    let report = format!(
+        "span_lint(cx, {}, {}.span, \"default lint message\");",
+        lint_upper, root
+    );
+
+    format!(
+        "use crate::utils::{utils};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{{LateContext, LateLintPass, LintArray, LintPass}};
+use rustc::{{declare_lint_pass, declare_tool_lint}};{lit_kind}
+
+declare_clippy_lint! {{
+    /// **What it does:**
+    ///
+    /// **Why is this bad?**
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    ///
+    /// ```rust,ignore
+{example}    /// ```
+    #[clippy::version = \"{version}\"]
+    pub {lint_upper},
+    nursery,
+    \"default lint description\"
+}}
+
+declare_lint_pass!({camel} => [{lint_upper}]);
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for {camel} {{
+    fn {method}(&mut self, cx: &LateContext<'a, 'tcx>, {root}: &'tcx {ty}) {{
+{chain}    }}
+}}
+",
+        utils = utils,
+        lit_kind = lit_kind,
+        example = example,
+        version = env!("CARGO_PKG_VERSION"),
+        lint_upper = lint_upper,
+        camel = camel_case(lint_name),
+        method = method,
+        root = root,
+        ty = ty,
+        chain = if_chain(conditions, &report, "        "),
+    )
+}
+
+/// The ui test of the lint `lint_name`, with the code the lint was generated from.
+fn lint_test(lint_name: &str, root: &str, snippet: &str) -> String {
+    let mut code = if root == "expr" {
+        format!("let _ = {};", snippet)
+    } else {
+        snippet.to_string()
+    };
+    if !code.ends_with(';') && !code.ends_with('}') {
+        code.push(';');
+    }
+    let code: String = code
+        .lines()
+        .map(|line| format!("    {}\n", line).replace("    \n", "\n"))
+        .collect();
+
+    format!("#![warn(clippy::{})]\n\nfn main() {{\n{}}}\n", lint_name, code)
+}
+
+fn write_new_file(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    // `create_new` fails if the file exists, so an existing lint is never overwritten.
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)?
+        .write_all(contents.as_bytes())
 }
 
 impl PrintVisitor {
     #[must_use]
-    fn new(s: &'static str) -> Self {
+    fn new(s: &'static str, options: &Options) -> Self {
+        let mut ids = FxHashMap::default();
+        if options.lint.is_some() {
+            // the root is the parameter of the generated `check_*` method, don't shadow it
+            ids.insert(s, 0);
+        }
         Self {
-            ids: FxHashMap::default(),
+            ids,
+            root: s,
             current: s.to_owned(),
+            generalize: options.generalize,
+            out: String::new(),
         }
     }
 
@@ -171,10 +432,18 @@ impl PrintVisitor {
         }
     }
 
+    /// Prints the check of the path bound to `path_pat`, unless the path was generalized to `_`.
+    fn print_qpath_pat(&mut self, path_pat: &str, path: &QPath) {
+        if path_pat.starts_with("ref ") {
+            self.current = path_pat["ref ".len()..].to_string();
+            self.print_qpath(path);
+        }
+    }
+
     fn print_qpath(&mut self, path: &QPath) {
-        print!("    if match_qpath({}, &[", self.current);
-        print_path(path, &mut true);
-        println!("]);");
+        out!(self, "    if match_qpath({}, &[", self.current);
+        print_path(path, &mut true, &mut self.out);
+        outln!(self, "]);");
     }
 }
 
@@ -182,8 +451,14 @@ struct PrintVisitor {
     /// Fields are the current index that needs to be appended to pattern
     /// binding names
     ids: FxHashMap<&'static str, usize>,
+    /// the name of the root node, the parameter of the `check_*` method
+    root: &'static str,
     /// the name that needs to be destructured
     current: String,
+    /// whether literal values, paths and binding names match anything
+    generalize: bool,
+    /// the conditions of the `if_chain!`, one per line
+    out: String,
 }
 
 impl<'tcx> Visitor<'tcx> for PrintVisitor {
@@ -196,16 +471,23 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
             let then_pat = self.next("then");
             if let Some(else_) = opt_else {
                 let else_pat = self.next("else_");
-                println!(
+                outln!(
+                    self,
                     "    if let Some((ref {}, ref {}, Some({}))) = higher::if_block(&{});",
-                    cond_pat, then_pat, else_pat, self.current
+                    cond_pat,
+                    then_pat,
+                    else_pat,
+                    self.current
                 );
                 self.current = else_pat;
                 self.visit_expr(else_);
             } else {
-                println!(
+                outln!(
+                    self,
                     "    if let Some((ref {}, ref {}, None)) = higher::if_block(&{});",
-                    cond_pat, then_pat, self.current
+                    cond_pat,
+                    then_pat,
+                    self.current
                 );
             }
             self.current = cond_pat;
@@ -215,19 +497,19 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
             return;
         }
 
-        print!("    if let ExprKind::");
+        out!(self, "    if let ExprKind::");
         let current = format!("{}.kind", self.current);
         match expr.kind {
             ExprKind::Box(ref inner) => {
                 let inner_pat = self.next("inner");
-                println!("Box(ref {}) = {};", inner_pat, current);
+                outln!(self, "Box(ref {}) = {};", inner_pat, current);
                 self.current = inner_pat;
                 self.visit_expr(inner);
             },
             ExprKind::Array(ref elements) => {
                 let elements_pat = self.next("elements");
-                println!("Array(ref {}) = {};", elements_pat, current);
-                println!("    if {}.len() == {};", elements_pat, elements.len());
+                outln!(self, "Array(ref {}) = {};", elements_pat, current);
+                outln!(self, "    if {}.len() == {};", elements_pat, elements.len());
                 for (i, element) in elements.iter().enumerate() {
                     self.current = format!("{}[{}]", elements_pat, i);
                     self.visit_expr(element);
@@ -236,23 +518,30 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
             ExprKind::Call(ref func, ref args) => {
                 let func_pat = self.next("func");
                 let args_pat = self.next("args");
-                println!("Call(ref {}, ref {}) = {};", func_pat, args_pat, current);
+                outln!(self, "Call(ref {}, ref {}) = {};", func_pat, args_pat, current);
                 self.current = func_pat;
                 self.visit_expr(func);
-                println!("    if {}.len() == {};", args_pat, args.len());
+                outln!(self, "    if {}.len() == {};", args_pat, args.len());
                 for (i, arg) in args.iter().enumerate() {
                     self.current = format!("{}[{}]", args_pat, i);
                     self.visit_expr(arg);
                 }
             },
             ExprKind::MethodCall(ref _method_name, ref _generics, ref _args) => {
-                println!("MethodCall(ref method_name, ref generics, ref args) = {};", current);
-                println!("    // unimplemented: `ExprKind::MethodCall` is not further destructured at the moment");
+                outln!(
+                    self,
+                    "MethodCall(ref method_name, ref generics, ref args) = {};",
+                    current
+                );
+                outln!(
+                    self,
+                    "    // unimplemented: `ExprKind::MethodCall` is not further destructured at the moment"
+                );
             },
             ExprKind::Tup(ref elements) => {
                 let elements_pat = self.next("elements");
-                println!("Tup(ref {}) = {};", elements_pat, current);
-                println!("    if {}.len() == {};", elements_pat, elements.len());
+                outln!(self, "Tup(ref {}) = {};", elements_pat, current);
+                outln!(self, "    if {}.len() == {};", elements_pat, elements.len());
                 for (i, element) in elements.iter().enumerate() {
                     self.current = format!("{}[{}]", elements_pat, i);
                     self.visit_expr(element);
@@ -262,11 +551,15 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                 let op_pat = self.next("op");
                 let left_pat = self.next("left");
                 let right_pat = self.next("right");
-                println!(
+                outln!(
+                    self,
                     "Binary(ref {}, ref {}, ref {}) = {};",
-                    op_pat, left_pat, right_pat, current
+                    op_pat,
+                    left_pat,
+                    right_pat,
+                    current
                 );
-                println!("    if BinOpKind::{:?} == {}.node;", op.node, op_pat);
+                outln!(self, "    if BinOpKind::{:?} == {}.node;", op.node, op_pat);
                 self.current = left_pat;
                 self.visit_expr(left);
                 self.current = right_pat;
@@ -274,53 +567,66 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
             },
             ExprKind::Unary(ref op, ref inner) => {
                 let inner_pat = self.next("inner");
-                println!("Unary(UnOp::{:?}, ref {}) = {};", op, inner_pat, current);
+                outln!(self, "Unary(UnOp::{:?}, ref {}) = {};", op, inner_pat, current);
                 self.current = inner_pat;
                 self.visit_expr(inner);
             },
             ExprKind::Lit(ref lit) => {
                 let lit_pat = self.next("lit");
-                println!("Lit(ref {}) = {};", lit_pat, current);
+                outln!(self, "Lit(ref {}) = {};", lit_pat, current);
+                if self.generalize {
+                    outln!(
+                        self,
+                        "    if let LitKind::{}(..) = {}.node;",
+                        lit_kind_name(&lit.node),
+                        lit_pat
+                    );
+                    return;
+                }
                 match lit.node {
-                    LitKind::Bool(val) => println!("    if let LitKind::Bool({:?}) = {}.node;", val, lit_pat),
-                    LitKind::Char(c) => println!("    if let LitKind::Char({:?}) = {}.node;", c, lit_pat),
-                    LitKind::Err(val) => println!("    if let LitKind::Err({}) = {}.node;", val, lit_pat),
-                    LitKind::Byte(b) => println!("    if let LitKind::Byte({}) = {}.node;", b, lit_pat),
+                    LitKind::Bool(val) => outln!(self, "    if let LitKind::Bool({:?}) = {}.node;", val, lit_pat),
+                    LitKind::Char(c) => outln!(self, "    if let LitKind::Char({:?}) = {}.node;", c, lit_pat),
+                    LitKind::Err(val) => outln!(self, "    if let LitKind::Err({}) = {}.node;", val, lit_pat),
+                    LitKind::Byte(b) => outln!(self, "    if let LitKind::Byte({}) = {}.node;", b, lit_pat),
                     // FIXME: also check int type
-                    LitKind::Int(i, _) => println!("    if let LitKind::Int({}, _) = {}.node;", i, lit_pat),
-                    LitKind::Float(..) => println!("    if let LitKind::Float(..) = {}.node;", lit_pat),
+                    LitKind::Int(i, _) => outln!(self, "    if let LitKind::Int({}, _) = {}.node;", i, lit_pat),
+                    LitKind::Float(..) => outln!(self, "    if let LitKind::Float(..) = {}.node;", lit_pat),
                     LitKind::FloatUnsuffixed(_) => {
-                        println!("    if let LitKind::FloatUnsuffixed(_) = {}.node;", lit_pat)
+                        outln!(self, "    if let LitKind::FloatUnsuffixed(_) = {}.node;", lit_pat)
                     },
                     LitKind::ByteStr(ref vec) => {
                         let vec_pat = self.next("vec");
-                        println!("    if let LitKind::ByteStr(ref {}) = {}.node;", vec_pat, lit_pat);
-                        println!("    if let [{:?}] = **{};", vec, vec_pat);
+                        outln!(self, "    if let LitKind::ByteStr(ref {}) = {}.node;", vec_pat, lit_pat);
+                        outln!(self, "    if let [{:?}] = **{};", vec, vec_pat);
                     },
                     LitKind::Str(ref text, _) => {
                         let str_pat = self.next("s");
-                        println!("    if let LitKind::Str(ref {}, _) = {}.node;", str_pat, lit_pat);
-                        println!("    if {}.as_str() == {:?}", str_pat, &*text.as_str())
+                        outln!(self, "    if let LitKind::Str(ref {}, _) = {}.node;", str_pat, lit_pat);
+                        outln!(self, "    if {}.as_str() == {:?};", str_pat, &*text.as_str())
                     },
                 }
             },
             ExprKind::Cast(ref expr, ref ty) => {
                 let cast_pat = self.next("expr");
                 let cast_ty = self.next("cast_ty");
-                let qp_label = self.next("qp");
 
-                println!("Cast(ref {}, ref {}) = {};", cast_pat, cast_ty, current);
+                outln!(self, "Cast(ref {}, ref {}) = {};", cast_pat, cast_ty, current);
                 if let TyKind::Path(ref qp) = ty.kind {
-                    println!("    if let TyKind::Path(ref {}) = {}.kind;", qp_label, cast_ty);
-                    self.current = qp_label;
-                    self.print_qpath(qp);
+                    if self.generalize {
+                        outln!(self, "    if let TyKind::Path(_) = {}.kind;", cast_ty);
+                    } else {
+                        let qp_label = self.next("qp");
+                        outln!(self, "    if let TyKind::Path(ref {}) = {}.kind;", qp_label, cast_ty);
+                        self.current = qp_label;
+                        self.print_qpath(qp);
+                    }
                 }
                 self.current = cast_pat;
                 self.visit_expr(expr);
             },
             ExprKind::Type(ref expr, ref _ty) => {
                 let cast_pat = self.next("expr");
-                println!("Type(ref {}, _) = {};", cast_pat, current);
+                outln!(self, "Type(ref {}, _) = {};", cast_pat, current);
                 self.current = cast_pat;
                 self.visit_expr(expr);
             },
@@ -328,7 +634,14 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                 let body_pat = self.next("body");
                 let des = loop_desugaring_name(desugaring);
                 let label_pat = self.next("label");
-                println!("Loop(ref {}, ref {}, {}) = {};", body_pat, label_pat, des, current);
+                outln!(
+                    self,
+                    "Loop(ref {}, ref {}, {}) = {};",
+                    body_pat,
+                    label_pat,
+                    des,
+                    current
+                );
                 self.current = body_pat;
                 self.visit_block(body);
             },
@@ -336,20 +649,27 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                 let des = desugaring_name(desugaring);
                 let expr_pat = self.next("expr");
                 let arms_pat = self.next("arms");
-                println!("Match(ref {}, ref {}, {}) = {};", expr_pat, arms_pat, des, current);
+                outln!(
+                    self,
+                    "Match(ref {}, ref {}, {}) = {};",
+                    expr_pat,
+                    arms_pat,
+                    des,
+                    current
+                );
                 self.current = expr_pat;
                 self.visit_expr(expr);
-                println!("    if {}.len() == {};", arms_pat, arms.len());
+                outln!(self, "    if {}.len() == {};", arms_pat, arms.len());
                 for (i, arm) in arms.iter().enumerate() {
                     self.current = format!("{}[{}].body", arms_pat, i);
                     self.visit_expr(&arm.body);
                     if let Some(ref guard) = arm.guard {
                         let guard_pat = self.next("guard");
-                        println!("    if let Some(ref {}) = {}[{}].guard;", guard_pat, arms_pat, i);
+                        outln!(self, "    if let Some(ref {}) = {}[{}].guard;", guard_pat, arms_pat, i);
                         match guard {
                             hir::Guard::If(ref if_expr) => {
                                 let if_expr_pat = self.next("expr");
-                                println!("    if let Guard::If(ref {}) = {};", if_expr_pat, guard_pat);
+                                outln!(self, "    if let Guard::If(ref {}) = {};", if_expr_pat, guard_pat);
                                 self.current = if_expr_pat;
                                 self.visit_expr(if_expr);
                             },
@@ -360,25 +680,28 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                 }
             },
             ExprKind::Closure(ref _capture_clause, ref _func, _, _, _) => {
-                println!("Closure(ref capture_clause, ref func, _, _, _) = {};", current);
-                println!("    // unimplemented: `ExprKind::Closure` is not further destructured at the moment");
+                outln!(self, "Closure(ref capture_clause, ref func, _, _, _) = {};", current);
+                outln!(
+                    self,
+                    "    // unimplemented: `ExprKind::Closure` is not further destructured at the moment"
+                );
             },
             ExprKind::Yield(ref sub, _) => {
                 let sub_pat = self.next("sub");
-                println!("Yield(ref sub) = {};", current);
+                outln!(self, "Yield(ref {}, _) = {};", sub_pat, current);
                 self.current = sub_pat;
                 self.visit_expr(sub);
             },
             ExprKind::Block(ref block, _) => {
                 let block_pat = self.next("block");
-                println!("Block(ref {}) = {};", block_pat, current);
+                outln!(self, "Block(ref {}, _) = {};", block_pat, current);
                 self.current = block_pat;
                 self.visit_block(block);
             },
             ExprKind::Assign(ref target, ref value) => {
                 let target_pat = self.next("target");
                 let value_pat = self.next("value");
-                println!("Assign(ref {}, ref {}) = {};", target_pat, value_pat, current);
+                outln!(self, "Assign(ref {}, ref {}) = {};", target_pat, value_pat, current);
                 self.current = target_pat;
                 self.visit_expr(target);
                 self.current = value_pat;
@@ -388,11 +711,15 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                 let op_pat = self.next("op");
                 let target_pat = self.next("target");
                 let value_pat = self.next("value");
-                println!(
+                outln!(
+                    self,
                     "AssignOp(ref {}, ref {}, ref {}) = {};",
-                    op_pat, target_pat, value_pat, current
+                    op_pat,
+                    target_pat,
+                    value_pat,
+                    current
                 );
-                println!("    if BinOpKind::{:?} == {}.node;", op.node, op_pat);
+                outln!(self, "    if BinOpKind::{:?} == {}.node;", op.node, op_pat);
                 self.current = target_pat;
                 self.visit_expr(target);
                 self.current = value_pat;
@@ -401,29 +728,38 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
             ExprKind::Field(ref object, ref field_ident) => {
                 let obj_pat = self.next("object");
                 let field_name_pat = self.next("field_name");
-                println!("Field(ref {}, ref {}) = {};", obj_pat, field_name_pat, current);
-                println!("    if {}.as_str() == {:?}", field_name_pat, field_ident.as_str());
+                outln!(self, "Field(ref {}, ref {}) = {};", obj_pat, field_name_pat, current);
+                outln!(
+                    self,
+                    "    if {}.as_str() == {:?};",
+                    field_name_pat,
+                    field_ident.as_str()
+                );
                 self.current = obj_pat;
                 self.visit_expr(object);
             },
             ExprKind::Index(ref object, ref index) => {
                 let object_pat = self.next("object");
                 let index_pat = self.next("index");
-                println!("Index(ref {}, ref {}) = {};", object_pat, index_pat, current);
+                outln!(self, "Index(ref {}, ref {}) = {};", object_pat, index_pat, current);
                 self.current = object_pat;
                 self.visit_expr(object);
                 self.current = index_pat;
                 self.visit_expr(index);
             },
             ExprKind::Path(ref path) => {
+                if self.generalize {
+                    outln!(self, "Path(_) = {};", current);
+                    return;
+                }
                 let path_pat = self.next("path");
-                println!("Path(ref {}) = {};", path_pat, current);
+                outln!(self, "Path(ref {}) = {};", path_pat, current);
                 self.current = path_pat;
                 self.print_qpath(path);
             },
             ExprKind::AddrOf(mutability, ref inner) => {
                 let inner_pat = self.next("inner");
-                println!("AddrOf({:?}, ref {}) = {};", mutability, inner_pat, current);
+                outln!(self, "AddrOf({:?}, ref {}) = {};", mutability, inner_pat, current);
                 self.current = inner_pat;
                 self.visit_expr(inner);
             },
@@ -431,66 +767,82 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                 let destination_pat = self.next("destination");
                 if let Some(ref value) = *opt_value {
                     let value_pat = self.next("value");
-                    println!("Break(ref {}, Some(ref {})) = {};", destination_pat, value_pat, current);
+                    outln!(
+                        self,
+                        "Break(ref {}, Some(ref {})) = {};",
+                        destination_pat,
+                        value_pat,
+                        current
+                    );
                     self.current = value_pat;
                     self.visit_expr(value);
                 } else {
-                    println!("Break(ref {}, None) = {};", destination_pat, current);
+                    outln!(self, "Break(ref {}, None) = {};", destination_pat, current);
                 }
                 // FIXME: implement label printing
             },
             ExprKind::Continue(ref _destination) => {
                 let destination_pat = self.next("destination");
-                println!("Again(ref {}) = {};", destination_pat, current);
+                outln!(self, "Continue(ref {}) = {};", destination_pat, current);
                 // FIXME: implement label printing
             },
             ExprKind::Ret(ref opt_value) => {
                 if let Some(ref value) = *opt_value {
                     let value_pat = self.next("value");
-                    println!("Ret(Some(ref {})) = {};", value_pat, current);
+                    outln!(self, "Ret(Some(ref {})) = {};", value_pat, current);
                     self.current = value_pat;
                     self.visit_expr(value);
                 } else {
-                    println!("Ret(None) = {};", current);
+                    outln!(self, "Ret(None) = {};", current);
                 }
             },
             ExprKind::InlineAsm(_, ref _input, ref _output) => {
-                println!("InlineAsm(_, ref input, ref output) = {};", current);
-                println!("    // unimplemented: `ExprKind::InlineAsm` is not further destructured at the moment");
+                outln!(self, "InlineAsm(_, ref input, ref output) = {};", current);
+                outln!(
+                    self,
+                    "    // unimplemented: `ExprKind::InlineAsm` is not further destructured at the moment"
+                );
             },
             ExprKind::Struct(ref path, ref fields, ref opt_base) => {
-                let path_pat = self.next("path");
+                let path_pat = if self.generalize {
+                    "_".to_string()
+                } else {
+                    format!("ref {}", self.next("path"))
+                };
                 let fields_pat = self.next("fields");
                 if let Some(ref base) = *opt_base {
                     let base_pat = self.next("base");
-                    println!(
-                        "Struct(ref {}, ref {}, Some(ref {})) = {};",
-                        path_pat, fields_pat, base_pat, current
+                    outln!(
+                        self,
+                        "Struct({}, ref {}, Some(ref {})) = {};",
+                        path_pat,
+                        fields_pat,
+                        base_pat,
+                        current
                     );
                     self.current = base_pat;
                     self.visit_expr(base);
                 } else {
-                    println!("Struct(ref {}, ref {}, None) = {};", path_pat, fields_pat, current);
+                    outln!(self, "Struct({}, ref {}, None) = {};", path_pat, fields_pat, current);
                 }
-                self.current = path_pat;
-                self.print_qpath(path);
-                println!("    if {}.len() == {};", fields_pat, fields.len());
-                println!("    // unimplemented: field checks");
+                self.print_qpath_pat(&path_pat, path);
+                outln!(self, "    if {}.len() == {};", fields_pat, fields.len());
+                outln!(self, "    // unimplemented: field checks");
             },
             // FIXME: compute length (needs type info)
             ExprKind::Repeat(ref value, _) => {
                 let value_pat = self.next("value");
-                println!("Repeat(ref {}, _) = {};", value_pat, current);
-                println!("// unimplemented: repeat count check");
+                outln!(self, "Repeat(ref {}, _) = {};", value_pat, current);
+                outln!(self, "    // unimplemented: repeat count check");
                 self.current = value_pat;
                 self.visit_expr(value);
             },
             ExprKind::Err => {
-                println!("Err = {}", current);
+                outln!(self, "Err = {};", current);
             },
             ExprKind::DropTemps(ref expr) => {
                 let expr_pat = self.next("expr");
-                println!("DropTemps(ref {}) = {};", expr_pat, current);
+                outln!(self, "DropTemps(ref {}) = {};", expr_pat, current);
                 self.current = expr_pat;
                 self.visit_expr(expr);
             },
@@ -498,22 +850,29 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
     }
 
     fn visit_block(&mut self, block: &Block) {
-        let trailing_pat = self.next("trailing_expr");
-        println!("    if let Some({}) = &{}.expr;", trailing_pat, self.current);
-        println!("    if {}.stmts.len() == {};", self.current, block.stmts.len());
+        let trailing_pat = block.expr.as_ref().map(|_| self.next("trailing_expr"));
+        match trailing_pat {
+            Some(ref trailing_pat) => outln!(self, "    if let Some(ref {}) = {}.expr;", trailing_pat, self.current),
+            None => outln!(self, "    if {}.expr.is_none();", self.current),
+        }
+        outln!(self, "    if {}.stmts.len() == {};", self.current, block.stmts.len());
         let current = self.current.clone();
         for (i, stmt) in block.stmts.iter().enumerate() {
             self.current = format!("{}.stmts[{}]", current, i);
             self.visit_stmt(stmt);
         }
+        if let (Some(trailing_pat), Some(expr)) = (trailing_pat, &block.expr) {
+            self.current = trailing_pat;
+            self.visit_expr(expr);
+        }
     }
 
     #[allow(clippy::too_many_lines)]
     fn visit_pat(&mut self, pat: &Pat) {
-        print!("    if let PatKind::");
+        out!(self, "    if let PatKind::");
         let current = format!("{}.kind", self.current);
         match pat.kind {
-            PatKind::Wild => println!("Wild = {};", current),
+            PatKind::Wild => outln!(self, "Wild = {};", current),
             PatKind::Binding(anno, .., ident, ref sub) => {
                 let anno_pat = match anno {
                     BindingAnnotation::Unannotated => "BindingAnnotation::Unannotated",
@@ -521,86 +880,118 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                     BindingAnnotation::Ref => "BindingAnnotation::Ref",
                     BindingAnnotation::RefMut => "BindingAnnotation::RefMut",
                 };
-                let name_pat = self.next("name");
+                let name_pat = if self.generalize {
+                    "_".to_string()
+                } else {
+                    self.next("name")
+                };
                 if let Some(ref sub) = *sub {
                     let sub_pat = self.next("sub");
-                    println!(
+                    outln!(
+                        self,
                         "Binding({}, _, {}, Some(ref {})) = {};",
-                        anno_pat, name_pat, sub_pat, current
+                        anno_pat,
+                        name_pat,
+                        sub_pat,
+                        current
                     );
                     self.current = sub_pat;
                     self.visit_pat(sub);
                 } else {
-                    println!("Binding({}, _, {}, None) = {};", anno_pat, name_pat, current);
+                    outln!(self, "Binding({}, _, {}, None) = {};", anno_pat, name_pat, current);
+                }
+                if !self.generalize {
+                    outln!(self, "    if {}.as_str() == \"{}\";", name_pat, ident.as_str());
                 }
-                println!("    if {}.as_str() == \"{}\";", name_pat, ident.as_str());
             },
             PatKind::Struct(ref path, ref fields, ignore) => {
-                let path_pat = self.next("path");
+                let path_pat = if self.generalize {
+                    "_".to_string()
+                } else {
+                    format!("ref {}", self.next("path"))
+                };
                 let fields_pat = self.next("fields");
-                println!(
-                    "Struct(ref {}, ref {}, {}) = {};",
-                    path_pat, fields_pat, ignore, current
+                outln!(
+                    self,
+                    "Struct({}, ref {}, {}) = {};",
+                    path_pat,
+                    fields_pat,
+                    ignore,
+                    current
                 );
-                self.current = path_pat;
-                self.print_qpath(path);
-                println!("    if {}.len() == {};", fields_pat, fields.len());
-                println!("    // unimplemented: field checks");
+                self.print_qpath_pat(&path_pat, path);
+                outln!(self, "    if {}.len() == {};", fields_pat, fields.len());
+                outln!(self, "    // unimplemented: field checks");
             },
             PatKind::Or(ref fields) => {
                 let fields_pat = self.next("fields");
-                println!("Or(ref {}) = {};", fields_pat, current);
-                println!("    if {}.len() == {};", fields_pat, fields.len());
-                println!("    // unimplemented: field checks");
+                outln!(self, "Or(ref {}) = {};", fields_pat, current);
+                outln!(self, "    if {}.len() == {};", fields_pat, fields.len());
+                outln!(self, "    // unimplemented: field checks");
             },
             PatKind::TupleStruct(ref path, ref fields, skip_pos) => {
-                let path_pat = self.next("path");
+                let path_pat = if self.generalize {
+                    "_".to_string()
+                } else {
+                    format!("ref {}", self.next("path"))
+                };
                 let fields_pat = self.next("fields");
-                println!(
-                    "TupleStruct(ref {}, ref {}, {:?}) = {};",
-                    path_pat, fields_pat, skip_pos, current
+                outln!(
+                    self,
+                    "TupleStruct({}, ref {}, {:?}) = {};",
+                    path_pat,
+                    fields_pat,
+                    skip_pos,
+                    current
                 );
-                self.current = path_pat;
-                self.print_qpath(path);
-                println!("    if {}.len() == {};", fields_pat, fields.len());
-                println!("    // unimplemented: field checks");
+                self.print_qpath_pat(&path_pat, path);
+                outln!(self, "    if {}.len() == {};", fields_pat, fields.len());
+                outln!(self, "    // unimplemented: field checks");
             },
             PatKind::Path(ref path) => {
+                if self.generalize {
+                    outln!(self, "Path(_) = {};", current);
+                    return;
+                }
                 let path_pat = self.next("path");
-                println!("Path(ref {}) = {};", path_pat, current);
+                outln!(self, "Path(ref {}) = {};", path_pat, current);
                 self.current = path_pat;
                 self.print_qpath(path);
             },
             PatKind::Tuple(ref fields, skip_pos) => {
                 let fields_pat = self.next("fields");
-                println!("Tuple(ref {}, {:?}) = {};", fields_pat, skip_pos, current);
-                println!("    if {}.len() == {};", fields_pat, fields.len());
-                println!("    // unimplemented: field checks");
+                outln!(self, "Tuple(ref {}, {:?}) = {};", fields_pat, skip_pos, current);
+                outln!(self, "    if {}.len() == {};", fields_pat, fields.len());
+                outln!(self, "    // unimplemented: field checks");
             },
             PatKind::Box(ref pat) => {
                 let pat_pat = self.next("pat");
-                println!("Box(ref {}) = {};", pat_pat, current);
+                outln!(self, "Box(ref {}) = {};", pat_pat, current);
                 self.current = pat_pat;
                 self.visit_pat(pat);
             },
             PatKind::Ref(ref pat, muta) => {
                 let pat_pat = self.next("pat");
-                println!("Ref(ref {}, Mutability::{:?}) = {};", pat_pat, muta, current);
+                outln!(self, "Ref(ref {}, Mutability::{:?}) = {};", pat_pat, muta, current);
                 self.current = pat_pat;
                 self.visit_pat(pat);
             },
             PatKind::Lit(ref lit_expr) => {
                 let lit_expr_pat = self.next("lit_expr");
-                println!("Lit(ref {}) = {}", lit_expr_pat, current);
+                outln!(self, "Lit(ref {}) = {};", lit_expr_pat, current);
                 self.current = lit_expr_pat;
                 self.visit_expr(lit_expr);
             },
             PatKind::Range(ref start, ref end, end_kind) => {
                 let start_pat = self.next("start");
                 let end_pat = self.next("end");
-                println!(
+                outln!(
+                    self,
                     "Range(ref {}, ref {}, RangeEnd::{:?}) = {};",
-                    start_pat, end_pat, end_kind, current
+                    start_pat,
+                    end_pat,
+                    end_kind,
+                    current
                 );
                 self.current = start_pat;
                 self.visit_expr(start);
@@ -612,21 +1003,25 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
                 let end_pat = self.next("end");
                 if let Some(ref middle) = middle {
                     let middle_pat = self.next("middle");
-                    println!(
+                    outln!(
+                        self,
                         "Slice(ref {}, Some(ref {}), ref {}) = {};",
-                        start_pat, middle_pat, end_pat, current
+                        start_pat,
+                        middle_pat,
+                        end_pat,
+                        current
                     );
                     self.current = middle_pat;
                     self.visit_pat(middle);
                 } else {
-                    println!("Slice(ref {}, None, ref {}) = {};", start_pat, end_pat, current);
+                    outln!(self, "Slice(ref {}, None, ref {}) = {};", start_pat, end_pat, current);
                 }
-                println!("    if {}.len() == {};", start_pat, start.len());
+                outln!(self, "    if {}.len() == {};", start_pat, start.len());
                 for (i, pat) in start.iter().enumerate() {
                     self.current = format!("{}[{}]", start_pat, i);
                     self.visit_pat(pat);
                 }
-                println!("    if {}.len() == {};", end_pat, end.len());
+                outln!(self, "    if {}.len() == {};", end_pat, end.len());
                 for (i, pat) in end.iter().enumerate() {
                     self.current = format!("{}[{}]", end_pat, i);
                     self.visit_pat(pat);
@@ -636,16 +1031,16 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
     }
 
     fn visit_stmt(&mut self, s: &Stmt) {
-        print!("    if let StmtKind::");
+        out!(self, "    if let StmtKind::");
         let current = format!("{}.kind", self.current);
         match s.kind {
             // A local (let) binding:
             StmtKind::Local(ref local) => {
                 let local_pat = self.next("local");
-                println!("Local(ref {}) = {};", local_pat, current);
+                outln!(self, "Local(ref {}) = {};", local_pat, current);
                 if let Some(ref init) = local.init {
                     let init_pat = self.next("init");
-                    println!("    if let Some(ref {}) = {}.init;", init_pat, local_pat);
+                    outln!(self, "    if let Some(ref {}) = {}.init;", init_pat, local_pat);
                     self.current = init_pat;
                     self.visit_expr(init);
                 }
@@ -654,13 +1049,13 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
             },
             // An item binding:
             StmtKind::Item(_) => {
-                println!("Item(item_id) = {};", current);
+                outln!(self, "Item(item_id) = {};", current);
             },
 
             // Expr without trailing semi-colon (must have unit type):
             StmtKind::Expr(ref e) => {
                 let e_pat = self.next("e");
-                println!("Expr(ref {}, _) = {}", e_pat, current);
+                outln!(self, "Expr(ref {}) = {};", e_pat, current);
                 self.current = e_pat;
                 self.visit_expr(e);
             },
@@ -668,7 +1063,7 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
             // Expr with trailing semi-colon (may have any type):
             StmtKind::Semi(ref e) => {
                 let e_pat = self.next("e");
-                println!("Semi(ref {}, _) = {}", e_pat, current);
+                outln!(self, "Semi(ref {}) = {};", e_pat, current);
                 self.current = e_pat;
                 self.visit_expr(e);
             },
@@ -680,10 +1075,6 @@ impl<'tcx> Visitor<'tcx> for PrintVisitor {
     }
 }
 
-fn has_attr(sess: &Session, attrs: &[Attribute]) -> bool {
-    get_attr(sess, attrs, "author").count() > 0
-}
-
 #[must_use]
 fn desugaring_name(des: hir::MatchSource) -> String {
     match des {
@@ -714,29 +1105,58 @@ fn loop_desugaring_name(des: hir::LoopSource) -> &'static str {
     }
 }
 
-fn print_path(path: &QPath, first: &mut bool) {
+fn lit_kind_name(lit: &LitKind) -> &'static str {
+    match *lit {
+        LitKind::Str(..) => "Str",
+        LitKind::ByteStr(_) => "ByteStr",
+        LitKind::Byte(_) => "Byte",
+        LitKind::Char(_) => "Char",
+        LitKind::Int(..) => "Int",
+        LitKind::Float(..) => "Float",
+        LitKind::FloatUnsuffixed(_) => "FloatUnsuffixed",
+        LitKind::Bool(_) => "Bool",
+        LitKind::Err(_) => "Err",
+    }
+}
+
+fn print_path(path: &QPath, first: &mut bool, out: &mut String) {
     match *path {
         QPath::Resolved(_, ref path) => {
             for segment in &path.segments {
                 if *first {
                     *first = false;
                 } else {
-                    print!(", ");
+                    out.push_str(", ");
                 }
-                print!("{:?}", segment.ident.as_str());
+                out.push_str(&format!("{:?}", segment.ident.as_str()));
             }
         },
         QPath::TypeRelative(ref ty, ref segment) => match ty.kind {
             hir::TyKind::Path(ref inner_path) => {
-                print_path(inner_path, first);
+                print_path(inner_path, first, out);
                 if *first {
                     *first = false;
                 } else {
-                    print!(", ");
+                    out.push_str(", ");
                 }
-                print!("{:?}", segment.ident.as_str());
+                out.push_str(&format!("{:?}", segment.ident.as_str()));
             },
-            ref other => print!("/* unimplemented: {:?}*/", other),
+            ref other => out.push_str(&format!("/* unimplemented: {:?}*/", other)),
         },
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{camel_case, is_snake_case};
+
+    #[test]
+    fn lint_names() {
+        assert!(is_snake_case("foo_bar2"));
+        assert!(!is_snake_case("FooBar"));
+        assert!(!is_snake_case("foo__bar"));
+        assert!(!is_snake_case("foo_"));
+        assert!(!is_snake_case("_foo"));
+        assert_eq!(camel_case("foo_bar2"), "FooBar2");
+    }
+}
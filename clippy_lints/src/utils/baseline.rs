@@ -0,0 +1,204 @@
+//! Baselines of known warnings, used by `cargo clippy --baseline` and `--write-baseline`.
+//!
+//! A baseline entry identifies a warning by its lint, its file and a fingerprint of the snippet
+//! it points to, so that entries survive unrelated changes moving the code around. The number of
+//! occurrences is recorded as well, so that a copy of a known warning is still reported.
+
+use lazy_static::lazy_static;
+use rustc::lint::Lint;
+use rustc::session::Session;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::{env, process};
+use syntax::source_map::{FileName, MultiSpan};
+
+/// Path of the baseline file whose warnings are not emitted.
+pub const BASELINE_ENV: &str = "CLIPPY_BASELINE";
+/// Path of the file the warnings are recorded to instead of being emitted, as one JSON object per
+/// line. `cargo clippy --write-baseline` merges them into a baseline file.
+pub const WRITE_BASELINE_ENV: &str = "CLIPPY_WRITE_BASELINE";
+
+/// The version of the baseline files, written by `cargo clippy --write-baseline`.
+const VERSION: u64 = 1;
+
+#[derive(Debug, Deserialize)]
+struct BaselineFile {
+    version: u64,
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Entry {
+    lint: String,
+    file: String,
+    fingerprint: String,
+    count: usize,
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    lint: &'a str,
+    file: &'a str,
+    fingerprint: &'a str,
+    /// Distinguishes the compilations of a file shared by several crates, like a library and its
+    /// unit tests.
+    process: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Key {
+    lint: String,
+    file: String,
+    fingerprint: String,
+}
+
+lazy_static! {
+    /// The number of warnings left to suppress for each entry of the baseline.
+    static ref BASELINE: Mutex<HashMap<Key, usize>> = Mutex::new(HashMap::new());
+}
+
+/// Reads the baseline file set in `CLIPPY_BASELINE`, if any.
+pub fn read(sess: &Session) {
+    let path = match env::var_os(BASELINE_ENV) {
+        Some(path) => PathBuf::from(path),
+        None => return,
+    };
+
+    // Loading the file through the source map adds it to the dep-info, so cargo checks the crate
+    // again when the baseline changes.
+    let src = match sess.source_map().load_file(&path) {
+        Ok(file) => file.src.as_ref().map_or_else(String::new, |src| src.to_string()),
+        Err(e) => {
+            sess.err(&format!("error reading Clippy's baseline `{}`: {}", path.display(), e));
+            return;
+        },
+    };
+    let file: BaselineFile = match serde_json::from_str(&src) {
+        Ok(file) => file,
+        Err(e) => {
+            sess.err(&format!("error reading Clippy's baseline `{}`: {}", path.display(), e));
+            return;
+        },
+    };
+    if file.version != VERSION {
+        sess.err(&format!(
+            "error reading Clippy's baseline `{}`: unsupported version {}, write it again with `cargo clippy --write-baseline`",
+            path.display(),
+            file.version
+        ));
+        return;
+    }
+
+    let mut baseline = BASELINE.lock().expect("no threading here");
+    for entry in file.entries {
+        let key = Key {
+            lint: entry.lint,
+            file: entry.file,
+            fingerprint: entry.fingerprint,
+        };
+        *baseline.entry(key).or_insert(0) += entry.count;
+    }
+}
+
+/// Returns `true` if the warning of `lint` at `span` must not be emitted, either because it is in
+/// the baseline or because it was recorded for a new baseline.
+pub fn suppresses(sess: &Session, lint: &'static Lint, span: &MultiSpan) -> bool {
+    let record_to = env::var_os(WRITE_BASELINE_ENV);
+    if record_to.is_none() && BASELINE.lock().expect("no threading here").is_empty() {
+        return false;
+    }
+
+    let key = match key(sess, lint, span) {
+        Some(key) => key,
+        None => return false,
+    };
+
+    if let Some(record_to) = record_to {
+        record(sess, Path::new(&record_to), &key);
+        return true;
+    }
+
+    match BASELINE.lock().expect("no threading here").get_mut(&key) {
+        Some(remaining) if *remaining > 0 => {
+            *remaining -= 1;
+            true
+        },
+        _ => false,
+    }
+}
+
+fn key(sess: &Session, lint: &'static Lint, span: &MultiSpan) -> Option<Key> {
+    let span = span.primary_span()?.source_callsite();
+    let source_map = sess.source_map();
+    let snippet = source_map.span_to_snippet(span).ok()?;
+
+    let file = match source_map.span_to_filename(span) {
+        FileName::Real(path) => {
+            let path = env::current_dir()
+                .ok()
+                .and_then(|dir| path.strip_prefix(dir).ok().map(Path::to_path_buf))
+                .unwrap_or(path);
+            path.to_string_lossy().replace('\\', "/")
+        },
+        name => name.to_string(),
+    };
+
+    Some(Key {
+        lint: lint.name_lower().replacen("clippy::", "", 1),
+        file,
+        fingerprint: fingerprint(&snippet),
+    })
+}
+
+/// Hashes `snippet` ignoring its whitespace, so that reindenting or reformatting the code doesn't
+/// change the fingerprint.
+///
+/// This uses FNV-1a rather than the `std` hasher, whose output may change between Rust releases.
+fn fingerprint(snippet: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for word in snippet.split_whitespace() {
+        for byte in word.bytes().chain(Some(b' ')) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+fn record(sess: &Session, path: &Path, key: &Key) {
+    let record = Record {
+        lint: &key.lint,
+        file: &key.file,
+        fingerprint: &key.fingerprint,
+        process: process::id(),
+    };
+    let mut line = serde_json::to_string(&record).expect("records are always serializable");
+    line.push('\n');
+
+    // Several drivers can run in parallel, each record is written at once in append mode.
+    let result = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(line.as_bytes()));
+    if let Err(e) = result {
+        sess.err(&format!("error writing Clippy's baseline `{}`: {}", path.display(), e));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::fingerprint;
+
+    #[test]
+    fn test_fingerprint() {
+        assert_eq!(fingerprint("x.clone()"), fingerprint("  x.clone()\n"));
+        assert_eq!(fingerprint("if a {\n    b\n}"), fingerprint("if a { b }"));
+        assert_ne!(fingerprint("x.clone()"), fingerprint("y.clone()"));
+        assert_ne!(fingerprint("a b"), fingerprint("ab"));
+    }
+}
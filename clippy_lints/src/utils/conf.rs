@@ -3,6 +3,9 @@
 #![deny(clippy::missing_docs_in_private_items)]
 
 use lazy_static::lazy_static;
+use rustc::lint;
+use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::default::Default;
 use std::io::Read;
 use std::sync::Mutex;
@@ -56,6 +59,129 @@ impl From<io::Error> for Error {
     }
 }
 
+/// A lint level that can be set in the `[lints]` table.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    /// `allow`
+    Allow,
+    /// `warn`
+    Warn,
+    /// `deny`
+    Deny,
+    /// `forbid`
+    Forbid,
+}
+
+impl From<LintLevel> for lint::Level {
+    fn from(level: LintLevel) -> Self {
+        match level {
+            LintLevel::Allow => Self::Allow,
+            LintLevel::Warn => Self::Warn,
+            LintLevel::Deny => Self::Deny,
+            LintLevel::Forbid => Self::Forbid,
+        }
+    }
+}
+
+/// An entry of the `[lints]` table.
+///
+/// It is either a plain level (`needless_return = "deny"`) or a table with lint specific settings
+/// (`too_many_lines = { level = "warn", threshold = 60 }`).
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum LintConf {
+    /// Only sets the level of the lint.
+    Level(LintLevel),
+    /// Sets the level and/or the settings of the lint.
+    Table(LintTable),
+}
+
+/// The table form of a `[lints]` entry.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LintTable {
+    /// The level of the lint, if it should be changed.
+    pub level: Option<LintLevel>,
+    /// Overrides the threshold configuration key of the lint, e.g. `too-many-lines-threshold`.
+    pub threshold: Option<u64>,
+}
+
+impl LintConf {
+    /// The configured level, if any.
+    #[must_use]
+    pub fn level(&self) -> Option<LintLevel> {
+        match *self {
+            Self::Level(level) => Some(level),
+            Self::Table(ref table) => table.level,
+        }
+    }
+
+    /// The configured threshold, if any.
+    #[must_use]
+    pub fn threshold(&self) -> Option<u64> {
+        match *self {
+            Self::Level(_) => None,
+            Self::Table(ref table) => table.threshold,
+        }
+    }
+}
+
+/// An entry of the `disallowed-methods`, `disallowed-types` and `disallowed-macros` lists.
+///
+/// It is either a plain path (`"std::env::set_var"`) or a table with the reason the path is
+/// disallowed (`{ path = "std::time::Instant::now", reason = "use the simulated clock" }`).
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DisallowedPath {
+    /// Only the path.
+    Simple(String),
+    /// The path and the reason shown with the warnings.
+    WithReason {
+        /// The path, e.g. `std::collections::HashMap`.
+        path: String,
+        /// The reason the path is disallowed.
+        reason: Option<String>,
+    },
+}
+
+impl DisallowedPath {
+    /// The path, e.g. `std::collections::HashMap`.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        match *self {
+            Self::Simple(ref path) | Self::WithReason { ref path, .. } => path,
+        }
+    }
+
+    /// The reason the path is disallowed, if any.
+    #[must_use]
+    pub fn reason(&self) -> Option<&str> {
+        match *self {
+            Self::Simple(_) => None,
+            Self::WithReason { ref reason, .. } => reason.as_ref().map(String::as_str),
+        }
+    }
+}
+
+/// Normalizes a key of the `[lints]` table to the name used on the command line, e.g.
+/// `needless-return` becomes `clippy::needless_return`.
+#[must_use]
+pub fn lint_opt_name(name: &str) -> String {
+    let name = name.replace('-', "_");
+    if name.contains("::") {
+        name
+    } else {
+        format!("clippy::{}", name)
+    }
+}
+
+/// Parses a version of Clippy like `0.0.212` into its numbers, to compare versions.
+#[must_use]
+pub fn parse_version(version: &str) -> Option<Vec<u64>> {
+    version.split('.').map(|number| number.parse().ok()).collect()
+}
+
 lazy_static! {
     static ref ERRORS: Mutex<Vec<Error>> = Mutex::new(Vec::new());
 }
@@ -74,6 +200,10 @@ macro_rules! define_Conf {
                 #[allow(dead_code)]
                 #[serde(default)]
                 third_party: Option<::toml::Value>,
+                /// The file each configuration key was read from, `[lints]` entries are recorded
+                /// as `lints.<name>`.
+                #[serde(skip)]
+                pub sources: std::collections::BTreeMap<String, std::path::PathBuf>,
             }
             $(
                 mod $rust_name {
@@ -151,6 +281,65 @@ define_Conf! {
     (trivial_copy_size_limit, "trivial_copy_size_limit", None => Option<u64>),
     /// Lint: TOO_MANY_LINES. The maximum number of lines a function or method can have
     (too_many_lines_threshold, "too_many_lines_threshold", 100 => u64),
+    /// Lint: DISALLOWED_METHOD. The functions and methods that must not be used, each a path or a table with a `path` and a `reason`
+    (disallowed_methods, "disallowed_methods", vec![] => Vec<crate::utils::conf::DisallowedPath>),
+    /// Lint: DISALLOWED_TYPE. The types that must not be used, each a path or a table with a `path` and a `reason`
+    (disallowed_types, "disallowed_types", vec![] => Vec<crate::utils::conf::DisallowedPath>),
+    /// Lint: DISALLOWED_MACRO. The macros that must not be used, each a path or a table with a `path` and a `reason`
+    (disallowed_macros, "disallowed_macros", vec![] => Vec<crate::utils::conf::DisallowedPath>),
+    /// Lint: MULTIPLE_CRATE_VERSIONS. The crates allowed to have several versions in the dependency graph, e.g. `["winapi"]`
+    (allowed_duplicate_crates, "allowed_duplicate_crates", vec![] => Vec<String>),
+    /// Lint: MULTIPLE_CRATE_VERSIONS. Only report the crates with several versions that the package depends on directly
+    (duplicate_crates_direct_only, "duplicate_crates_direct_only", false => bool),
+    /// Lint: DISALLOWED_LICENSES. The SPDX licenses the dependencies may use, e.g. `["MIT", "Apache-2.0"]`, any license not denied if empty
+    (allowed_licenses, "allowed_licenses", vec![] => Vec<String>),
+    /// Lint: DISALLOWED_LICENSES. The SPDX licenses the dependencies must not use, e.g. `["GPL-3.0"]`
+    (denied_licenses, "denied_licenses", vec![] => Vec<String>),
+    /// Lint: DISALLOWED_SOURCES. The git repositories, registries and directories the dependencies may come from besides crates.io and the workspace, as prefixes of their URL or path
+    (allowed_sources, "allowed_sources", vec![] => Vec<String>),
+    /// Lint: CHECKED_CONVERSIONS, DURATION_SUBSEC, MAP_CLONE, MISSING_CONST_FOR_FN, PTR_OFFSET_WITH_CAST, RANGE_PLUS_ONE, TRANSMUTE_INT_TO_FLOAT. The minimum Rust version that the project supports, e.g. `"1.31"`
+    (msrv, "msrv", None => Option<String>),
+    /// Only enables the lints added in this version of Clippy or before, e.g. `"0.0.212"`. Newer lints are allowed unless they are named in the `[lints]` table or on the command line
+    (max_lint_version, "max_lint_version", None => Option<String>),
+    /// Lint levels and lint specific settings of the `[lints]` table, e.g. `needless_return = "deny"`
+    (lints, "lints", std::collections::BTreeMap::new() => std::collections::BTreeMap<String, crate::utils::conf::LintConf>),
+    /// Paths of the lint plugins to load, relative to the configuration file, e.g. `["target/release/libmyorg_lints.so"]`
+    (lint_plugins, "lint_plugins", Vec::new() => std::vec::Vec<String>),
+    /// Settings of the lint plugins, one table per plugin, e.g. `[plugins.myorg]`
+    (plugins, "plugins", std::collections::BTreeMap::new() => std::collections::BTreeMap<String, toml::value::Table>),
+}
+
+impl Conf {
+    /// Applies the `threshold` settings of the `[lints]` table to the matching configuration keys.
+    ///
+    /// A threshold set in the `[lints]` table takes precedence over the top-level key.
+    fn apply_lint_thresholds(&mut self, errors: &mut Vec<Error>) {
+        let thresholds: Vec<(String, u64)> = self
+            .lints
+            .iter()
+            .filter_map(|(name, lint)| lint.threshold().map(|threshold| (name.clone(), threshold)))
+            .collect();
+
+        for (name, threshold) in thresholds {
+            match lint_opt_name(&name).trim_start_matches("clippy::") {
+                "cognitive_complexity" => self.cognitive_complexity_threshold = threshold,
+                "too_many_arguments" => self.too_many_arguments_threshold = threshold,
+                "type_complexity" => self.type_complexity_threshold = threshold,
+                "many_single_char_names" => self.single_char_binding_names_threshold = threshold,
+                "boxed_local" => self.too_large_for_stack = threshold,
+                "enum_variant_names" => self.enum_variant_name_threshold = threshold,
+                "large_enum_variant" => self.enum_variant_size_threshold = threshold,
+                "verbose_bit_mask" => self.verbose_bit_mask_threshold = threshold,
+                "decimal_literal_representation" => self.literal_representation_threshold = threshold,
+                "trivially_copy_pass_by_ref" => self.trivial_copy_size_limit = Some(threshold),
+                "too_many_lines" => self.too_many_lines_threshold = threshold,
+                _ => errors.push(Error::Toml(format!(
+                    "lint `{}` in the `[lints]` table has no `threshold` setting",
+                    name
+                ))),
+            }
+        }
+    }
 }
 
 impl Default for Conf {
@@ -204,33 +393,343 @@ fn default(errors: Vec<Error>) -> (Conf, Vec<Error>) {
     (Conf::default(), errors)
 }
 
+/// Key naming the configuration file a configuration file inherits from.
+const EXTENDS_KEY: &str = "extends";
+/// Key of the path-scoped override sections of a configuration file.
+const OVERRIDES_KEY: &str = "overrides";
+/// Key of the path patterns an override section applies to.
+const OVERRIDE_PATHS_KEY: &str = "paths";
+
+/// A configuration file that has been parsed but not merged yet.
+struct Layer {
+    /// The path of the file.
+    path: path::PathBuf,
+    /// The keys of the file, without `extends` and `overrides`.
+    table: toml::value::Table,
+    /// The `[[overrides]]` sections of the file.
+    overrides: Vec<toml::value::Table>,
+}
+
+/// Reads the whole content of a configuration file.
+fn read_file(path: &path::Path) -> Result<String, Error> {
+    let mut file = fs::File::open(path)?;
+    let mut buf = String::new();
+    file.read_to_string(&mut buf)?;
+    Ok(buf)
+}
+
+/// Whether a configuration file needs to be merged with other files or override sections.
+fn is_layered(toml: &toml::Value) -> bool {
+    toml.get(EXTENDS_KEY).is_some() || toml.get(OVERRIDES_KEY).is_some()
+}
+
+/// Parses `path` and, first, the files it `extends`, pushing them to `layers` from the most
+/// general to the most specific one.
+///
+/// `visiting` holds the files currently being read, to detect cycles.
+fn read_layers(path: &path::Path, visiting: &mut Vec<path::PathBuf>, layers: &mut Vec<Layer>, errors: &mut Vec<Error>) {
+    if visiting.iter().any(|visited| visited == path) {
+        errors.push(Error::Toml(format!(
+            "`{}` extends itself through `{}`",
+            path.display(),
+            visiting.last().map_or(path, path::PathBuf::as_path).display()
+        )));
+        return;
+    }
+
+    let toml = read_file(path).and_then(|file| toml::from_str(&file).map_err(|e| Error::Toml(e.to_string())));
+    let mut table = match toml {
+        Ok(toml::Value::Table(table)) => table,
+        Ok(_) => unreachable!("a toml document is always a table"),
+        Err(e) => {
+            errors.push(Error::Toml(format!("in `{}`: {}", path.display(), e)));
+            return;
+        },
+    };
+
+    match table.remove(EXTENDS_KEY) {
+        Some(toml::Value::String(parent)) => {
+            let parent = path.parent().unwrap_or_else(|| path::Path::new("")).join(parent);
+            visiting.push(path.to_path_buf());
+            read_layers(&parent, visiting, layers, errors);
+            visiting.pop();
+        },
+        Some(value) => errors.push(Error::Toml(format!(
+            "in `{}`: `{}` must be a string, found {}",
+            path.display(),
+            EXTENDS_KEY,
+            value.type_str()
+        ))),
+        None => (),
+    }
+
+    let overrides = match table.remove(OVERRIDES_KEY) {
+        Some(toml::Value::Array(overrides)) => overrides
+            .into_iter()
+            .filter_map(|section| match section {
+                toml::Value::Table(section) => Some(section),
+                _ => {
+                    errors.push(Error::Toml(format!(
+                        "in `{}`: `{}` must be an array of tables",
+                        path.display(),
+                        OVERRIDES_KEY
+                    )));
+                    None
+                },
+            })
+            .collect(),
+        Some(_) => {
+            errors.push(Error::Toml(format!(
+                "in `{}`: `{}` must be an array of tables",
+                path.display(),
+                OVERRIDES_KEY
+            )));
+            Vec::new()
+        },
+        None => Vec::new(),
+    };
+
+    layers.push(Layer {
+        path: path.to_path_buf(),
+        table,
+        overrides,
+    });
+}
+
+/// Records `path` as the source of `key`, and of each of its entries if it is the `[lints]` table.
+fn record_source(sources: &mut BTreeMap<String, path::PathBuf>, key: &str, value: &toml::Value, path: &path::Path) {
+    sources.insert(key.to_string(), path.to_path_buf());
+    if let (Some(lints), "lints") = (value.as_table(), key) {
+        for name in lints.keys() {
+            sources.insert(format!("lints.{}", name), path.to_path_buf());
+        }
+    }
+}
+
+/// Merges the keys of `table` into `merged`, recording `path` as their source.
+///
+/// Entries of the `[lints]` and `[plugins]` tables are merged one by one, all other keys are
+/// replaced.
+fn merge_table(
+    merged: &mut toml::value::Table,
+    sources: &mut BTreeMap<String, path::PathBuf>,
+    table: toml::value::Table,
+    path: &path::Path,
+) {
+    for (key, value) in table {
+        record_source(sources, &key, &value, path);
+        match (merged.get_mut(&key), value) {
+            (Some(toml::Value::Table(old)), toml::Value::Table(new)) if key == "lints" || key == "plugins" => {
+                old.extend(new)
+            },
+            (_, value) => {
+                merged.insert(key, value);
+            },
+        }
+    }
+}
+
+/// Returns the path of `path` relative to `base`, resolving symlinks and `..` if possible.
+fn relative_to(path: &path::Path, base: &path::Path) -> Option<path::PathBuf> {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let base = base.canonicalize().unwrap_or_else(|_| base.to_path_buf());
+    path.strip_prefix(base).ok().map(path::Path::to_path_buf)
+}
+
+/// Matches a single path component against a pattern where `*` matches any sequence of
+/// characters and `?` matches any single character.
+fn component_matches(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some((&'*', rest)) => (0..=name.len()).any(|i| component_matches(rest, &name[i..])),
+        Some((&c, rest)) => name.split_first().map_or(false, |(&n, name)| {
+            (c == '?' || c == n) && component_matches(rest, name)
+        }),
+    }
+}
+
+/// Matches path components against pattern components where `**` matches any number of
+/// components.
+fn components_match(pattern: &[&str], path: &[String]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|i| components_match(rest, &path[i..])),
+        Some((first, rest)) => path.split_first().map_or(false, |(name, path)| {
+            component_matches(&first.chars().collect::<Vec<_>>(), &name.chars().collect::<Vec<_>>())
+                && components_match(rest, path)
+        }),
+    }
+}
+
+/// Whether `path` or one of its parent directories matches the glob `pattern`, e.g. `tests/**`,
+/// `benches` or `src/*_test.rs`.
+fn glob_matches(pattern: &str, path: &path::Path) -> bool {
+    let pattern: Vec<&str> = pattern.split('/').filter(|c| !c.is_empty() && *c != ".").collect();
+    let path: Vec<String> = path
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    (1..=path.len()).any(|len| components_match(&pattern, &path[..len]))
+}
+
+/// Checks the keys of an override `section` of `file` that isn't merged into the configuration.
+fn check_override(section: &toml::value::Table, file: &path::Path, errors: &mut Vec<Error>) {
+    let mut section_errors = Vec::new();
+    match Conf::deserialize(toml::Value::Table(section.clone())) {
+        Ok(mut conf) => conf.apply_lint_thresholds(&mut section_errors),
+        Err(e) => section_errors.push(Error::Toml(e.to_string())),
+    }
+    // invalid values of known keys are recorded in `ERRORS` instead of failing the deserialization
+    section_errors.extend(ERRORS.lock().expect("no threading -> mutex always safe").split_off(0));
+
+    for error in section_errors {
+        errors.push(Error::Toml(format!(
+            "in an `[[{}]]` section of `{}`: {}",
+            OVERRIDES_KEY,
+            file.display(),
+            error
+        )));
+    }
+}
+
+/// Whether the override `section` of a configuration file in `dir` applies to `crate_root`.
+///
+/// Without a `crate_root`, no section applies but their `paths` are still checked.
+fn override_applies(
+    section: &toml::value::Table,
+    dir: &path::Path,
+    crate_root: Option<&path::Path>,
+    errors: &mut Vec<Error>,
+) -> bool {
+    let patterns = match section.get(OVERRIDE_PATHS_KEY).and_then(toml::Value::as_array) {
+        Some(patterns) => patterns,
+        None => {
+            errors.push(Error::Toml(format!(
+                "`{}` sections need a `{}` array",
+                OVERRIDES_KEY, OVERRIDE_PATHS_KEY
+            )));
+            return false;
+        },
+    };
+
+    crate_root
+        .and_then(|crate_root| relative_to(crate_root, dir))
+        .map_or(false, |crate_root| {
+            patterns
+                .iter()
+                .filter_map(toml::Value::as_str)
+                .any(|pattern| glob_matches(pattern, &crate_root))
+        })
+}
+
+/// Reads `path`, the files it `extends` and the `[[overrides]]` sections applying to
+/// `crate_root`, and merges them into a single configuration.
+fn read_layered(path: &path::Path, crate_root: Option<&path::Path>, errors: &mut Vec<Error>) -> Option<Conf> {
+    let mut layers = Vec::new();
+    read_layers(path, &mut Vec::new(), &mut layers, errors);
+
+    let mut merged = toml::value::Table::new();
+    let mut sources = BTreeMap::new();
+    let mut overrides = Vec::new();
+    for layer in layers {
+        merge_table(&mut merged, &mut sources, layer.table, &layer.path);
+        let dir = layer.path.parent().unwrap_or_else(|| path::Path::new("")).to_path_buf();
+        overrides.extend(
+            layer
+                .overrides
+                .into_iter()
+                .map(|section| (layer.path.clone(), dir.clone(), section)),
+        );
+    }
+
+    // Overrides are applied after all files are merged, so that they also win against the keys
+    // of more specific files.
+    // Sections that don't apply are still checked, so that a typo doesn't go unnoticed until the
+    // section is used.
+    for (file, dir, mut section) in overrides {
+        let applies = override_applies(&section, &dir, crate_root, errors);
+        section.remove(OVERRIDE_PATHS_KEY);
+        if applies {
+            merge_table(&mut merged, &mut sources, section, &file);
+        } else {
+            check_override(&section, &file, errors);
+        }
+    }
+
+    match Conf::deserialize(toml::Value::Table(merged)) {
+        Ok(mut conf) => {
+            conf.sources = sources;
+            Some(conf)
+        },
+        Err(e) => {
+            errors.push(Error::Toml(e.to_string()));
+            None
+        },
+    }
+}
+
 /// Read the `toml` configuration file.
 ///
+/// If the file `extends` other configuration files or has `[[overrides]]` sections, all of them
+/// are merged, applying the overrides whose `paths` match `crate_root`. Otherwise the file is
+/// deserialized directly, so errors keep their position.
+///
 /// In case of error, the function tries to continue as much as possible.
-pub fn read(path: Option<&path::Path>) -> (Conf, Vec<Error>) {
+pub fn read(path: Option<&path::Path>, crate_root: Option<&path::Path>) -> (Conf, Vec<Error>) {
     let path = if let Some(path) = path {
         path
     } else {
         return default(Vec::new());
     };
 
-    let file = match fs::File::open(path) {
-        Ok(mut file) => {
-            let mut buf = String::new();
-
-            if let Err(err) = file.read_to_string(&mut buf) {
-                return default(vec![err.into()]);
-            }
+    let file = match read_file(path) {
+        Ok(file) => file,
+        Err(err) => return default(vec![err]),
+    };
 
-            buf
+    assert!(ERRORS.lock().expect("no threading -> mutex always safe").is_empty());
+    let mut layer_errors = Vec::new();
+    let conf = match toml::from_str::<toml::Value>(&file) {
+        Ok(ref toml) if is_layered(toml) => read_layered(path, crate_root, &mut layer_errors),
+        toml => match toml::from_str::<Conf>(&file) {
+            Ok(mut conf) => {
+                if let Ok(toml::Value::Table(table)) = toml {
+                    for (key, value) in &table {
+                        record_source(&mut conf.sources, key, value, path);
+                    }
+                }
+                Some(conf)
+            },
+            Err(e) => {
+                layer_errors.push(Error::Toml(e.to_string()));
+                None
+            },
         },
-        Err(err) => return default(vec![err.into()]),
     };
 
-    assert!(ERRORS.lock().expect("no threading -> mutex always safe").is_empty());
-    match toml::from_str(&file) {
-        Ok(toml) => {
-            let mut errors = ERRORS.lock().expect("no threading -> mutex always safe").split_off(0);
+    let mut errors = ERRORS.lock().expect("no threading -> mutex always safe").split_off(0);
+    errors.extend(layer_errors);
+
+    match conf {
+        Some(mut toml) => {
+            toml.apply_lint_thresholds(&mut errors);
+            if let Some(version) = toml.max_lint_version.as_ref().filter(|v| parse_version(v).is_none()) {
+                errors.push(Error::Toml(format!(
+                    "invalid `max-lint-version` `{}`, expected a version like `0.0.212`",
+                    version
+                )));
+            }
+
+            if let Some(msrv) = toml
+                .msrv
+                .as_ref()
+                .filter(|v| crate::utils::msrvs::RustcVersion::parse(v).is_none())
+            {
+                errors.push(Error::Toml(format!(
+                    "invalid `msrv` `{}`, expected a Rust version like `1.31`",
+                    msrv
+                )));
+            }
 
             let toml_ref: &Conf = &toml;
 
@@ -243,11 +742,59 @@ pub fn read(path: Option<&path::Path>) -> (Conf, Vec<Error>) {
 
             (toml, errors)
         },
-        Err(e) => {
-            let mut errors = ERRORS.lock().expect("no threading -> mutex always safe").split_off(0);
-            errors.push(Error::Toml(e.to_string()));
+        None => default(errors),
+    }
+}
 
-            default(errors)
-        },
+#[cfg(test)]
+mod test {
+    use super::{check_override, glob_matches, parse_version};
+    use std::path::Path;
+
+    #[test]
+    fn glob_directories() {
+        assert!(glob_matches("tests/**", Path::new("tests/foo.rs")));
+        assert!(glob_matches("tests/**", Path::new("tests/ui/foo.rs")));
+        assert!(glob_matches("tests", Path::new("tests/foo.rs")));
+        assert!(glob_matches("./benches/", Path::new("benches/foo.rs")));
+        assert!(!glob_matches("tests/**", Path::new("src/tests.rs")));
+    }
+
+    #[test]
+    fn glob_wildcards() {
+        assert!(glob_matches("src/*_test.rs", Path::new("src/foo_test.rs")));
+        assert!(glob_matches("**/*_test.rs", Path::new("src/bar/foo_test.rs")));
+        assert!(glob_matches("src/ba?.rs", Path::new("src/bar.rs")));
+        assert!(!glob_matches("src/*_test.rs", Path::new("src/bar/foo_test.rs")));
+        assert!(!glob_matches("src/ba?.rs", Path::new("src/ba.rs")));
+    }
+
+    #[test]
+    fn versions() {
+        assert_eq!(parse_version("0.0.212"), Some(vec![0, 0, 212]));
+        assert!(parse_version("0.0.99") < parse_version("0.0.212"));
+        assert_eq!(parse_version("0.0.x"), None);
+        assert_eq!(parse_version(""), None);
+    }
+
+    #[test]
+    fn unapplied_overrides() {
+        let check = |section: &str| {
+            let mut errors = Vec::new();
+            check_override(&toml::from_str(section).unwrap(), Path::new("clippy.toml"), &mut errors);
+            errors.iter().map(ToString::to_string).collect::<Vec<_>>()
+        };
+
+        assert!(check("too-many-lines-threshold = 60\n[lints]\nneedless_return = \"deny\"").is_empty());
+        assert_eq!(check("too-many-line-threshold = 60").len(), 1);
+        assert_eq!(check("too-many-lines-threshold = \"60\"").len(), 1);
+        assert_eq!(check("[lints]\nneedless_return = \"denied\"").len(), 1);
+        assert_eq!(
+            check("[lints]\nneedless_return = { level = \"deny\", threshold = 1 }").len(),
+            1
+        );
+        assert!(
+            check("too-many-line-threshold = 60")[0].starts_with("in an `[[overrides]]` section of `clippy.toml`: ")
+        );
     }
 }
@@ -1,7 +1,9 @@
 //! Clippy wrappers around rustc's diagnostic functions.
 
+use crate::utils::{baseline, profile};
 use rustc::hir::HirId;
 use rustc::lint::{LateContext, Lint, LintContext};
+use rustc::session::{DiagnosticMessageId, Session};
 use rustc_errors::{Applicability, CodeSuggestion, Substitution, SubstitutionPart, SuggestionStyle};
 use std::env;
 use syntax::errors::DiagnosticBuilder;
@@ -17,6 +19,27 @@ impl<'a> Drop for DiagnosticWrapper<'a> {
 }
 
 impl<'a> DiagnosticWrapper<'a> {
+    /// Cancels the lint if it is part of the baseline, see `utils::baseline`, or counts it for the
+    /// pass being profiled, see `utils::profile`.
+    fn baseline(&mut self, sess: &Session, lint: &'static Lint) {
+        if !self.0.cancelled() && baseline::suppresses(sess, lint, &self.0.span) {
+            // rustc only adds notes like "`-D clippy::x` implied by `-D warnings`" to the first
+            // diagnostic of each lint, forget them so that the next emitted one has them
+            let mut one_time = sess.one_time_diagnostics.lock();
+            for child in &self.0.children {
+                one_time.remove(&(
+                    DiagnosticMessageId::from(lint),
+                    child.span.primary_span(),
+                    child.message(),
+                ));
+            }
+            self.0.cancel();
+        }
+        if !self.0.cancelled() {
+            profile::record_warning(lint);
+        }
+    }
+
     fn docs_link(&mut self, lint: &'static Lint) {
         if env::var("CLIPPY_DISABLE_DOCS_LINKS").is_err() {
             self.0.help(&format!(
@@ -49,7 +72,9 @@ impl<'a> DiagnosticWrapper<'a> {
 ///    |     ^^^^^^^^^^^^^^^^^^^^^^^
 /// ```
 pub fn span_lint<T: LintContext>(cx: &T, lint: &'static Lint, sp: impl Into<MultiSpan>, msg: &str) {
-    DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg)).docs_link(lint);
+    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg));
+    db.docs_link(lint);
+    db.baseline(cx.sess(), lint);
 }
 
 /// Same as `span_lint` but with an extra `help` message.
@@ -74,6 +99,7 @@ pub fn span_help_and_lint<'a, T: LintContext>(cx: &'a T, lint: &'static Lint, sp
     let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg));
     db.0.help(help);
     db.docs_link(lint);
+    db.baseline(cx.sess(), lint);
 }
 
 /// Like `span_lint` but with a `note` section instead of a `help` message.
@@ -112,6 +138,7 @@ pub fn span_note_and_lint<'a, T: LintContext>(
         db.0.span_note(note_span, note);
     }
     db.docs_link(lint);
+    db.baseline(cx.sess(), lint);
 }
 
 pub fn span_lint_and_then<'a, T: LintContext, F>(cx: &'a T, lint: &'static Lint, sp: Span, msg: &str, f: F)
@@ -121,10 +148,13 @@ where
     let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg));
     f(&mut db.0);
     db.docs_link(lint);
+    db.baseline(cx.sess(), lint);
 }
 
 pub fn span_lint_hir(cx: &LateContext<'_, '_>, lint: &'static Lint, hir_id: HirId, sp: Span, msg: &str) {
-    DiagnosticWrapper(cx.tcx.struct_span_lint_hir(lint, hir_id, sp, msg)).docs_link(lint);
+    let mut db = DiagnosticWrapper(cx.tcx.struct_span_lint_hir(lint, hir_id, sp, msg));
+    db.docs_link(lint);
+    db.baseline(cx.sess(), lint);
 }
 
 pub fn span_lint_hir_and_then(
@@ -138,6 +168,7 @@ pub fn span_lint_hir_and_then(
     let mut db = DiagnosticWrapper(cx.tcx.struct_span_lint_hir(lint, hir_id, sp, msg));
     f(&mut db.0);
     db.docs_link(lint);
+    db.baseline(cx.sess(), lint);
 }
 
 /// Add a span lint with a suggestion on how to fix it.
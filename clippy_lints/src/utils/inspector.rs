@@ -2,11 +2,17 @@
 
 use crate::utils::get_attr;
 use rustc::hir;
+use rustc::hir::def::Res;
+use rustc::hir::def_id::DefId;
 use rustc::hir::print;
+use rustc::hir::ptr::P;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintContext, LintPass};
 use rustc::session::Session;
+use rustc::ty::TypeckTables;
 use rustc::{declare_lint_pass, declare_tool_lint};
-use syntax::ast::Attribute;
+use serde_json::{json, Map, Value};
+use syntax::ast::{Attribute, Ident};
+use syntax::source_map::Span;
 
 declare_clippy_lint! {
     /// **What it does:** Dumps every ast/hir node which has the `#[clippy::dump]`
@@ -25,6 +31,10 @@ declare_clippy_lint! {
     /// visibility inherited from outer item
     /// extern crate dylib source: "/path/to/foo.so"
     /// ```
+    ///
+    /// `#[clippy::dump = "json"]` prints the whole tree of the node as JSON instead, with the
+    /// kinds, spans, resolved paths, types and adjustments of its nodes.
+    #[clippy::version = "0.0.212"]
     pub DEEP_CODE_INSPECTION,
     internal_warn,
     "helper to dump info about code"
@@ -32,113 +42,201 @@ declare_clippy_lint! {
 
 declare_lint_pass!(DeepCodeInspector => [DEEP_CODE_INSPECTION]);
 
+/// The output formats of `#[clippy::dump]`.
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    /// `#[clippy::dump]`
+    Text,
+    /// `#[clippy::dump = "json"]`
+    Json,
+}
+
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DeepCodeInspector {
     fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::Item) {
-        if !has_attr(cx.sess(), &item.attrs) {
-            return;
+        match dump_format(cx.sess(), &item.attrs) {
+            Some(Format::Text) => print_item(cx, item),
+            Some(Format::Json) => print_json(&JsonDumper::new(cx).item(item)),
+            None => {},
         }
-        print_item(cx, item);
     }
 
     fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::ImplItem) {
-        if !has_attr(cx.sess(), &item.attrs) {
-            return;
+        match dump_format(cx.sess(), &item.attrs) {
+            Some(Format::Text) => print_impl_item(cx, item),
+            Some(Format::Json) => print_json(&JsonDumper::new(cx).impl_item(item)),
+            None => {},
         }
-        println!("impl item `{}`", item.ident.name);
-        match item.vis.node {
-            hir::VisibilityKind::Public => println!("public"),
-            hir::VisibilityKind::Crate(_) => println!("visible crate wide"),
-            hir::VisibilityKind::Restricted { ref path, .. } => println!(
-                "visible in module `{}`",
-                print::to_string(print::NO_ANN, |s| s.print_path(path, false))
-            ),
-            hir::VisibilityKind::Inherited => println!("visibility inherited from outer item"),
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::TraitItem) {
+        match dump_format(cx.sess(), &item.attrs) {
+            Some(Format::Text) => print_trait_item(item),
+            Some(Format::Json) => print_json(&JsonDumper::new(cx).trait_item(item)),
+            None => {},
         }
-        if item.defaultness.is_default() {
-            println!("default");
+    }
+
+    fn check_variant(&mut self, cx: &LateContext<'a, 'tcx>, var: &'tcx hir::Variant) {
+        match dump_format(cx.sess(), &var.attrs) {
+            Some(Format::Text) => print_variant(cx, var),
+            Some(Format::Json) => print_json(&JsonDumper::new(cx).variant(var)),
+            None => {},
         }
-        match item.kind {
-            hir::ImplItemKind::Const(_, body_id) => {
-                println!("associated constant");
-                print_expr(cx, &cx.tcx.hir().body(body_id).value, 1);
-            },
-            hir::ImplItemKind::Method(..) => println!("method"),
-            hir::ImplItemKind::TyAlias(_) => println!("associated type"),
-            hir::ImplItemKind::OpaqueTy(_) => println!("existential type"),
+    }
+
+    fn check_struct_field(&mut self, cx: &LateContext<'a, 'tcx>, field: &'tcx hir::StructField) {
+        match dump_format(cx.sess(), &field.attrs) {
+            Some(Format::Text) => print_struct_field(cx, field),
+            Some(Format::Json) => print_json(&JsonDumper::new(cx).struct_field(field)),
+            None => {},
         }
     }
-    // fn check_trait_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx
-    // hir::TraitItem) {
-    // if !has_attr(&item.attrs) {
-    // return;
-    // }
-    // }
-    //
-    // fn check_variant(&mut self, cx: &LateContext<'a, 'tcx>, var: &'tcx
-    // hir::Variant, _:
-    // &hir::Generics) {
-    // if !has_attr(&var.node.attrs) {
-    // return;
-    // }
-    // }
-    //
-    // fn check_struct_field(&mut self, cx: &LateContext<'a, 'tcx>, field: &'tcx
-    // hir::StructField) {
-    // if !has_attr(&field.attrs) {
-    // return;
-    // }
-    // }
-    //
 
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx hir::Expr) {
-        if !has_attr(cx.sess(), &expr.attrs) {
-            return;
+        match dump_format(cx.sess(), &expr.attrs) {
+            Some(Format::Text) => print_expr(cx, expr, 0),
+            Some(Format::Json) => print_json(&JsonDumper::new(cx).expr(expr)),
+            None => {},
         }
-        print_expr(cx, expr, 0);
     }
 
     fn check_arm(&mut self, cx: &LateContext<'a, 'tcx>, arm: &'tcx hir::Arm) {
-        if !has_attr(cx.sess(), &arm.attrs) {
-            return;
+        match dump_format(cx.sess(), &arm.attrs) {
+            Some(Format::Text) => print_arm(cx, arm),
+            Some(Format::Json) => print_json(&JsonDumper::new(cx).arm(arm)),
+            None => {},
         }
-        print_pat(cx, &arm.pat, 1);
-        if let Some(ref guard) = arm.guard {
-            println!("guard:");
-            print_guard(cx, guard, 1);
-        }
-        println!("body:");
-        print_expr(cx, &arm.body, 1);
     }
 
     fn check_stmt(&mut self, cx: &LateContext<'a, 'tcx>, stmt: &'tcx hir::Stmt) {
-        if !has_attr(cx.sess(), stmt.kind.attrs()) {
-            return;
+        match dump_format(cx.sess(), stmt.kind.attrs()) {
+            Some(Format::Text) => print_stmt(cx, stmt),
+            Some(Format::Json) => print_json(&JsonDumper::new(cx).stmt(stmt)),
+            None => {},
         }
-        match stmt.kind {
-            hir::StmtKind::Local(ref local) => {
-                println!("local variable of type {}", cx.tables.node_type(local.hir_id));
-                println!("pattern:");
-                print_pat(cx, &local.pat, 0);
-                if let Some(ref e) = local.init {
-                    println!("init expression:");
-                    print_expr(cx, e, 0);
-                }
-            },
-            hir::StmtKind::Item(_) => println!("item decl"),
-            hir::StmtKind::Expr(ref e) | hir::StmtKind::Semi(ref e) => print_expr(cx, e, 0),
+    }
+
+    fn check_foreign_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::ForeignItem) {
+        match dump_format(cx.sess(), &item.attrs) {
+            Some(Format::Text) => print_foreign_item(cx, item),
+            Some(Format::Json) => print_json(&JsonDumper::new(cx).foreign_item(item)),
+            None => {},
         }
     }
-    // fn check_foreign_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx
-    // hir::ForeignItem) {
-    // if !has_attr(&item.attrs) {
-    // return;
-    // }
-    // }
-    //
 }
 
-fn has_attr(sess: &Session, attrs: &[Attribute]) -> bool {
-    get_attr(sess, attrs, "dump").count() > 0
+/// The format of the `#[clippy::dump]` attribute in `attrs`, if there is one.
+fn dump_format(sess: &Session, attrs: &[Attribute]) -> Option<Format> {
+    let attr = get_attr(sess, attrs, "dump").next()?;
+    match attr.value_str() {
+        None => Some(Format::Text),
+        Some(format) if format.as_str() == "json" => Some(Format::Json),
+        Some(format) => {
+            sess.span_err(
+                attr.span,
+                &format!("unknown `clippy::dump` format `{}`, the only format is `json`", format),
+            );
+            None
+        },
+    }
+}
+
+fn print_impl_item(cx: &LateContext<'_, '_>, item: &hir::ImplItem) {
+    println!("impl item `{}`", item.ident.name);
+    match item.vis.node {
+        hir::VisibilityKind::Public => println!("public"),
+        hir::VisibilityKind::Crate(_) => println!("visible crate wide"),
+        hir::VisibilityKind::Restricted { ref path, .. } => println!(
+            "visible in module `{}`",
+            print::to_string(print::NO_ANN, |s| s.print_path(path, false))
+        ),
+        hir::VisibilityKind::Inherited => println!("visibility inherited from outer item"),
+    }
+    if item.defaultness.is_default() {
+        println!("default");
+    }
+    match item.kind {
+        hir::ImplItemKind::Const(_, body_id) => {
+            println!("associated constant");
+            print_expr(cx, &cx.tcx.hir().body(body_id).value, 1);
+        },
+        hir::ImplItemKind::Method(..) => println!("method"),
+        hir::ImplItemKind::TyAlias(_) => println!("associated type"),
+        hir::ImplItemKind::OpaqueTy(_) => println!("existential type"),
+    }
+}
+
+fn print_trait_item(item: &hir::TraitItem) {
+    println!("trait item `{}`", item.ident.name);
+    match item.kind {
+        hir::TraitItemKind::Const(_, default) => {
+            println!("associated constant");
+            if default.is_some() {
+                println!("with a default value");
+            }
+        },
+        hir::TraitItemKind::Method(_, hir::TraitMethod::Required(_)) => println!("required method"),
+        hir::TraitItemKind::Method(_, hir::TraitMethod::Provided(_)) => println!("provided method"),
+        hir::TraitItemKind::Type(_, ref default) => {
+            println!("associated type");
+            if default.is_some() {
+                println!("with a default type");
+            }
+        },
+    }
+}
+
+fn print_variant(cx: &LateContext<'_, '_>, var: &hir::Variant) {
+    println!("variant `{}`", var.ident.name);
+    match var.data {
+        hir::VariantData::Struct(..) => println!("struct variant"),
+        hir::VariantData::Tuple(..) => println!("tuple variant"),
+        hir::VariantData::Unit(..) => println!("unit variant"),
+    }
+    for field in var.data.fields() {
+        print_struct_field(cx, field);
+    }
+}
+
+fn print_arm(cx: &LateContext<'_, '_>, arm: &hir::Arm) {
+    print_pat(cx, &arm.pat, 1);
+    if let Some(ref guard) = arm.guard {
+        println!("guard:");
+        print_guard(cx, guard, 1);
+    }
+    println!("body:");
+    print_expr(cx, &arm.body, 1);
+}
+
+fn print_stmt(cx: &LateContext<'_, '_>, stmt: &hir::Stmt) {
+    match stmt.kind {
+        hir::StmtKind::Local(ref local) => {
+            println!("local variable of type {}", cx.tables.node_type(local.hir_id));
+            println!("pattern:");
+            print_pat(cx, &local.pat, 0);
+            if let Some(ref e) = local.init {
+                println!("init expression:");
+                print_expr(cx, e, 0);
+            }
+        },
+        hir::StmtKind::Item(_) => println!("item decl"),
+        hir::StmtKind::Expr(ref e) | hir::StmtKind::Semi(ref e) => print_expr(cx, e, 0),
+    }
+}
+
+fn print_foreign_item(cx: &LateContext<'_, '_>, item: &hir::ForeignItem) {
+    let did = cx.tcx.hir().local_def_id(item.hir_id);
+    println!("foreign item `{}`", item.ident.name);
+    match item.kind {
+        hir::ForeignItemKind::Fn(..) => println!("function of type {:#?}", cx.tcx.type_of(did)),
+        hir::ForeignItemKind::Static(..) => println!("static item of type {:#?}", cx.tcx.type_of(did)),
+        hir::ForeignItemKind::Type => println!("foreign type"),
+    }
+}
+
+fn print_struct_field(cx: &LateContext<'_, '_>, field: &hir::StructField) {
+    let did = cx.tcx.hir().local_def_id(field.hir_id);
+    println!("field `{}` of type {:?}", field.ident.name, cx.tcx.type_of(did));
 }
 
 #[allow(clippy::similar_names)]
@@ -514,3 +612,729 @@ fn print_guard(cx: &LateContext<'_, '_>, guard: &hir::Guard, indent: usize) {
         },
     }
 }
+
+fn print_json(value: &Value) {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(value).expect("JSON values are always serializable")
+    );
+}
+
+/// Builds the JSON trees of `#[clippy::dump = "json"]`. Every node has its `node` type, its
+/// `kind` and its `span`, expressions and patterns also have their type and adjustments, and
+/// paths the `DefPath` they resolve to.
+struct JsonDumper<'a, 'tcx> {
+    cx: &'a LateContext<'a, 'tcx>,
+    /// The tables of the body being dumped, replaced when entering a nested body.
+    tables: &'a TypeckTables<'tcx>,
+}
+
+impl<'a, 'tcx> JsonDumper<'a, 'tcx> {
+    fn new(cx: &'a LateContext<'a, 'tcx>) -> Self {
+        Self { cx, tables: cx.tables }
+    }
+
+    /// The common fields of the nodes, added to the `fields` specific to their kind.
+    fn node(&self, node: &str, kind: &str, span: Span, fields: Value) -> Map<String, Value> {
+        let mut map = match fields {
+            Value::Object(map) => map,
+            _ => Map::new(),
+        };
+        map.insert("node".to_string(), json!(node));
+        map.insert("kind".to_string(), json!(kind));
+        map.insert(
+            "span".to_string(),
+            json!(self.cx.sess().source_map().span_to_string(span)),
+        );
+        map
+    }
+
+    fn def_path(&self, def_id: Option<DefId>) -> Value {
+        def_id.map_or(Value::Null, |def_id| json!(self.cx.tcx.def_path_str(def_id)))
+    }
+
+    fn res(&self, res: Res) -> Value {
+        self.def_path(res.opt_def_id())
+    }
+
+    fn local_def_path(&self, hir_id: hir::HirId) -> Value {
+        self.def_path(Some(self.cx.tcx.hir().local_def_id(hir_id)))
+    }
+
+    /// The type of the definition of `hir_id`.
+    fn type_of(&self, hir_id: hir::HirId) -> Value {
+        json!(self.cx.tcx.type_of(self.cx.tcx.hir().local_def_id(hir_id)).to_string())
+    }
+
+    /// The signature of the function `hir_id`.
+    fn fn_sig(&self, hir_id: hir::HirId) -> Value {
+        json!(self.cx.tcx.fn_sig(self.cx.tcx.hir().local_def_id(hir_id)).to_string())
+    }
+
+    /// A path in a body, which may be resolved by type checking.
+    fn qpath(&self, qpath: &hir::QPath, hir_id: hir::HirId) -> Value {
+        json!({
+            "path": print::to_string(print::NO_ANN, |s| s.print_qpath(qpath, false)),
+            "res": self.res(self.tables.qpath_res(qpath, hir_id)),
+        })
+    }
+
+    fn body(&self, body_id: hir::BodyId) -> Value {
+        let body = self.cx.tcx.hir().body(body_id);
+        let dumper = Self {
+            cx: self.cx,
+            tables: self.cx.tcx.body_tables(body_id),
+        };
+        json!({
+            "params": body.params.iter().map(|param| dumper.pat(&param.pat)).collect::<Vec<_>>(),
+            "value": dumper.expr(&body.value),
+        })
+    }
+
+    fn opt_body(&self, body_id: Option<hir::BodyId>) -> Value {
+        body_id.map_or(Value::Null, |body_id| self.body(body_id))
+    }
+
+    fn exprs(&self, exprs: &[hir::Expr]) -> Vec<Value> {
+        exprs.iter().map(|expr| self.expr(expr)).collect()
+    }
+
+    fn opt_expr(&self, expr: &Option<P<hir::Expr>>) -> Value {
+        expr.as_ref().map_or(Value::Null, |expr| self.expr(expr))
+    }
+
+    fn pats(&self, pats: &[P<hir::Pat>]) -> Vec<Value> {
+        pats.iter().map(|pat| self.pat(pat)).collect()
+    }
+
+    fn tys(&self, tys: &[hir::Ty]) -> Vec<Value> {
+        tys.iter().map(|ty| self.ty(ty)).collect()
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn expr(&self, expr: &hir::Expr) -> Value {
+        let (kind, fields) = match expr.kind {
+            hir::ExprKind::Box(ref inner) => ("Box", json!({ "inner": self.expr(inner) })),
+            hir::ExprKind::Array(ref elements) => ("Array", json!({ "elements": self.exprs(elements) })),
+            hir::ExprKind::Call(ref func, ref args) => (
+                "Call",
+                json!({
+                    "func": self.expr(func),
+                    "args": self.exprs(args),
+                }),
+            ),
+            hir::ExprKind::MethodCall(ref path, _, ref args) => (
+                "MethodCall",
+                json!({
+                    "method": path.ident.name.to_string(),
+                    "res": self.def_path(self.tables.type_dependent_def_id(expr.hir_id)),
+                    "args": self.exprs(args),
+                }),
+            ),
+            hir::ExprKind::Tup(ref elements) => ("Tup", json!({ "elements": self.exprs(elements) })),
+            hir::ExprKind::Binary(op, ref lhs, ref rhs) => (
+                "Binary",
+                json!({
+                    "op": format!("{:?}", op.node),
+                    "lhs": self.expr(lhs),
+                    "rhs": self.expr(rhs),
+                }),
+            ),
+            hir::ExprKind::Unary(op, ref inner) => (
+                "Unary",
+                json!({
+                    "op": format!("{:?}", op),
+                    "inner": self.expr(inner),
+                }),
+            ),
+            hir::ExprKind::Lit(ref lit) => ("Lit", json!({ "lit": format!("{:?}", lit.node) })),
+            hir::ExprKind::Cast(ref inner, ref ty) => (
+                "Cast",
+                json!({
+                    "expr": self.expr(inner),
+                    "target": self.ty(ty),
+                }),
+            ),
+            hir::ExprKind::Type(ref inner, ref ty) => (
+                "Type",
+                json!({
+                    "expr": self.expr(inner),
+                    "target": self.ty(ty),
+                }),
+            ),
+            hir::ExprKind::Loop(ref body, label, source) => (
+                "Loop",
+                json!({
+                    "label": label.map(|label| label.ident.name.to_string()),
+                    "source": format!("{:?}", source),
+                    "body": self.block(body),
+                }),
+            ),
+            hir::ExprKind::Match(ref scrutinee, ref arms, source) => (
+                "Match",
+                json!({
+                    "source": format!("{:?}", source),
+                    "scrutinee": self.expr(scrutinee),
+                    "arms": arms.iter().map(|arm| self.arm(arm)).collect::<Vec<_>>(),
+                }),
+            ),
+            hir::ExprKind::Closure(capture, _, body_id, _, _) => (
+                "Closure",
+                json!({
+                    "capture": format!("{:?}", capture),
+                    "body": self.body(body_id),
+                }),
+            ),
+            hir::ExprKind::Yield(ref inner, source) => (
+                "Yield",
+                json!({
+                    "source": format!("{:?}", source),
+                    "inner": self.expr(inner),
+                }),
+            ),
+            hir::ExprKind::Block(ref block, label) => (
+                "Block",
+                json!({
+                    "label": label.map(|label| label.ident.name.to_string()),
+                    "block": self.block(block),
+                }),
+            ),
+            hir::ExprKind::Assign(ref lhs, ref rhs) => (
+                "Assign",
+                json!({
+                    "lhs": self.expr(lhs),
+                    "rhs": self.expr(rhs),
+                }),
+            ),
+            hir::ExprKind::AssignOp(op, ref lhs, ref rhs) => (
+                "AssignOp",
+                json!({
+                    "op": format!("{:?}", op.node),
+                    "lhs": self.expr(lhs),
+                    "rhs": self.expr(rhs),
+                }),
+            ),
+            hir::ExprKind::Field(ref inner, ident) => (
+                "Field",
+                json!({
+                    "field": ident.name.to_string(),
+                    "expr": self.expr(inner),
+                }),
+            ),
+            hir::ExprKind::Index(ref array, ref index) => (
+                "Index",
+                json!({
+                    "expr": self.expr(array),
+                    "index": self.expr(index),
+                }),
+            ),
+            hir::ExprKind::Path(ref qpath) => ("Path", json!({ "path": self.qpath(qpath, expr.hir_id) })),
+            hir::ExprKind::AddrOf(mutability, ref inner) => (
+                "AddrOf",
+                json!({
+                    "mutability": format!("{:?}", mutability),
+                    "expr": self.expr(inner),
+                }),
+            ),
+            hir::ExprKind::Break(destination, ref value) => (
+                "Break",
+                json!({
+                    "label": destination.label.map(|label| label.ident.name.to_string()),
+                    "value": self.opt_expr(value),
+                }),
+            ),
+            hir::ExprKind::Continue(destination) => (
+                "Continue",
+                json!({ "label": destination.label.map(|label| label.ident.name.to_string()) }),
+            ),
+            hir::ExprKind::Ret(ref value) => ("Ret", json!({ "value": self.opt_expr(value) })),
+            hir::ExprKind::InlineAsm(_, ref inputs, ref outputs) => (
+                "InlineAsm",
+                json!({
+                    "inputs": self.exprs(inputs),
+                    "outputs": self.exprs(outputs),
+                }),
+            ),
+            hir::ExprKind::Struct(ref qpath, ref fields, ref base) => (
+                "Struct",
+                json!({
+                    "path": self.qpath(qpath, expr.hir_id),
+                    "fields": fields
+                        .iter()
+                        .map(|field| json!({
+                            "name": field.ident.name.to_string(),
+                            "shorthand": field.is_shorthand,
+                            "expr": self.expr(&field.expr),
+                        }))
+                        .collect::<Vec<_>>(),
+                    "base": self.opt_expr(base),
+                }),
+            ),
+            hir::ExprKind::Repeat(ref value, ref count) => (
+                "Repeat",
+                json!({
+                    "value": self.expr(value),
+                    "count": self.body(count.body),
+                }),
+            ),
+            hir::ExprKind::Err => ("Err", json!({})),
+            hir::ExprKind::DropTemps(ref inner) => ("DropTemps", json!({ "expr": self.expr(inner) })),
+        };
+
+        let mut node = self.node("Expr", kind, expr.span, fields);
+        node.insert(
+            "ty".to_string(),
+            json!(self.tables.expr_ty_opt(expr).map(|ty| ty.to_string())),
+        );
+        let adjustments = self
+            .tables
+            .adjustments()
+            .get(expr.hir_id)
+            .map_or_else(Vec::new, |adjustments| {
+                adjustments
+                    .iter()
+                    .map(|adjustment| {
+                        json!({
+                            "kind": format!("{:?}", adjustment.kind),
+                            "target": adjustment.target.to_string(),
+                        })
+                    })
+                    .collect()
+            });
+        node.insert("adjustments".to_string(), json!(adjustments));
+        Value::Object(node)
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn pat(&self, pat: &hir::Pat) -> Value {
+        let (kind, fields) = match pat.kind {
+            hir::PatKind::Wild => ("Wild", json!({})),
+            hir::PatKind::Binding(mode, _, ident, ref sub) => (
+                "Binding",
+                json!({
+                    "mode": format!("{:?}", mode),
+                    "name": ident.name.to_string(),
+                    "sub": sub.as_ref().map_or(Value::Null, |sub| self.pat(sub)),
+                }),
+            ),
+            hir::PatKind::Struct(ref qpath, ref fields, rest) => (
+                "Struct",
+                json!({
+                    "path": self.qpath(qpath, pat.hir_id),
+                    "fields": fields
+                        .iter()
+                        .map(|field| json!({
+                            "name": field.ident.name.to_string(),
+                            "shorthand": field.is_shorthand,
+                            "pat": self.pat(&field.pat),
+                        }))
+                        .collect::<Vec<_>>(),
+                    "rest": rest,
+                }),
+            ),
+            hir::PatKind::TupleStruct(ref qpath, ref pats, dots) => (
+                "TupleStruct",
+                json!({
+                    "path": self.qpath(qpath, pat.hir_id),
+                    "pats": self.pats(pats),
+                    "dots": dots,
+                }),
+            ),
+            hir::PatKind::Or(ref pats) => ("Or", json!({ "pats": self.pats(pats) })),
+            hir::PatKind::Path(ref qpath) => ("Path", json!({ "path": self.qpath(qpath, pat.hir_id) })),
+            hir::PatKind::Tuple(ref pats, dots) => (
+                "Tuple",
+                json!({
+                    "pats": self.pats(pats),
+                    "dots": dots,
+                }),
+            ),
+            hir::PatKind::Box(ref inner) => ("Box", json!({ "pat": self.pat(inner) })),
+            hir::PatKind::Ref(ref inner, mutability) => (
+                "Ref",
+                json!({
+                    "mutability": format!("{:?}", mutability),
+                    "pat": self.pat(inner),
+                }),
+            ),
+            hir::PatKind::Lit(ref expr) => ("Lit", json!({ "expr": self.expr(expr) })),
+            hir::PatKind::Range(ref start, ref end, end_kind) => (
+                "Range",
+                json!({
+                    "start": self.expr(start),
+                    "end": self.expr(end),
+                    "end_kind": format!("{:?}", end_kind),
+                }),
+            ),
+            hir::PatKind::Slice(ref before, ref middle, ref after) => (
+                "Slice",
+                json!({
+                    "before": self.pats(before),
+                    "middle": middle.as_ref().map_or(Value::Null, |middle| self.pat(middle)),
+                    "after": self.pats(after),
+                }),
+            ),
+        };
+
+        let mut node = self.node("Pat", kind, pat.span, fields);
+        node.insert(
+            "ty".to_string(),
+            json!(self.tables.pat_ty_opt(pat).map(|ty| ty.to_string())),
+        );
+        let adjustments = self
+            .tables
+            .pat_adjustments()
+            .get(pat.hir_id)
+            .map_or_else(Vec::new, |adjustments| {
+                adjustments.iter().map(ToString::to_string).collect()
+            });
+        node.insert("adjustments".to_string(), json!(adjustments));
+        Value::Object(node)
+    }
+
+    fn ty(&self, ty: &hir::Ty) -> Value {
+        let (kind, res) = match ty.kind {
+            hir::TyKind::Slice(_) => ("Slice", None),
+            hir::TyKind::Array(..) => ("Array", None),
+            hir::TyKind::Ptr(_) => ("Ptr", None),
+            hir::TyKind::Rptr(..) => ("Rptr", None),
+            hir::TyKind::BareFn(_) => ("BareFn", None),
+            hir::TyKind::Never => ("Never", None),
+            hir::TyKind::Tup(_) => ("Tup", None),
+            // the type relative paths are only resolved in the bodies
+            hir::TyKind::Path(hir::QPath::Resolved(_, ref path)) => ("Path", Some(path.res)),
+            hir::TyKind::Path(hir::QPath::TypeRelative(..)) => ("Path", None),
+            hir::TyKind::Def(..) => ("Def", None),
+            hir::TyKind::TraitObject(..) => ("TraitObject", None),
+            hir::TyKind::Typeof(_) => ("Typeof", None),
+            hir::TyKind::Infer => ("Infer", None),
+            hir::TyKind::Err => ("Err", None),
+        };
+        let fields = json!({
+            "text": print::to_string(print::NO_ANN, |s| s.print_type(ty)),
+            "res": res.map_or(Value::Null, |res| self.res(res)),
+        });
+        Value::Object(self.node("Ty", kind, ty.span, fields))
+    }
+
+    fn opt_ty(&self, ty: &Option<P<hir::Ty>>) -> Value {
+        ty.as_ref().map_or(Value::Null, |ty| self.ty(ty))
+    }
+
+    fn block(&self, block: &hir::Block) -> Value {
+        let fields = json!({
+            "rules": format!("{:?}", block.rules),
+            "stmts": block.stmts.iter().map(|stmt| self.stmt(stmt)).collect::<Vec<_>>(),
+            "expr": self.opt_expr(&block.expr),
+        });
+        Value::Object(self.node("Block", "Block", block.span, fields))
+    }
+
+    fn stmt(&self, stmt: &hir::Stmt) -> Value {
+        let (kind, fields) = match stmt.kind {
+            hir::StmtKind::Local(ref local) => (
+                "Local",
+                json!({
+                    "pat": self.pat(&local.pat),
+                    "annotation": self.opt_ty(&local.ty),
+                    "ty": self.tables.node_type_opt(local.hir_id).map(|ty| ty.to_string()),
+                    "init": self.opt_expr(&local.init),
+                }),
+            ),
+            hir::StmtKind::Item(item_id) => (
+                "Item",
+                json!({ "item": self.item(self.cx.tcx.hir().expect_item(item_id.id)) }),
+            ),
+            hir::StmtKind::Expr(ref expr) => ("Expr", json!({ "expr": self.expr(expr) })),
+            hir::StmtKind::Semi(ref expr) => ("Semi", json!({ "expr": self.expr(expr) })),
+        };
+        Value::Object(self.node("Stmt", kind, stmt.span, fields))
+    }
+
+    fn arm(&self, arm: &hir::Arm) -> Value {
+        let fields = json!({
+            "pat": self.pat(&arm.pat),
+            "guard": arm.guard.as_ref().map_or(Value::Null, |guard| match guard {
+                hir::Guard::If(expr) => self.expr(expr),
+            }),
+            "body": self.expr(&arm.body),
+        });
+        Value::Object(self.node("Arm", "Arm", arm.span, fields))
+    }
+
+    /// The common fields of the items.
+    fn item_node(
+        &self,
+        node: &str,
+        kind: &str,
+        ident: Ident,
+        hir_id: hir::HirId,
+        span: Span,
+        fields: Value,
+    ) -> Map<String, Value> {
+        let mut node = self.node(node, kind, span, fields);
+        node.insert("name".to_string(), json!(ident.name.to_string()));
+        node.insert("def_path".to_string(), self.local_def_path(hir_id));
+        node
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn item(&self, item: &hir::Item) -> Value {
+        let map = self.cx.tcx.hir();
+        let (kind, fields) = match item.kind {
+            hir::ItemKind::ExternCrate(original_name) => (
+                "ExternCrate",
+                json!({ "original_name": original_name.map(|name| name.to_string()) }),
+            ),
+            hir::ItemKind::Use(ref path, use_kind) => (
+                "Use",
+                json!({
+                    "path": print::to_string(print::NO_ANN, |s| s.print_path(path, false)),
+                    "res": self.res(path.res),
+                    "use_kind": format!("{:?}", use_kind),
+                }),
+            ),
+            hir::ItemKind::Static(ref ty, mutability, body_id) => (
+                "Static",
+                json!({
+                    "mutability": format!("{:?}", mutability),
+                    "annotation": self.ty(ty),
+                    "ty": self.type_of(item.hir_id),
+                    "body": self.body(body_id),
+                }),
+            ),
+            hir::ItemKind::Const(ref ty, body_id) => (
+                "Const",
+                json!({
+                    "annotation": self.ty(ty),
+                    "ty": self.type_of(item.hir_id),
+                    "body": self.body(body_id),
+                }),
+            ),
+            hir::ItemKind::Fn(ref decl, ref header, _, body_id) => (
+                "Fn",
+                json!({
+                    "unsafety": format!("{:?}", header.unsafety),
+                    "constness": format!("{:?}", header.constness),
+                    "asyncness": format!("{:?}", header.asyncness),
+                    "inputs": self.tys(&decl.inputs),
+                    "output": match decl.output {
+                        hir::FunctionRetTy::Return(ref ty) => self.ty(ty),
+                        hir::FunctionRetTy::DefaultReturn(_) => Value::Null,
+                    },
+                    "sig": self.fn_sig(item.hir_id),
+                    "body": self.body(body_id),
+                }),
+            ),
+            hir::ItemKind::Mod(ref module) => (
+                "Mod",
+                json!({
+                    "items": module
+                        .item_ids
+                        .iter()
+                        .map(|item_id| self.item(map.expect_item(item_id.id)))
+                        .collect::<Vec<_>>(),
+                }),
+            ),
+            hir::ItemKind::ForeignMod(ref foreign_mod) => (
+                "ForeignMod",
+                json!({
+                    "abi": foreign_mod.abi.to_string(),
+                    "items": foreign_mod
+                        .items
+                        .iter()
+                        .map(|item| self.foreign_item(item))
+                        .collect::<Vec<_>>(),
+                }),
+            ),
+            hir::ItemKind::GlobalAsm(_) => ("GlobalAsm", json!({})),
+            hir::ItemKind::TyAlias(ref ty, _) => (
+                "TyAlias",
+                json!({
+                    "aliased": self.ty(ty),
+                    "ty": self.type_of(item.hir_id),
+                }),
+            ),
+            hir::ItemKind::OpaqueTy(_) => ("OpaqueTy", json!({})),
+            hir::ItemKind::Enum(ref def, _) => (
+                "Enum",
+                json!({
+                    "ty": self.type_of(item.hir_id),
+                    "variants": def.variants.iter().map(|variant| self.variant(variant)).collect::<Vec<_>>(),
+                }),
+            ),
+            hir::ItemKind::Struct(ref data, _) => (
+                "Struct",
+                json!({
+                    "ty": self.type_of(item.hir_id),
+                    "fields": self.struct_fields(data),
+                }),
+            ),
+            hir::ItemKind::Union(ref data, _) => (
+                "Union",
+                json!({
+                    "ty": self.type_of(item.hir_id),
+                    "fields": self.struct_fields(data),
+                }),
+            ),
+            hir::ItemKind::Trait(_, unsafety, _, _, ref item_refs) => (
+                "Trait",
+                json!({
+                    "auto": self.cx.tcx.trait_is_auto(map.local_def_id(item.hir_id)),
+                    "unsafety": format!("{:?}", unsafety),
+                    "items": item_refs
+                        .iter()
+                        .map(|item_ref| self.trait_item(map.trait_item(item_ref.id)))
+                        .collect::<Vec<_>>(),
+                }),
+            ),
+            hir::ItemKind::TraitAlias(..) => ("TraitAlias", json!({})),
+            hir::ItemKind::Impl(unsafety, polarity, defaultness, _, ref trait_ref, ref self_ty, ref item_refs) => (
+                "Impl",
+                json!({
+                    "unsafety": format!("{:?}", unsafety),
+                    "polarity": format!("{:?}", polarity),
+                    "defaultness": format!("{:?}", defaultness),
+                    "trait": trait_ref.as_ref().map_or(Value::Null, |trait_ref| self.res(trait_ref.path.res)),
+                    "self_ty": self.ty(self_ty),
+                    "items": item_refs
+                        .iter()
+                        .map(|item_ref| self.impl_item(map.impl_item(item_ref.id)))
+                        .collect::<Vec<_>>(),
+                }),
+            ),
+        };
+        let mut node = self.item_node("Item", kind, item.ident, item.hir_id, item.span, fields);
+        node.insert("vis".to_string(), vis(&item.vis));
+        Value::Object(node)
+    }
+
+    fn impl_item(&self, item: &hir::ImplItem) -> Value {
+        let (kind, fields) = match item.kind {
+            hir::ImplItemKind::Const(ref ty, body_id) => (
+                "Const",
+                json!({
+                    "annotation": self.ty(ty),
+                    "ty": self.type_of(item.hir_id),
+                    "body": self.body(body_id),
+                }),
+            ),
+            hir::ImplItemKind::Method(ref sig, body_id) => (
+                "Method",
+                json!({
+                    "inputs": self.tys(&sig.decl.inputs),
+                    "sig": self.fn_sig(item.hir_id),
+                    "body": self.body(body_id),
+                }),
+            ),
+            hir::ImplItemKind::TyAlias(ref ty) => (
+                "TyAlias",
+                json!({
+                    "aliased": self.ty(ty),
+                    "ty": self.type_of(item.hir_id),
+                }),
+            ),
+            hir::ImplItemKind::OpaqueTy(_) => ("OpaqueTy", json!({})),
+        };
+        let mut node = self.item_node("ImplItem", kind, item.ident, item.hir_id, item.span, fields);
+        node.insert("vis".to_string(), vis(&item.vis));
+        node.insert("default".to_string(), json!(item.defaultness.is_default()));
+        Value::Object(node)
+    }
+
+    fn trait_item(&self, item: &hir::TraitItem) -> Value {
+        let (kind, fields) = match item.kind {
+            hir::TraitItemKind::Const(ref ty, default) => (
+                "Const",
+                json!({
+                    "annotation": self.ty(ty),
+                    "ty": self.type_of(item.hir_id),
+                    "default": self.opt_body(default),
+                }),
+            ),
+            hir::TraitItemKind::Method(ref sig, ref method) => (
+                "Method",
+                json!({
+                    "inputs": self.tys(&sig.decl.inputs),
+                    "sig": self.fn_sig(item.hir_id),
+                    "default": match *method {
+                        hir::TraitMethod::Required(_) => Value::Null,
+                        hir::TraitMethod::Provided(body_id) => self.body(body_id),
+                    },
+                }),
+            ),
+            hir::TraitItemKind::Type(_, ref default) => ("Type", json!({ "default": self.opt_ty(default) })),
+        };
+        Value::Object(self.item_node("TraitItem", kind, item.ident, item.hir_id, item.span, fields))
+    }
+
+    fn foreign_item(&self, item: &hir::ForeignItem) -> Value {
+        let (kind, fields) = match item.kind {
+            hir::ForeignItemKind::Fn(ref decl, _, _) => (
+                "Fn",
+                json!({
+                    "inputs": self.tys(&decl.inputs),
+                    "sig": self.fn_sig(item.hir_id),
+                }),
+            ),
+            hir::ForeignItemKind::Static(ref ty, mutability) => (
+                "Static",
+                json!({
+                    "mutability": format!("{:?}", mutability),
+                    "annotation": self.ty(ty),
+                    "ty": self.type_of(item.hir_id),
+                }),
+            ),
+            hir::ForeignItemKind::Type => ("Type", json!({})),
+        };
+        let mut node = self.item_node("ForeignItem", kind, item.ident, item.hir_id, item.span, fields);
+        node.insert("vis".to_string(), vis(&item.vis));
+        Value::Object(node)
+    }
+
+    fn variant(&self, variant: &hir::Variant) -> Value {
+        let kind = match variant.data {
+            hir::VariantData::Struct(..) => "Struct",
+            hir::VariantData::Tuple(..) => "Tuple",
+            hir::VariantData::Unit(..) => "Unit",
+        };
+        let fields = json!({
+            "fields": self.struct_fields(&variant.data),
+            "discriminant": variant.disr_expr.as_ref().map_or(Value::Null, |disr| self.body(disr.body)),
+        });
+        Value::Object(self.item_node("Variant", kind, variant.ident, variant.id, variant.span, fields))
+    }
+
+    fn struct_fields(&self, data: &hir::VariantData) -> Vec<Value> {
+        data.fields().iter().map(|field| self.struct_field(field)).collect()
+    }
+
+    fn struct_field(&self, field: &hir::StructField) -> Value {
+        let fields = json!({
+            "annotation": self.ty(&field.ty),
+            "ty": self.type_of(field.hir_id),
+        });
+        let mut node = self.item_node(
+            "StructField",
+            "StructField",
+            field.ident,
+            field.hir_id,
+            field.span,
+            fields,
+        );
+        node.insert("vis".to_string(), vis(&field.vis));
+        Value::Object(node)
+    }
+}
+
+fn vis(vis: &hir::Visibility) -> Value {
+    json!(match vis.node {
+        hir::VisibilityKind::Public => "pub".to_string(),
+        hir::VisibilityKind::Crate(_) => "crate".to_string(),
+        hir::VisibilityKind::Restricted { ref path, .. } => format!(
+            "pub(in {})",
+            print::to_string(print::NO_ANN, |s| s.print_path(path, false))
+        ),
+        hir::VisibilityKind::Inherited => "inherited".to_string(),
+    })
+}
@@ -1,13 +1,16 @@
+use crate::consts::{constant_simple, Constant};
 use crate::utils::{
-    is_expn_of, match_def_path, match_type, method_calls, paths, span_help_and_lint, span_lint, span_lint_and_sugg,
-    walk_ptrs_ty,
+    is_expn_of, match_def_path, match_type, method_calls, path_to_res, paths, span_help_and_lint, span_lint,
+    span_lint_and_sugg, walk_ptrs_ty,
 };
 use if_chain::if_chain;
 use rustc::hir;
 use rustc::hir::def::{DefKind, Res};
+use rustc::hir::def_id::DefId;
 use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
 use rustc::hir::*;
 use rustc::lint::{EarlyContext, EarlyLintPass, LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
 use rustc::{declare_lint_pass, declare_tool_lint, impl_lint_pass};
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_errors::Applicability;
@@ -23,6 +26,7 @@ declare_clippy_lint! {
     /// **Known problems:** None.
     ///
     /// **Example:** Wrong ordering of the util::paths constants.
+    #[clippy::version = "0.0.212"]
     pub CLIPPY_LINTS_INTERNAL,
     internal,
     "various things that will negatively affect your clippy experience"
@@ -47,6 +51,7 @@ declare_clippy_lint! {
     /// declare_lint_pass!(Pass => [LINT_1, LINT_2]);
     /// // missing FORGOTTEN_LINT
     /// ```
+    #[clippy::version = "0.0.212"]
     pub LINT_WITHOUT_LINT_PASS,
     internal,
     "declaring a lint without associating it in a LintPass"
@@ -71,6 +76,7 @@ declare_clippy_lint! {
     /// ```rust,ignore
     /// utils::span_lint(cx, LINT_NAME, "message");
     /// ```
+    #[clippy::version = "0.0.212"]
     pub COMPILER_LINT_FUNCTIONS,
     internal,
     "usage of the lint functions of the compiler instead of the utils::* variant"
@@ -94,11 +100,50 @@ declare_clippy_lint! {
     /// ```rust,ignore
     /// expr.span.ctxt().outer_expn_data()
     /// ```
+    #[clippy::version = "0.0.212"]
     pub OUTER_EXPN_EXPN_DATA,
     internal,
     "using `cx.outer_expn().expn_data()` instead of `cx.outer_expn_data()`"
 }
 
+declare_clippy_lint! {
+    /// **What it does:** Checks that the constants of `utils::paths` resolve to an item.
+    ///
+    /// **Why is this bad?** When an item is moved, e.g. in `std`, the path to its old location
+    /// doesn't match anything anymore, and the lints using it silently stop working.
+    ///
+    /// **Known problems:** Only the paths of the crates Clippy depends on can be checked, so
+    /// e.g. the `regex` paths and the paths matched as a suffix, like `["ptr", "null"]`, are
+    /// skipped.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// pub const BTREEMAP: [&str; 4] = ["alloc", "collections", "btree_map", "BTreeMap"];
+    /// ```
+    #[clippy::version = "0.0.212"]
+    pub INVALID_PATHS,
+    internal,
+    "constant of `utils::paths` that doesn't resolve to an item"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for constants of `utils::paths` that no lint uses.
+    ///
+    /// **Why is this bad?** Unused paths are kept up to date for nothing.
+    ///
+    /// **Known problems:** None.
+    ///
+    /// **Example:**
+    /// ```rust,ignore
+    /// // not used anywhere
+    /// pub const LINKED_LIST: [&str; 4] = ["alloc", "collections", "linked_list", "LinkedList"];
+    /// ```
+    #[clippy::version = "0.0.212"]
+    pub UNUSED_PATHS,
+    internal,
+    "constant of `utils::paths` that isn't used"
+}
+
 declare_lint_pass!(ClippyLintsInternal => [CLIPPY_LINTS_INTERNAL]);
 
 impl EarlyLintPass for ClippyLintsInternal {
@@ -302,3 +347,117 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for OuterExpnDataPass {
         }
     }
 }
+
+#[derive(Clone, Debug, Default)]
+pub struct PathConstants {
+    /// The constants of the `paths` module, in their declaration order.
+    declared: Vec<(DefId, Span)>,
+    used: FxHashSet<DefId>,
+}
+
+impl_lint_pass!(PathConstants => [INVALID_PATHS, UNUSED_PATHS]);
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for PathConstants {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if_chain! {
+            if let hir::ItemKind::Const(_, body_id) = item.kind;
+            let def_id = cx.tcx.hir().local_def_id(item.hir_id);
+            if is_path_constant(cx, def_id);
+            then {
+                self.declared.push((def_id, item.span));
+
+                let value = &cx.tcx.hir().body(body_id).value;
+                if let Some(Constant::Vec(segments)) = constant_simple(cx, cx.tcx.body_tables(body_id), value) {
+                    let path: Vec<&str> = segments
+                        .iter()
+                        .filter_map(|segment| match segment {
+                            Constant::Str(segment) => Some(segment.as_str()),
+                            _ => None,
+                        })
+                        .collect();
+                    if !check_path(cx, &path) {
+                        span_lint(cx, INVALID_PATHS, item.span, "this path doesn't resolve to an item");
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if let ExprKind::Path(ref qpath) = expr.kind {
+            if let Res::Def(DefKind::Const, def_id) = cx.tables.qpath_res(qpath, expr.hir_id) {
+                self.used.insert(def_id);
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for &(def_id, span) in &self.declared {
+            if !self.used.contains(&def_id) {
+                span_lint(cx, UNUSED_PATHS, span, "this path isn't used by any lint");
+            }
+        }
+    }
+}
+
+/// Whether `def_id` is a `[&str; N]` constant of a `paths` module.
+fn is_path_constant(cx: &LateContext<'_, '_>, def_id: DefId) -> bool {
+    if_chain! {
+        if let Some(module) = cx.tcx.parent(def_id);
+        if cx.get_def_path(module).last().map_or(false, |name| name.as_str() == "paths");
+        if let ty::Array(element, _) = cx.tcx.type_of(def_id).kind;
+        if let ty::Ref(_, inner, _) = element.kind;
+        then {
+            inner.is_str()
+        } else {
+            false
+        }
+    }
+}
+
+/// Whether `path` resolves to an item, or can't be checked because its crate isn't a dependency.
+fn check_path(cx: &LateContext<'_, '_>, path: &[&str]) -> bool {
+    // the foreign modules have no name, e.g. in `["core", "intrinsics", "", "transmute"]`, and
+    // their items are children of the enclosing module
+    let path: Vec<&str> = path.iter().copied().filter(|segment| !segment.is_empty()).collect();
+    let (name, parent) = match path.split_last() {
+        Some((name, parent)) if !parent.is_empty() => (name, parent),
+        _ => return false,
+    };
+    if !cx
+        .tcx
+        .crates()
+        .iter()
+        .any(|&krate| cx.tcx.crate_name(krate).as_str() == path[0])
+    {
+        return true;
+    }
+    if path_to_res(cx, &path).is_some() {
+        return true;
+    }
+
+    // the associated items of the inherent impls aren't children of their type
+    match path_to_res(cx, parent) {
+        Some(Res::Def(DefKind::Struct, def_id))
+        | Some(Res::Def(DefKind::Enum, def_id))
+        | Some(Res::Def(DefKind::Union, def_id)) => cx
+            .tcx
+            .inherent_impls(def_id)
+            .iter()
+            .any(|&impl_id| has_associated_item(cx, impl_id, name)),
+        // the inherent impls of the primitive types are lang items, e.g. `["alloc", "slice", "<impl [T]>"]`
+        _ => cx
+            .tcx
+            .lang_items()
+            .items()
+            .iter()
+            .flatten()
+            .any(|&item| match_def_path(cx, item, parent) && has_associated_item(cx, item, name)),
+    }
+}
+
+fn has_associated_item(cx: &LateContext<'_, '_>, impl_id: DefId, name: &str) -> bool {
+    cx.tcx
+        .associated_items(impl_id)
+        .any(|item| item.ident.name.as_str() == name)
+}
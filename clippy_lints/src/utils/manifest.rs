@@ -0,0 +1,520 @@
+//! Locations of the tables and keys of a `Cargo.toml`, so that the lints of the `cargo` group
+//! point into it instead of reporting without a span.
+
+use cargo_metadata::{Dependency, DependencyKind, Metadata, NodeDep, Package, PackageId, Target};
+use itertools::Itertools;
+use rustc::session::Session;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::sync::Lrc;
+use std::collections::VecDeque;
+use std::env;
+use std::ops::Range;
+use std::path::Path;
+use syntax::source_map::{BytePos, SourceFile, Span, DUMMY_SP};
+
+/// A `Cargo.toml` loaded into the source map.
+pub struct Manifest {
+    file: Lrc<SourceFile>,
+    entries: Vec<Entry>,
+}
+
+/// A table header, or a key with the table it is in.
+#[derive(Debug, PartialEq)]
+struct Entry {
+    /// The dotted path of the table or key, e.g. `["dependencies", "serde"]` for `serde = "1"` in
+    /// `[dependencies]`, or for the header `[dependencies.serde]`.
+    path: Vec<String>,
+    /// The header, or the key with its value if it fits on the line.
+    range: Range<usize>,
+}
+
+impl Manifest {
+    /// Loads the `Cargo.toml` at `path` into the source map, which also makes cargo check the
+    /// crate again when it changes.
+    pub fn load(sess: &Session, path: &Path) -> Option<Self> {
+        let file = sess.source_map().load_file(path).ok()?;
+        let entries = file.src.as_ref().map_or_else(Vec::new, |src| parse(src));
+        Some(Self { file, entries })
+    }
+
+    /// The span of the first table header or key at `path`, or in it, e.g. `["package"]` finds
+    /// `[package]`.
+    pub fn span(&self, path: &[&str]) -> Option<Span> {
+        self.entries
+            .iter()
+            .find(|entry| entry.path.len() >= path.len() && entry.path.iter().zip(path).all(|(a, b)| a == b))
+            .map(|entry| self.to_span(&entry.range))
+    }
+
+    /// The span of `dependency` in the tables of its kind, including the tables of a target like
+    /// `[target.'cfg(unix)'.dependencies]`.
+    pub fn dependency_span(&self, dependency: &Dependency) -> Option<Span> {
+        let table = match dependency.kind {
+            DependencyKind::Development => "dev-dependencies",
+            DependencyKind::Build => "build-dependencies",
+            _ => "dependencies",
+        };
+        let name = dependency.rename.as_ref().unwrap_or(&dependency.name);
+        self.entries
+            .iter()
+            .find(|entry| {
+                let path = &entry.path;
+                let in_table =
+                    |index: usize| path.len() == index + 2 && path[index] == table && path[index + 1] == *name;
+                in_table(0) || (path.len() == 4 && path[0] == "target" && in_table(2))
+            })
+            .map(|entry| self.to_span(&entry.range))
+    }
+
+    /// The span of the first line, for the lints about the whole file.
+    pub fn start_span(&self) -> Span {
+        let end = self
+            .file
+            .src
+            .as_ref()
+            .map_or(0, |src| src.lines().next().map_or(0, str::len));
+        self.to_span(&(0..end))
+    }
+
+    fn to_span(&self, range: &Range<usize>) -> Span {
+        DUMMY_SP
+            .with_lo(self.file.start_pos + BytePos::from_usize(range.start))
+            .with_hi(self.file.start_pos + BytePos::from_usize(range.end))
+    }
+}
+
+/// The package of the crate being checked, the first package of `metadata` if cargo doesn't run
+/// the compiler.
+pub fn current_package(metadata: &Metadata) -> Option<&Package> {
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR");
+    metadata
+        .packages
+        .iter()
+        .find(|package| {
+            manifest_dir
+                .as_ref()
+                .map_or(false, |dir| package.manifest_path.parent() == Some(Path::new(dir)))
+        })
+        .or_else(|| metadata.packages.first())
+}
+
+/// Returns `true` if the crate is the only library or binary target of `package`. The crates of
+/// the other targets, like examples, only use some of the dependencies and features of the
+/// package.
+pub fn is_only_target(sess: &Session, package: &Package) -> bool {
+    let mut targets = package
+        .targets
+        .iter()
+        .filter(|target| is_library(target) || target.kind.iter().any(|kind| kind == "bin"));
+    match (targets.next(), targets.next(), &sess.local_crate_source_file) {
+        (Some(target), None, Some(source_file)) => target.src_path.ends_with(source_file),
+        _ => false,
+    }
+}
+
+/// Returns `true` if the crate is the only binary target of `package` and the package also has a
+/// library, so that the binary loads the crates the library depends on.
+pub fn is_binary_of_library(sess: &Session, package: &Package) -> bool {
+    let mut binaries = package
+        .targets
+        .iter()
+        .filter(|target| target.kind.iter().any(|kind| kind == "bin"));
+    match (binaries.next(), binaries.next(), &sess.local_crate_source_file) {
+        (Some(binary), None, Some(source_file)) => {
+            package.targets.iter().any(is_library) && binary.src_path.ends_with(source_file)
+        },
+        _ => false,
+    }
+}
+
+pub fn is_library(target: &Target) -> bool {
+    target
+        .kind
+        .iter()
+        .any(|kind| kind == "lib" || kind == "rlib" || kind == "dylib" || kind == "proc-macro")
+}
+
+/// The packages the checked package depends on, directly or not.
+pub struct DependencyGraph<'a> {
+    pub packages: FxHashMap<&'a PackageId, &'a Package>,
+    /// The package each dependency is first reached from, in breadth-first order, so that
+    /// following them gives a shortest path from the root. `None` for the root.
+    pub parents: FxHashMap<&'a PackageId, Option<&'a PackageId>>,
+    /// The names the root gives its direct dependencies, which differ from their library names
+    /// when they are renamed.
+    names: FxHashMap<&'a PackageId, &'a str>,
+}
+
+impl<'a> DependencyGraph<'a> {
+    pub fn new(metadata: &'a Metadata, root: &'a PackageId) -> Self {
+        Self::build(metadata, root, true)
+    }
+
+    /// The graph of the dependencies built into the package, without the dev-dependencies of
+    /// the packages of the workspace.
+    pub fn without_dev_dependencies(metadata: &'a Metadata, root: &'a PackageId) -> Self {
+        Self::build(metadata, root, false)
+    }
+
+    fn build(metadata: &'a Metadata, root: &'a PackageId, dev_dependencies: bool) -> Self {
+        let packages: FxHashMap<_, _> = metadata.packages.iter().map(|package| (&package.id, package)).collect();
+        let dependencies: FxHashMap<_, _> = metadata
+            .resolve
+            .iter()
+            .flat_map(|resolve| &resolve.nodes)
+            .map(|node| (&node.id, &node.deps))
+            .collect();
+        let names = dependencies
+            .get(root)
+            .into_iter()
+            .flat_map(|deps| deps.iter())
+            .map(|dep: &NodeDep| (&dep.pkg, dep.name.as_str()))
+            .collect();
+
+        let mut parents = FxHashMap::default();
+        parents.insert(root, None);
+        let mut queue = VecDeque::new();
+        queue.push_back(root);
+        while let Some(id) = queue.pop_front() {
+            for dependency in dependencies
+                .get(id)
+                .into_iter()
+                .flat_map(|deps| deps.iter())
+                .map(|dep| &dep.pkg)
+            {
+                // only the packages of the workspace have their dev-dependencies resolved
+                let built = dev_dependencies || is_built_into(packages[id], packages[dependency]);
+                if built && !parents.contains_key(dependency) {
+                    parents.insert(dependency, Some(id));
+                    queue.push_back(dependency);
+                }
+            }
+        }
+
+        Self {
+            packages,
+            parents,
+            names,
+        }
+    }
+
+    /// Returns `true` if the root depends on `id` directly.
+    pub fn is_direct(&self, id: &PackageId) -> bool {
+        match self.parents.get(id) {
+            Some(&Some(parent)) => self.parents.get(parent) == Some(&None),
+            _ => false,
+        }
+    }
+
+    /// The dependency of the manifest of the root that brings in `id`, directly or not. The
+    /// renamed dependencies are told apart by their names, and the dependencies on several
+    /// versions of a package by their requirements.
+    pub fn direct_dependency(&self, id: &'a PackageId) -> Option<&'a Dependency> {
+        let path = self.path(id);
+        let (root, direct) = (path.first()?, path.get(1)?);
+        let name = self.names.get(&direct.id)?;
+        let mut candidates = root.dependencies.iter().filter(|dep| match &dep.rename {
+            Some(rename) => rename.replace('-', "_") == *name,
+            None => dep.name == direct.name,
+        });
+        let first = candidates.clone().next();
+        candidates.find(|dep| dep.req.matches(&direct.version)).or(first)
+    }
+
+    /// The packages from the root to `id`, both included.
+    pub fn path(&self, mut id: &'a PackageId) -> Vec<&'a Package> {
+        let mut path = vec![self.packages[id]];
+        while let Some(&Some(parent)) = self.parents.get(id) {
+            path.push(self.packages[parent]);
+            id = parent;
+        }
+        path.reverse();
+        path
+    }
+
+    /// The path from the root to `id`, like `my-crate -> foo 1.0.0 -> bar 0.2.3`.
+    pub fn path_to(&self, id: &'a PackageId) -> String {
+        self.path(id)
+            .iter()
+            .enumerate()
+            .map(|(index, package)| {
+                if index == 0 {
+                    package.name.clone()
+                } else {
+                    format!("{} {}", package.name, package.version)
+                }
+            })
+            .join(" -> ")
+    }
+}
+
+/// Returns `true` if `package` depends on `dependency` other than as a dev-dependency.
+fn is_built_into(package: &Package, dependency: &Package) -> bool {
+    package
+        .dependencies
+        .iter()
+        .any(|dep| dep.name == dependency.name && dep.kind != DependencyKind::Development)
+}
+
+/// Finds the table headers and keys of `src`. This is not a full TOML parser, the keys inside of
+/// inline tables and the values are skipped.
+fn parse(src: &str) -> Vec<Entry> {
+    let mut entries = Vec::new();
+    let mut table = Vec::new();
+    let mut in_multiline_string = false;
+    let mut offset = 0;
+
+    for line in src.split('\n') {
+        let start = offset + line.len() - line.trim_start().len();
+        offset += line.len() + 1;
+        let trimmed = line.trim();
+        let content = trimmed.split(" #").next().unwrap_or_default().trim_end();
+
+        if in_multiline_string {
+            in_multiline_string = trimmed.matches("\"\"\"").count() % 2 == 0;
+            continue;
+        }
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if content.starts_with('[') {
+            let header = content.trim_start_matches('[').trim_end_matches(']');
+            if let Some((path, _)) = parse_key(header) {
+                table = path.clone();
+                entries.push(Entry {
+                    path,
+                    range: start..start + content.len(),
+                });
+            }
+        } else if let Some((key, rest)) = parse_key(trimmed) {
+            if rest.starts_with('=') {
+                let mut path = table.clone();
+                path.extend(key);
+                entries.push(Entry {
+                    path,
+                    range: start..start + content.len(),
+                });
+                in_multiline_string = trimmed.matches("\"\"\"").count() % 2 == 1;
+            }
+        }
+    }
+
+    entries
+}
+
+/// Parses the dotted key at the start of `s`, returning its segments and the rest of `s`.
+fn parse_key(mut s: &str) -> Option<(Vec<String>, &str)> {
+    let mut segments = Vec::new();
+    loop {
+        s = s.trim_start();
+        let (segment, rest) = if s.starts_with('"') || s.starts_with('\'') {
+            let quote = s.chars().next()?;
+            let end = s[1..].find(quote)? + 1;
+            (s[1..end].to_string(), &s[end + 1..])
+        } else {
+            let end = s
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+                .unwrap_or_else(|| s.len());
+            if end == 0 {
+                return None;
+            }
+            (s[..end].to_string(), &s[end..])
+        };
+        segments.push(segment);
+
+        let rest = rest.trim_start();
+        if rest.starts_with('.') {
+            s = &rest[1..];
+        } else {
+            return Some((segments, rest));
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::{parse, DependencyGraph, Entry};
+    use cargo_metadata::{Metadata, PackageId};
+    use serde_json::{json, Value};
+
+    pub fn id(id: &str) -> PackageId {
+        PackageId { repr: id.to_string() }
+    }
+
+    /// A package of `cargo metadata` whose id is `"{name} {version}"`.
+    pub fn package(name: &str, version: &str, dependencies: Vec<Value>) -> Value {
+        json!({
+            "name": name,
+            "version": version,
+            "id": format!("{} {}", name, version),
+            "source": "registry+https://github.com/rust-lang/crates.io-index",
+            "dependencies": dependencies,
+            "license": "MIT",
+            "targets": [{
+                "name": name,
+                "kind": ["lib"],
+                "src_path": format!("/{}/src/lib.rs", name),
+            }],
+            "features": {},
+            "manifest_path": format!("/{}/Cargo.toml", name),
+        })
+    }
+
+    /// A normal dependency of a package of `cargo metadata`.
+    pub fn dependency(name: &str, req: &str) -> Value {
+        json!({
+            "name": name,
+            "source": "registry+https://github.com/rust-lang/crates.io-index",
+            "req": req,
+            "kind": null,
+            "optional": false,
+            "uses_default_features": true,
+            "features": [],
+            "rename": null,
+        })
+    }
+
+    /// The metadata of `packages`, the first one being the member of the workspace, with the
+    /// resolved `edges` from a package id to the id of a dependency and the name it is given.
+    pub fn metadata(packages: &[Value], edges: &[(&str, &str, &str)]) -> Metadata {
+        let nodes: Vec<Value> = packages
+            .iter()
+            .map(|package| {
+                let edges = edges.iter().filter(|(from, _, _)| package["id"] == *from);
+                json!({
+                    "id": package["id"],
+                    "dependencies": edges.clone().map(|(_, to, _)| to).collect::<Vec<_>>(),
+                    "deps": edges.map(|(_, to, name)| json!({ "name": name, "pkg": to })).collect::<Vec<_>>(),
+                    "features": [],
+                })
+            })
+            .collect();
+        serde_json::from_value(json!({
+            "packages": packages,
+            "workspace_members": [packages[0]["id"]],
+            "resolve": { "nodes": nodes, "root": packages[0]["id"] },
+            "workspace_root": "/",
+            "target_directory": "/target",
+            "version": 1,
+        }))
+        .unwrap()
+    }
+
+    fn paths(src: &str) -> Vec<String> {
+        parse(src)
+            .into_iter()
+            .map(|Entry { path, range }| format!("{} {:?}", path.join("."), &src[range]))
+            .collect()
+    }
+
+    #[test]
+    fn tables_and_keys() {
+        let src = r#"
+[package]
+name = "foo" # the name
+authors = [
+    "a = b",
+]
+description = """
+[not.a.table]
+"""
+
+[target.'cfg(unix)'.dependencies]
+libc = "*"
+
+[dependencies.serde]
+version = "1"
+"#;
+        assert_eq!(
+            paths(src),
+            vec![
+                r#"package "[package]""#,
+                r#"package.name "name = \"foo\"""#,
+                r#"package.authors "authors = [""#,
+                r#"package.description "description = \"\"\"""#,
+                r#"target.cfg(unix).dependencies "[target.'cfg(unix)'.dependencies]""#,
+                r#"target.cfg(unix).dependencies.libc "libc = \"*\"""#,
+                r#"dependencies.serde "[dependencies.serde]""#,
+                r#"dependencies.serde.version "version = \"1\"""#,
+            ]
+        );
+    }
+
+    #[test]
+    fn dependency_graph() {
+        let mut dev = dependency("e", "^1");
+        dev["kind"] = json!("dev");
+        let metadata = metadata(
+            &[
+                package("a", "0.1.0", vec![dependency("b", "^1"), dependency("c", "^1"), dev]),
+                package("b", "1.0.0", vec![dependency("d", "^1")]),
+                package("c", "1.0.0", vec![dependency("d", "^1")]),
+                package("d", "1.0.0", vec![]),
+                package("e", "1.0.0", vec![dependency("f", "^1")]),
+                package("f", "1.0.0", vec![]),
+                package("unused", "1.0.0", vec![]),
+            ],
+            &[
+                ("a 0.1.0", "b 1.0.0", "b"),
+                ("a 0.1.0", "c 1.0.0", "c"),
+                ("a 0.1.0", "e 1.0.0", "e"),
+                ("b 1.0.0", "d 1.0.0", "d"),
+                ("c 1.0.0", "d 1.0.0", "d"),
+                ("e 1.0.0", "f 1.0.0", "f"),
+            ],
+        );
+        let root = id("a 0.1.0");
+
+        let graph = DependencyGraph::new(&metadata, &root);
+        let mut reached: Vec<_> = graph.parents.keys().map(|id| id.repr.as_str()).collect();
+        reached.sort();
+        assert_eq!(
+            reached,
+            ["a 0.1.0", "b 1.0.0", "c 1.0.0", "d 1.0.0", "e 1.0.0", "f 1.0.0"]
+        );
+        // the first of the shortest paths, in the order of the dependencies
+        assert_eq!(graph.path_to(&id("d 1.0.0")), "a -> b 1.0.0 -> d 1.0.0");
+        assert_eq!(graph.path_to(&id("f 1.0.0")), "a -> e 1.0.0 -> f 1.0.0");
+        assert_eq!(graph.path_to(&root), "a");
+        assert!(graph.is_direct(&id("b 1.0.0")));
+        assert!(!graph.is_direct(&id("d 1.0.0")));
+        assert!(!graph.is_direct(&root));
+
+        let graph = DependencyGraph::without_dev_dependencies(&metadata, &root);
+        assert!(graph.parents.contains_key(&id("d 1.0.0")));
+        assert!(!graph.parents.contains_key(&id("e 1.0.0")));
+        assert!(!graph.parents.contains_key(&id("f 1.0.0")));
+    }
+
+    #[test]
+    fn direct_dependency() {
+        let mut renamed = dependency("b", "^2");
+        renamed["rename"] = json!("b-two");
+        let metadata = metadata(
+            &[
+                package("a", "0.1.0", vec![dependency("b", "^1"), renamed]),
+                package("b", "1.0.0", vec![]),
+                package("b", "2.0.0", vec![dependency("c", "^1")]),
+                package("c", "1.0.0", vec![]),
+            ],
+            &[
+                ("a 0.1.0", "b 1.0.0", "b"),
+                ("a 0.1.0", "b 2.0.0", "b_two"),
+                ("b 2.0.0", "c 1.0.0", "c"),
+            ],
+        );
+        let ids = [id("a 0.1.0"), id("b 1.0.0"), id("b 2.0.0"), id("c 1.0.0")];
+        let graph = DependencyGraph::new(&metadata, &ids[0]);
+        let direct = |index: usize| {
+            graph
+                .direct_dependency(&ids[index])
+                .map(|dep| (dep.name.as_str(), dep.rename.as_ref().map(String::as_str)))
+        };
+
+        assert_eq!(direct(0), None);
+        assert_eq!(direct(1), Some(("b", None)));
+        assert_eq!(direct(2), Some(("b", Some("b-two"))));
+        assert_eq!(direct(3), Some(("b", Some("b-two"))));
+    }
+}
@@ -3,6 +3,7 @@ pub mod sym;
 
 pub mod attrs;
 pub mod author;
+pub mod baseline;
 pub mod camel_case;
 pub mod comparisons;
 pub mod conf;
@@ -12,7 +13,12 @@ pub mod higher;
 mod hir_utils;
 pub mod inspector;
 pub mod internal_lints;
+pub mod manifest;
+#[macro_use]
+pub mod msrvs;
 pub mod paths;
+pub mod plugins;
+pub mod profile;
 pub mod ptr;
 pub mod sugg;
 pub mod usage;
@@ -31,7 +37,7 @@ use rustc::hir::def_id::{DefId, CRATE_DEF_INDEX, LOCAL_CRATE};
 use rustc::hir::intravisit::{NestedVisitorMap, Visitor};
 use rustc::hir::Node;
 use rustc::hir::*;
-use rustc::lint::{LateContext, Level, Lint, LintContext};
+use rustc::lint::{EarlyContext, LateContext, Level, Lint, LintContext};
 use rustc::traits;
 use rustc::ty::{
     self,
@@ -959,6 +965,13 @@ pub fn is_allowed(cx: &LateContext<'_, '_>, lint: &'static Lint, id: HirId) -> b
     cx.tcx.lint_level_at_node(lint, id).0 == Level::Allow
 }
 
+/// Returns `true` if the lint is allowed in the current context of an early lint pass
+///
+/// Useful for skipping long running code when it's unnecessary
+pub fn is_allowed_early(cx: &EarlyContext<'_>, lint: &'static Lint) -> bool {
+    cx.builder.lint_level(lint).0 == Level::Allow
+}
+
 pub fn get_arg_name(pat: &Pat) -> Option<ast::Name> {
     match pat.kind {
         PatKind::Binding(.., ident, None) => Some(ident.name),
@@ -0,0 +1,120 @@
+//! The minimum supported Rust version (MSRV) of the checked crate, from the `msrv` configuration
+//! or the `#![clippy::msrv = "1.31"]` attribute, and the versions that stabilized the std APIs and
+//! language features suggested by the lints.
+
+use crate::utils::get_attr;
+use rustc::session::Session;
+use std::fmt;
+use syntax::ast;
+
+/// A version of Rust, compared by its numbers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RustcVersion {
+    major: u64,
+    minor: u64,
+    patch: u64,
+}
+
+impl RustcVersion {
+    #[must_use]
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self { major, minor, patch }
+    }
+
+    /// Parses a version like `1.31` or `1.31.0`.
+    #[must_use]
+    pub fn parse(version: &str) -> Option<Self> {
+        let numbers = version
+            .split('.')
+            .map(|number| number.parse().ok())
+            .collect::<Option<Vec<u64>>>()?;
+        match *numbers.as_slice() {
+            [major, minor] => Some(Self::new(major, minor, 0)),
+            [major, minor, patch] => Some(Self::new(major, minor, patch)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for RustcVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+macro_rules! msrv_aliases {
+    ($($major:literal,$minor:literal,$patch:literal { $($name:ident),* $(,)? })*) => {
+        $($(
+            pub const $name: RustcVersion = RustcVersion::new($major, $minor, $patch);
+        )*)*
+    };
+}
+
+// names may refer to stabilized std APIs or language features
+msrv_aliases! {
+    1,36,0 { ITERATOR_COPIED }
+    1,34,0 { TRY_FROM }
+    1,31,0 { CONST_FN }
+    1,27,0 { DURATION_SUBSEC_MILLIS_MICROS }
+    1,26,0 { POINTER_ADD_SUB, RANGE_INCLUSIVE }
+    1,20,0 { FLOAT_FROM_BITS }
+}
+
+/// Returns `true` if a crate supporting `msrv` can use a feature stabilized in `feature`. Without
+/// a configured MSRV, every feature is available.
+#[must_use]
+pub fn meets_msrv(msrv: Option<RustcVersion>, feature: RustcVersion) -> bool {
+    msrv.map_or(true, |msrv| msrv >= feature)
+}
+
+/// Reads the `#![clippy::msrv = "1.31"]` attribute of a crate, reporting an error if it's
+/// repeated or isn't a version.
+pub fn get_msrv_attr(sess: &Session, attrs: &[ast::Attribute]) -> Option<RustcVersion> {
+    let mut msrv_attrs = get_attr(sess, attrs, "msrv");
+    let attr = msrv_attrs.next()?;
+    if let Some(duplicate) = msrv_attrs.next() {
+        sess.struct_span_err(duplicate.span, "`msrv` is defined multiple times")
+            .span_note(attr.span, "first definition found here")
+            .emit();
+    }
+
+    let version = attr.value_str().and_then(|value| RustcVersion::parse(&value.as_str()));
+    if version.is_none() {
+        sess.span_err(attr.span, "`msrv` is not a valid Rust version, expected e.g. `1.31`");
+    }
+    version
+}
+
+/// Implements `check_crate` for a lint pass with an `msrv` field, to override the configured MSRV
+/// with the `#![clippy::msrv]` attribute of the crate.
+macro_rules! extract_msrv_attr {
+    (LateContext) => {
+        fn check_crate(&mut self, cx: &rustc::lint::LateContext<'a, 'tcx>, krate: &'tcx rustc::hir::Crate) {
+            use rustc::lint::LintContext;
+            if let Some(msrv) = crate::utils::msrvs::get_msrv_attr(cx.sess(), &krate.attrs) {
+                self.msrv = Some(msrv);
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::{meets_msrv, RustcVersion, TRY_FROM};
+
+    #[test]
+    fn parse() {
+        assert_eq!(RustcVersion::parse("1.31"), Some(RustcVersion::new(1, 31, 0)));
+        assert_eq!(RustcVersion::parse("1.31.1"), Some(RustcVersion::new(1, 31, 1)));
+        assert_eq!(RustcVersion::parse("1"), None);
+        assert_eq!(RustcVersion::parse("1.31.0.0"), None);
+        assert_eq!(RustcVersion::parse("1.x"), None);
+    }
+
+    #[test]
+    fn meets() {
+        assert!(meets_msrv(None, TRY_FROM));
+        assert!(meets_msrv(RustcVersion::parse("1.34"), TRY_FROM));
+        assert!(!meets_msrv(RustcVersion::parse("1.33.1"), TRY_FROM));
+    }
+}
@@ -0,0 +1,230 @@
+//! Lint plugins: dynamic libraries registering additional lints, passed to the driver with
+//! `--lint-plugin PATH` or listed in the `lint-plugins` configuration key.
+//!
+//! A plugin is a `dylib` crate built with the same toolchain and `clippy_lints` as the driver. It
+//! exports its registration function, called after Clippy's own lints are registered, and the
+//! version it was built with:
+//!
+//! ```rust,ignore
+//! clippy_lints::export_plugin_version!();
+//!
+//! #[no_mangle]
+//! pub fn clippy_register_lints(store: &mut LintStore, conf: &clippy_lints::Conf) {
+//!     store.register_lints(&[&MYORG_BANNED_API]);
+//!     store.register_late_pass(|| box BannedApi);
+//!     store.register_group(true, "clippy::myorg", Some("clippy_myorg"), vec![
+//!         LintId::of(&MYORG_BANNED_API),
+//!     ]);
+//! }
+//! ```
+//!
+//! The registration function has no stable ABI, so it is only called if the plugin's version is
+//! the driver's. It is called twice, once to check the names it registers and once to register
+//! them, so it must not do anything else than registering its lints and passes.
+//!
+//! Only the `clippy` tool lints can be controlled from the code, so the lints of a plugin are
+//! declared with `declare_tool_lint!` in the `clippy` namespace. They are namespaced by a group of
+//! the plugin instead: the name of each lint starts with the name of one of the plugin's groups,
+//! like `clippy::myorg_banned_api` in `clippy::myorg`.
+
+use crate::utils::conf::Conf;
+use rustc::lint::LintStore;
+use rustc_metadata::dynamic_lib::DynamicLibrary;
+use std::ffi::CStr;
+use std::mem;
+use std::os::raw::c_char;
+use std::path::{Path, PathBuf};
+
+/// The symbol of the registration function of a plugin.
+pub const REGISTRAR: &str = "clippy_register_lints";
+
+/// The symbol of the function returning the version a plugin was built with, exported by
+/// `export_plugin_version!`.
+pub const VERSION_SYMBOL: &str = "clippy_plugin_version";
+
+/// The version of `clippy_lints` and of the compiler, nul-terminated. A plugin can only be loaded
+/// by a driver of the same version.
+pub const PLUGIN_VERSION: &str = concat!(env!("CARGO_PKG_VERSION"), " ", env!("RUSTC_VERSION"), "\0");
+
+type Registrar = fn(&mut LintStore, &Conf);
+
+/// Exports the version of `clippy_lints` and of the compiler a lint plugin is built with, which
+/// the driver checks before calling its registration function.
+#[macro_export]
+macro_rules! export_plugin_version {
+    () => {
+        #[no_mangle]
+        pub extern "C" fn clippy_plugin_version() -> *const ::std::os::raw::c_char {
+            $crate::PLUGIN_VERSION.as_ptr() as *const ::std::os::raw::c_char
+        }
+    };
+}
+
+/// The plugins passed with `--lint-plugin`, then the ones of the `lint-plugins` key, which are
+/// relative to the configuration file declaring them.
+pub fn paths(conf: &Conf, args: &[PathBuf]) -> Vec<PathBuf> {
+    let conf_dir = conf.sources.get("lint-plugins").and_then(|file| file.parent());
+    args.iter()
+        .cloned()
+        .chain(
+            conf.lint_plugins
+                .iter()
+                .map(|path| conf_dir.map_or_else(|| PathBuf::from(path), |dir| dir.join(path))),
+        )
+        .collect()
+}
+
+/// Loads the plugins at `paths` and lets them register their lints.
+///
+/// # Errors
+///
+/// Returns a message if a plugin can't be loaded, or if the names of its lints or groups aren't
+/// namespaced or are already registered.
+pub fn register(store: &mut LintStore, conf: &Conf, paths: &[PathBuf]) -> Result<(), String> {
+    for path in paths {
+        let registrar =
+            load(path).map_err(|e| format!("could not load the lint plugin `{}`: {}", path.display(), e))?;
+
+        // rustc panics when a lint or a group is registered twice, so the plugin registers them in
+        // a store of its own first to check their names. The passes can't be moved from one store
+        // to another, hence the second call.
+        let mut plugin_store = LintStore::new();
+        registrar(&mut plugin_store, conf);
+        check_names(store, &plugin_store)
+            .map_err(|e| format!("the lint plugin `{}` registers {}", path.display(), e))?;
+        registrar(store, conf);
+    }
+    Ok(())
+}
+
+/// Checks the names of the lints and groups registered by a plugin in `plugin` against the ones
+/// already registered in `store`.
+fn check_names(store: &LintStore, plugin: &LintStore) -> Result<(), String> {
+    let registered_groups: Vec<_> = store.get_lint_groups().into_iter().map(|(name, ..)| name).collect();
+    let groups: Vec<_> = plugin.get_lint_groups().into_iter().map(|(name, ..)| name).collect();
+    for group in &groups {
+        if !group.starts_with("clippy::") {
+            return Err(format!(
+                "the group `{}`, plugin groups must be declared in the `clippy` namespace",
+                group
+            ));
+        }
+        if registered_groups.contains(group) {
+            return Err(format!("the group `{}`, which is already registered", group));
+        }
+    }
+
+    for lint in plugin.get_lints() {
+        let name = lint.name_lower();
+        if !groups.iter().any(|group| name.starts_with(&format!("{}_", group))) {
+            return Err(format!(
+                "`{}`, plugin lints must be prefixed by the name of one of the plugin's groups, \
+                 like `clippy::myorg_banned_api` in `clippy::myorg`",
+                name
+            ));
+        }
+        if store
+            .get_lints()
+            .iter()
+            .any(|registered| registered.name_lower() == name)
+        {
+            return Err(format!("`{}`, which is already registered", name));
+        }
+    }
+    Ok(())
+}
+
+fn load(path: &Path) -> Result<Registrar, String> {
+    let lib = DynamicLibrary::open(Some(path))?;
+    unsafe {
+        let version = lib.symbol::<u8>(VERSION_SYMBOL).map_err(|_| {
+            format!(
+                "it doesn't export its version, add `clippy_lints::export_plugin_version!();` to it (`{}` not found)",
+                VERSION_SYMBOL
+            )
+        })?;
+        let version = mem::transmute::<*mut u8, extern "C" fn() -> *const c_char>(version)();
+        let version = CStr::from_ptr(version).to_string_lossy();
+        let expected = PLUGIN_VERSION.trim_end_matches('\0');
+        if version != expected {
+            return Err(format!(
+                "it was built with `clippy_lints {}`, but the driver is `clippy_lints {}`",
+                version, expected
+            ));
+        }
+
+        let registrar = lib.symbol::<u8>(REGISTRAR)?;
+        // The registered passes are code of the library, it stays loaded until the process exits.
+        mem::forget(lib);
+        Ok(mem::transmute::<*mut u8, Registrar>(registrar))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{check_names, paths};
+    use crate::utils::conf::Conf;
+    use rustc::declare_tool_lint;
+    use rustc::lint::{LintId, LintStore};
+    use std::path::PathBuf;
+
+    declare_tool_lint! {
+        pub clippy::MYORG_BANNED_API, Warn, "banned API"
+    }
+
+    declare_tool_lint! {
+        pub clippy::BANNED_API, Warn, "banned API without a group prefix"
+    }
+
+    fn plugin(group: &'static str, lint: &'static rustc::lint::Lint) -> LintStore {
+        let mut store = LintStore::new();
+        store.register_lints(&[lint]);
+        store.register_group(true, group, None, vec![LintId::of(lint)]);
+        store
+    }
+
+    #[test]
+    fn names() {
+        let store = LintStore::new();
+        assert!(check_names(&store, &plugin("clippy::myorg", &MYORG_BANNED_API)).is_ok());
+        assert!(check_names(&store, &plugin("myorg", &MYORG_BANNED_API)).is_err());
+        assert!(check_names(&store, &plugin("clippy::myorg", &BANNED_API)).is_err());
+    }
+
+    #[test]
+    fn duplicate_names() {
+        let registered = plugin("clippy::myorg", &MYORG_BANNED_API);
+        let error = check_names(&registered, &plugin("clippy::myorg", &MYORG_BANNED_API)).unwrap_err();
+        assert!(error.contains("the group `clippy::myorg`"));
+
+        let mut registered = LintStore::new();
+        registered.register_lints(&[&MYORG_BANNED_API]);
+        let error = check_names(&registered, &plugin("clippy::myorg", &MYORG_BANNED_API)).unwrap_err();
+        assert!(error.contains("`clippy::myorg_banned_api`"));
+    }
+
+    #[test]
+    fn plugin_paths() {
+        let mut conf: Conf = toml::from_str("lint-plugins = [\"lib/a.so\", \"/abs/b.so\"]").unwrap();
+        let args = [PathBuf::from("c.so")];
+        assert_eq!(
+            paths(&conf, &args),
+            [
+                PathBuf::from("c.so"),
+                PathBuf::from("lib/a.so"),
+                PathBuf::from("/abs/b.so")
+            ]
+        );
+
+        conf.sources
+            .insert("lint-plugins".to_string(), PathBuf::from("/project/clippy.toml"));
+        assert_eq!(
+            paths(&conf, &args),
+            [
+                PathBuf::from("c.so"),
+                PathBuf::from("/project/lib/a.so"),
+                PathBuf::from("/abs/b.so")
+            ]
+        );
+    }
+}
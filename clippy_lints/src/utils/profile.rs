@@ -0,0 +1,339 @@
+//! Timing of the lint passes, enabled by setting `CLIPPY_PROFILE=1`.
+//!
+//! The passes are registered through `ProfilingStore`, which wraps them in a pass measuring the
+//! time spent in each of their `check_*` methods. The timings of a pass are collected when it is
+//! dropped at the end of the lint checking, and printed by the driver after the compilation.
+//!
+//! A pass checks all its lints at once, so the time can't be split between them. Instead, the
+//! warnings emitted while a pass runs are counted per lint, and listed under the pass.
+
+use lazy_static::lazy_static;
+use rustc::hir;
+use rustc::lint::{
+    EarlyContext, EarlyLintPass, EarlyLintPassObject, LateContext, LateLintPass, LateLintPassObject, Lint, LintPass,
+    LintStore,
+};
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::env;
+use std::ops::{Deref, DerefMut};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use syntax::ast;
+use syntax_pos::Span;
+
+pub const PROFILE_ENV: &str = "CLIPPY_PROFILE";
+
+pub fn enabled() -> bool {
+    env::var_os(PROFILE_ENV).map_or(false, |value| !value.is_empty() && value != "0")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Kind {
+    PreExpansion,
+    Early,
+    Late,
+}
+
+impl Kind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Kind::PreExpansion => "pre-expansion",
+            Kind::Early => "early",
+            Kind::Late => "late",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+struct Timings {
+    time: Duration,
+    /// The number of calls to the `check_*` methods of the pass. A wrapped pass can't tell which
+    /// methods the pass implements, so the calls to the default, empty, ones are counted too: this
+    /// is the number of visited nodes rather than the work done by the pass.
+    visits: u64,
+    /// The number of warnings emitted by the pass, per lint.
+    warnings: BTreeMap<String, u64>,
+}
+
+impl Timings {
+    fn add(&mut self, start: Instant) {
+        self.time += start.elapsed();
+        self.visits += 1;
+    }
+}
+
+lazy_static! {
+    static ref TIMINGS: Mutex<BTreeMap<(&'static str, Kind), Timings>> = Mutex::new(BTreeMap::new());
+}
+
+thread_local! {
+    /// The timed pass being run, if any.
+    static CURRENT_PASS: Cell<Option<(&'static str, Kind)>> = Cell::new(None);
+}
+
+fn collect(name: &'static str, kind: Kind, timings: Timings) {
+    let mut all = TIMINGS.lock().expect("no threading here");
+    let total = all.entry((name, kind)).or_default();
+    total.time += timings.time;
+    total.visits += timings.visits;
+    for (lint, count) in timings.warnings {
+        *total.warnings.entry(lint).or_default() += count;
+    }
+}
+
+/// Runs `f` as the pass `name`, so that the warnings it emits are counted for this pass.
+fn run_as<R>(name: &'static str, kind: Kind, f: impl FnOnce() -> R) -> R {
+    let outer = CURRENT_PASS.with(|current| current.replace(Some((name, kind))));
+    let result = f();
+    CURRENT_PASS.with(|current| current.set(outer));
+    result
+}
+
+/// Counts a warning of `lint` for the timed pass being run. Does nothing if profiling is disabled.
+///
+/// Used by the functions of `utils::diagnostics`.
+pub fn record_warning(lint: &'static Lint) {
+    if let Some(key) = CURRENT_PASS.with(Cell::get) {
+        let mut all = TIMINGS.lock().expect("no threading here");
+        *all.entry(key).or_default().warnings.entry(lint.name_lower()).or_default() += 1;
+    }
+}
+
+/// Prints the time spent in each pass while checking `crate_name`, slowest first.
+pub fn print_report(crate_name: &str) {
+    let timings = std::mem::replace(&mut *TIMINGS.lock().expect("no threading here"), BTreeMap::new());
+    if !timings.is_empty() {
+        eprint!("{}", report(crate_name, timings));
+    }
+}
+
+/// The table of `print_report`: a line per pass, slowest first, followed by a line per lint it
+/// emitted, the most emitted first.
+#[allow(clippy::cast_precision_loss)]
+fn report(crate_name: &str, timings: BTreeMap<(&'static str, Kind), Timings>) -> String {
+    let mut timings: Vec<_> = timings.into_iter().collect();
+    timings.sort_by(|(_, a), (_, b)| b.time.cmp(&a.time));
+    let total: Duration = timings.iter().map(|(_, timings)| timings.time).sum();
+
+    let mut report = format!("Clippy lint passes of `{}`, {} ms in total:\n", crate_name, millis(total));
+    report.push_str(&format!(
+        "{:>10} {:>7} {:>12} {:>9}  {:<14} pass/lint\n",
+        "time (ms)", "%", "visits", "warnings", "kind"
+    ));
+    for ((name, kind), timings) in timings {
+        let share = if total.as_nanos() == 0 {
+            0.
+        } else {
+            timings.time.as_nanos() as f64 * 100. / total.as_nanos() as f64
+        };
+        report.push_str(&format!(
+            "{:>10} {:>7.2} {:>12} {:>9}  {:<14} {}\n",
+            millis(timings.time),
+            share,
+            timings.visits,
+            timings.warnings.values().sum::<u64>(),
+            kind.as_str(),
+            name
+        ));
+
+        let mut warnings: Vec<_> = timings.warnings.into_iter().collect();
+        warnings.sort_by(|(_, a), (_, b)| b.cmp(a));
+        for (lint, count) in warnings {
+            report.push_str(&format!("{:>10} {:>7} {:>12} {:>9}  {:<14}   {}\n", "", "", "", count, "", lint));
+        }
+    }
+    report
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1000.)
+}
+
+/// Registers the passes in a `LintStore`, timing them if profiling is enabled.
+///
+/// The other methods of `LintStore` are available through `Deref`.
+pub struct ProfilingStore<'a> {
+    store: &'a mut LintStore,
+    enabled: bool,
+}
+
+impl<'a> ProfilingStore<'a> {
+    pub fn new(store: &'a mut LintStore) -> Self {
+        Self {
+            store,
+            enabled: enabled(),
+        }
+    }
+
+    pub fn register_pre_expansion_pass(&mut self, pass: impl Fn() -> EarlyLintPassObject + 'static + Sync + Send) {
+        if self.enabled {
+            self.store
+                .register_pre_expansion_pass(move || box TimedEarly::new(pass(), Kind::PreExpansion));
+        } else {
+            self.store.register_pre_expansion_pass(pass);
+        }
+    }
+
+    pub fn register_early_pass(&mut self, pass: impl Fn() -> EarlyLintPassObject + 'static + Sync + Send) {
+        if self.enabled {
+            self.store
+                .register_early_pass(move || box TimedEarly::new(pass(), Kind::Early));
+        } else {
+            self.store.register_early_pass(pass);
+        }
+    }
+
+    pub fn register_late_pass(&mut self, pass: impl Fn() -> LateLintPassObject + 'static + Sync + Send) {
+        if self.enabled {
+            self.store.register_late_pass(move || box TimedLate::new(pass()));
+        } else {
+            self.store.register_late_pass(pass);
+        }
+    }
+}
+
+impl Deref for ProfilingStore<'_> {
+    type Target = LintStore;
+
+    fn deref(&self) -> &LintStore {
+        self.store
+    }
+}
+
+impl DerefMut for ProfilingStore<'_> {
+    fn deref_mut(&mut self) -> &mut LintStore {
+        self.store
+    }
+}
+
+/// An early or pre-expansion pass, timed.
+struct TimedEarly {
+    pass: EarlyLintPassObject,
+    kind: Kind,
+    timings: Timings,
+}
+
+impl TimedEarly {
+    fn new(pass: EarlyLintPassObject, kind: Kind) -> Self {
+        Self {
+            pass,
+            kind,
+            timings: Timings::default(),
+        }
+    }
+}
+
+impl Drop for TimedEarly {
+    fn drop(&mut self) {
+        collect(self.pass.name(), self.kind, std::mem::replace(&mut self.timings, Timings::default()));
+    }
+}
+
+impl LintPass for TimedEarly {
+    fn name(&self) -> &'static str {
+        self.pass.name()
+    }
+}
+
+macro_rules! timed_early_pass_impl {
+    ([], [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => (
+        impl EarlyLintPass for TimedEarly {
+            $(fn $name(&mut self, cx: &EarlyContext<'_>, $($param: $arg),*) {
+                let start = Instant::now();
+                let pass = &mut self.pass;
+                run_as(pass.name(), self.kind, || pass.$name(cx, $($param),*));
+                self.timings.add(start);
+            })*
+        }
+    )
+}
+
+rustc::early_lint_methods!(timed_early_pass_impl, []);
+
+/// A late pass, timed.
+struct TimedLate {
+    pass: LateLintPassObject,
+    timings: Timings,
+}
+
+impl TimedLate {
+    fn new(pass: LateLintPassObject) -> Self {
+        Self {
+            pass,
+            timings: Timings::default(),
+        }
+    }
+}
+
+impl Drop for TimedLate {
+    fn drop(&mut self) {
+        collect(self.pass.name(), Kind::Late, std::mem::replace(&mut self.timings, Timings::default()));
+    }
+}
+
+impl LintPass for TimedLate {
+    fn name(&self) -> &'static str {
+        self.pass.name()
+    }
+}
+
+macro_rules! timed_late_pass_impl {
+    ([], [$hir:tt], [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => (
+        impl<'a, $hir> LateLintPass<'a, $hir> for TimedLate {
+            $(fn $name(&mut self, cx: &LateContext<'a, $hir>, $($param: $arg),*) {
+                let start = Instant::now();
+                let pass = &mut self.pass;
+                run_as(pass.name(), Kind::Late, || pass.$name(cx, $($param),*));
+                self.timings.add(start);
+            })*
+        }
+    )
+}
+
+rustc::late_lint_methods!(timed_late_pass_impl, [], ['tcx]);
+
+#[cfg(test)]
+mod test {
+    use super::{collect, report, Kind, Timings, TIMINGS};
+    use std::collections::BTreeMap;
+    use std::time::Duration;
+
+    fn timings(millis: u64, visits: u64, warnings: &[(&str, u64)]) -> Timings {
+        Timings {
+            time: Duration::from_millis(millis),
+            visits,
+            warnings: warnings.iter().map(|(lint, count)| (lint.to_string(), *count)).collect(),
+        }
+    }
+
+    #[test]
+    fn collected() {
+        collect("Loops", Kind::Late, timings(1, 2, &[("clippy::explicit_counter_loop", 1)]));
+        collect("Loops", Kind::Late, timings(2, 3, &[("clippy::explicit_counter_loop", 2)]));
+        collect("Loops", Kind::Early, timings(4, 1, &[]));
+        let all = std::mem::replace(&mut *TIMINGS.lock().unwrap(), BTreeMap::new());
+
+        assert_eq!(all.len(), 2);
+        let late = &all[&("Loops", Kind::Late)];
+        assert_eq!(late.time, Duration::from_millis(3));
+        assert_eq!(late.visits, 5);
+        assert_eq!(late.warnings["clippy::explicit_counter_loop"], 3);
+    }
+
+    #[test]
+    fn report_order() {
+        let mut all = BTreeMap::new();
+        all.insert(("Fast", Kind::Early), timings(1, 10, &[]));
+        all.insert(("Slow", Kind::Late), timings(3, 20, &[("clippy::a", 1), ("clippy::b", 4)]));
+        let report = report("krate", all);
+        let lines: Vec<Vec<&str>> = report.lines().map(|line| line.split_whitespace().collect()).collect();
+
+        assert_eq!(lines[0], ["Clippy", "lint", "passes", "of", "`krate`,", "4.000", "ms", "in", "total:"]);
+        assert_eq!(lines[2], ["3.000", "75.00", "20", "5", "late", "Slow"]);
+        assert_eq!(lines[3], ["4", "clippy::b"]);
+        assert_eq!(lines[4], ["1", "clippy::a"]);
+        assert_eq!(lines[5], ["1.000", "25.00", "10", "0", "early", "Fast"]);
+        assert_eq!(lines.len(), 6);
+    }
+}
@@ -20,6 +20,7 @@ declare_clippy_lint! {
     /// ```rust,ignore
     /// foo(&vec![1, 2])
     /// ```
+    #[clippy::version = "0.0.59"]
     pub USELESS_VEC,
     perf,
     "useless `vec!`"
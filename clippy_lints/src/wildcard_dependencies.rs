@@ -1,3 +1,4 @@
+use crate::utils::manifest::{current_package, Manifest};
 use crate::utils::span_lint;
 use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
 use rustc::{declare_lint_pass, declare_tool_lint};
@@ -22,6 +23,7 @@ declare_clippy_lint! {
     /// [dependencies]
     /// regex = "*"
     /// ```
+    #[clippy::version = "0.0.212"]
     pub WILDCARD_DEPENDENCIES,
     cargo,
     "wildcard dependencies being used"
@@ -38,7 +40,14 @@ impl EarlyLintPass for WildcardDependencies {
             return;
         };
 
-        for dep in &metadata.packages[0].dependencies {
+        let package = if let Some(package) = current_package(&metadata) {
+            package
+        } else {
+            return;
+        };
+        let manifest = Manifest::load(cx.sess, &package.manifest_path);
+
+        for dep in &package.dependencies {
             // VersionReq::any() does not work
             if_chain! {
                 if let Ok(wildcard_ver) = semver::VersionReq::parse("*");
@@ -46,10 +55,14 @@ impl EarlyLintPass for WildcardDependencies {
                 if !source.starts_with("git");
                 if dep.req == wildcard_ver;
                 then {
+                    let span = manifest
+                        .as_ref()
+                        .and_then(|manifest| manifest.dependency_span(dep))
+                        .unwrap_or(DUMMY_SP);
                     span_lint(
                         cx,
                         WILDCARD_DEPENDENCIES,
-                        DUMMY_SP,
+                        span,
                         &format!("wildcard dependency for `{}`", dep.name),
                     );
                 }
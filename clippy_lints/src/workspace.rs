@@ -0,0 +1,253 @@
+//! Facts about each crate of a workspace, for the lints `cargo clippy` reports once every crate is
+//! checked, see `src/workspace.rs`.
+
+use crate::utils::in_macro;
+use rustc::hir::def_id::{DefId, LOCAL_CRATE};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, Level, Lint, LintArray, LintContext, LintPass};
+use rustc::session::config::CrateType;
+use rustc::{declare_tool_lint, impl_lint_pass};
+use rustc_data_structures::fx::FxHashSet;
+use serde::Serialize;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use syntax::source_map::{FileName, Span};
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for public items of a library of the workspace that no crate of
+    /// the workspace uses. Only reported by `cargo clippy`, once every crate is checked.
+    ///
+    /// **Why is this bad?** The item is dead code that the `dead_code` lint can't see, as it is
+    /// part of the public API of its crate.
+    ///
+    /// **Known problems:** The items used by other crates outside of the workspace, or only by
+    /// targets that aren't checked, like the tests without `--all-targets`, are reported too.
+    /// Only reported when enabled on the command line, e.g.
+    /// `cargo clippy -- -W clippy::unused_workspace_item`.
+    ///
+    /// **Example:**
+    /// ```rust
+    /// // in `util/src/lib.rs`, if no crate of the workspace calls `util::helper`
+    /// pub fn helper() {}
+    /// ```
+    #[clippy::version = "0.0.212"]
+    pub UNUSED_WORKSPACE_ITEM,
+    restriction,
+    "public items that no crate of the workspace uses"
+}
+
+declare_clippy_lint! {
+    /// **What it does:** Checks for packages of the workspace whose edition differs from the
+    /// edition of most packages. Only reported by `cargo clippy`, once every crate is checked.
+    ///
+    /// **Why is this bad?** The same code means different things in different editions, e.g. the
+    /// paths of `use` items, which makes moving code between the crates error-prone.
+    ///
+    /// **Known problems:** Only reported when enabled on the command line, e.g.
+    /// `cargo clippy -- -W clippy::inconsistent_workspace_edition`.
+    ///
+    /// **Example:**
+    /// ```toml
+    /// # in a workspace of 2018 edition packages
+    /// [package]
+    /// name = "legacy"
+    /// version = "0.1.0"
+    /// edition = "2015"
+    /// ```
+    #[clippy::version = "0.0.212"]
+    pub INCONSISTENT_WORKSPACE_EDITION,
+    cargo,
+    "packages of a workspace using different editions"
+}
+
+#[derive(Serialize)]
+struct Facts {
+    #[serde(rename = "crate")]
+    krate: String,
+    /// The directory of the `Cargo.toml` of the package, to find the members of the workspace.
+    manifest_dir: Option<PathBuf>,
+    /// The root file of the crate, the lib and the binaries of a package can have the same name.
+    root: Option<PathBuf>,
+    /// Whether the crate is the test harness of a target, which has the same name and root.
+    test: bool,
+    library: bool,
+    edition: String,
+    /// The level of `INCONSISTENT_WORKSPACE_EDITION` in this crate.
+    edition_level: &'static str,
+    /// The public items, if the crate is a library.
+    exports: Vec<Export>,
+    /// The items the crate refers to, of any crate, in the format of `Export::id`.
+    uses: FxHashSet<String>,
+}
+
+#[derive(Serialize)]
+struct Export {
+    /// An identifier of the item that is the same in every crate, its `DefPathHash`.
+    id: String,
+    /// The path of the item, like `util::helper`.
+    path: String,
+    file: String,
+    line: usize,
+    column: usize,
+    /// The level of `UNUSED_WORKSPACE_ITEM` for this item.
+    level: &'static str,
+}
+
+pub struct WorkspaceFacts {
+    dir: PathBuf,
+    exports: Vec<Export>,
+    uses: FxHashSet<String>,
+}
+
+impl WorkspaceFacts {
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            exports: Vec::new(),
+            uses: FxHashSet::default(),
+        }
+    }
+
+    fn check_export(&mut self, cx: &LateContext<'_, '_>, hir_id: HirId, span: Span) {
+        if in_macro(span) || !cx.access_levels.is_exported(hir_id) || !is_library(cx) {
+            return;
+        }
+        let level = level_at(cx, UNUSED_WORKSPACE_ITEM, hir_id);
+        if level == "allow" {
+            return;
+        }
+
+        let def_id = cx.tcx.hir().local_def_id(hir_id);
+        let loc = cx.sess().source_map().lookup_char_pos(span.lo());
+        if let FileName::Real(ref file) = loc.file.name {
+            let path = cx.tcx.def_path_str(def_id);
+            let path = if path.starts_with("crate::") {
+                &path["crate::".len()..]
+            } else {
+                &path
+            };
+            self.exports.push(Export {
+                id: id(cx, def_id),
+                path: format!("{}::{}", cx.tcx.crate_name(LOCAL_CRATE), path),
+                file: file.display().to_string(),
+                line: loc.line,
+                column: loc.col.0 + 1,
+                level,
+            });
+        }
+    }
+
+    fn check_use(&mut self, cx: &LateContext<'_, '_>, def_id: Option<DefId>) {
+        if let Some(def_id) = def_id {
+            self.uses.insert(id(cx, def_id));
+        }
+    }
+}
+
+impl_lint_pass!(WorkspaceFacts => [UNUSED_WORKSPACE_ITEM, INCONSISTENT_WORKSPACE_EDITION]);
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for WorkspaceFacts {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        match item.kind {
+            ItemKind::Fn(..)
+            | ItemKind::Const(..)
+            | ItemKind::Static(..)
+            | ItemKind::Struct(..)
+            | ItemKind::Enum(..)
+            | ItemKind::Union(..)
+            | ItemKind::Trait(..)
+            | ItemKind::TyAlias(..) => self.check_export(cx, item.hir_id, item.span),
+            _ => {},
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, impl_item: &'tcx ImplItem) {
+        // the items of trait impls are used through the trait
+        let parent = cx.tcx.hir().get_parent_item(impl_item.hir_id);
+        if let Some(Node::Item(&Item {
+            kind: ItemKind::Impl(_, _, _, _, None, _, _),
+            ..
+        })) = cx.tcx.hir().find(parent)
+        {
+            self.check_export(cx, impl_item.hir_id, impl_item.span);
+        }
+    }
+
+    fn check_path(&mut self, cx: &LateContext<'a, 'tcx>, path: &'tcx Path, hir_id: HirId) {
+        // an import doesn't use the item, and a re-export is checked where it is used
+        if let Some(Node::Item(&Item {
+            kind: ItemKind::Use(..),
+            ..
+        })) = cx.tcx.hir().find(hir_id)
+        {
+            return;
+        }
+        self.check_use(cx, path.res.opt_def_id());
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        match expr.kind {
+            ExprKind::MethodCall(..) | ExprKind::Path(QPath::TypeRelative(..)) => {
+                self.check_use(cx, cx.tables.type_dependent_def_id(expr.hir_id));
+            },
+            _ => {},
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        let sess = cx.sess();
+        let krate = cx.tcx.crate_name(LOCAL_CRATE);
+        let facts = Facts {
+            krate: krate.to_string(),
+            manifest_dir: env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from),
+            root: sess.local_crate_source_file.clone(),
+            test: sess.opts.test,
+            library: is_library(cx),
+            edition: sess.edition().to_string(),
+            edition_level: level_at(cx, INCONSISTENT_WORKSPACE_EDITION, CRATE_HIR_ID),
+            exports: std::mem::replace(&mut self.exports, Vec::new()),
+            uses: std::mem::replace(&mut self.uses, FxHashSet::default()),
+        };
+
+        // the library, its tests and the binaries of a package are different crates
+        let file = self.dir.join(format!(
+            "{}-{}.json",
+            krate,
+            sess.local_crate_disambiguator().to_fingerprint().to_hex()
+        ));
+        let written = fs::create_dir_all(&self.dir).and_then(|_| {
+            fs::write(
+                &file,
+                serde_json::to_string(&facts).expect("the facts are always serializable"),
+            )
+        });
+        if let Err(e) = written {
+            sess.err(&format!("error writing `{}`: {}", file.display(), e));
+        }
+    }
+}
+
+/// An identifier of the item that is the same in every crate.
+fn id(cx: &LateContext<'_, '_>, def_id: DefId) -> String {
+    cx.tcx.def_path_hash(def_id).0.to_hex()
+}
+
+fn is_library(cx: &LateContext<'_, '_>) -> bool {
+    !cx.sess().opts.test
+        && !cx
+            .sess()
+            .crate_types
+            .borrow()
+            .iter()
+            .any(|crate_type| *crate_type == CrateType::Executable)
+}
+
+fn level_at(cx: &LateContext<'_, '_>, lint: &'static Lint, hir_id: HirId) -> &'static str {
+    match cx.tcx.lint_level_at_node(lint, hir_id).0 {
+        Level::Allow => "allow",
+        Level::Warn => "warn",
+        Level::Deny => "deny",
+        Level::Forbid => "forbid",
+    }
+}
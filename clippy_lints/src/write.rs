@@ -20,6 +20,7 @@ declare_clippy_lint! {
     /// ```rust
     /// println!("");
     /// ```
+    #[clippy::version = "0.0.167"]
     pub PRINTLN_EMPTY_STRING,
     style,
     "using `println!(\"\")` with an empty string"
@@ -45,6 +46,7 @@ declare_clippy_lint! {
     /// # let name = "World";
     /// println!("Hello {}!", name);
     /// ```
+    #[clippy::version = "0.0.83"]
     pub PRINT_WITH_NEWLINE,
     style,
     "using `print!()` with a format string that ends in a single newline"
@@ -63,6 +65,7 @@ declare_clippy_lint! {
     /// ```rust
     /// println!("Hello world!");
     /// ```
+    #[clippy::version = "0.0.212"]
     pub PRINT_STDOUT,
     restriction,
     "printing on stdout"
@@ -80,6 +83,7 @@ declare_clippy_lint! {
     /// # let foo = "bar";
     /// println!("{:?}", foo);
     /// ```
+    #[clippy::version = "0.0.212"]
     pub USE_DEBUG,
     restriction,
     "use of `Debug`-based formatting"
@@ -103,6 +107,7 @@ declare_clippy_lint! {
     /// ```rust
     /// println!("foo");
     /// ```
+    #[clippy::version = "0.0.192"]
     pub PRINT_LITERAL,
     style,
     "printing a literal with a format string"
@@ -122,6 +127,7 @@ declare_clippy_lint! {
     /// # let mut buf = String::new();
     /// writeln!(buf, "");
     /// ```
+    #[clippy::version = "0.0.194"]
     pub WRITELN_EMPTY_STRING,
     style,
     "using `writeln!(buf, \"\")` with an empty string"
@@ -144,6 +150,7 @@ declare_clippy_lint! {
     /// # let name = "World";
     /// write!(buf, "Hello {}!\n", name);
     /// ```
+    #[clippy::version = "0.0.194"]
     pub WRITE_WITH_NEWLINE,
     style,
     "using `write!()` with a format string that ends in a single newline"
@@ -165,6 +172,7 @@ declare_clippy_lint! {
     /// # let mut buf = String::new();
     /// writeln!(buf, "{}", "foo");
     /// ```
+    #[clippy::version = "0.0.194"]
     pub WRITE_LITERAL,
     style,
     "writing a literal with a format string"
@@ -17,6 +17,7 @@ declare_clippy_lint! {
     /// ```rust
     /// 0.0f32 / 0.0;
     /// ```
+    #[clippy::version = "0.0.47"]
     pub ZERO_DIVIDED_BY_ZERO,
     complexity,
     "usage of `0.0 / 0.0` to obtain NaN instead of std::f32::NaN or std::f64::NaN"
@@ -0,0 +1,192 @@
+//! `cargo clippy --baseline` and `--write-baseline`. The warnings are matched against the baseline
+//! by the driver, see `clippy_lints/src/utils/baseline.rs`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+pub enum Baseline {
+    /// Don't report the warnings of this baseline file.
+    Read(PathBuf),
+    /// Write the current warnings to `baseline`, the drivers record them in `records`.
+    Write { baseline: PathBuf, records: PathBuf },
+}
+
+impl Baseline {
+    /// The environment variable passing the baseline to the driver.
+    pub fn env(&self) -> (&'static str, &Path) {
+        match self {
+            Baseline::Read(path) => ("CLIPPY_BASELINE", path),
+            Baseline::Write { records, .. } => ("CLIPPY_WRITE_BASELINE", records),
+        }
+    }
+}
+
+/// Removes `--baseline PATH` or `--write-baseline PATH` from the cargo arguments.
+pub fn take_baseline_args(args: &mut Vec<String>) -> Result<Option<Baseline>, i32> {
+    let read = take_path_arg(args, "--baseline")?;
+    let write = take_path_arg(args, "--write-baseline")?;
+
+    // The drivers don't run in the current directory.
+    let absolute = |path: PathBuf| std::env::current_dir().map(|dir| dir.join(&path)).unwrap_or(path);
+
+    match (read, write) {
+        (Some(_), Some(_)) => {
+            eprintln!("error: `--baseline` and `--write-baseline` can't be used together");
+            Err(1)
+        },
+        (Some(path), None) => Ok(Some(Baseline::Read(absolute(path)))),
+        (None, Some(path)) => Ok(Some(Baseline::Write {
+            baseline: absolute(path),
+            records: std::env::temp_dir().join(format!("clippy-baseline-{}.jsonl", std::process::id())),
+        })),
+        (None, None) => Ok(None),
+    }
+}
+
+fn take_path_arg(args: &mut Vec<String>, name: &str) -> Result<Option<PathBuf>, i32> {
+    let prefix = format!("{}=", name);
+    let index = match args.iter().position(|arg| arg == name || arg.starts_with(&prefix)) {
+        Some(index) => index,
+        None => return Ok(None),
+    };
+
+    let arg = args.remove(index);
+    if arg == name {
+        if index < args.len() {
+            Ok(Some(PathBuf::from(args.remove(index))))
+        } else {
+            eprintln!("error: `{}` requires a path", name);
+            Err(1)
+        }
+    } else {
+        Ok(Some(PathBuf::from(&arg[prefix.len()..])))
+    }
+}
+
+/// Runs Clippy recording the warnings, and writes them to `baseline`.
+pub fn write<F>(args: &[String], baseline: &Path, records: &Path, cargo: F) -> Result<(), i32>
+where
+    F: Fn(&[String]) -> Command,
+{
+    let _ = fs::remove_file(records);
+    crate::clean_workspace(args)?;
+
+    let exit_status = cargo(args)
+        .spawn()
+        .expect("could not run cargo")
+        .wait()
+        .expect("failed to wait for cargo?");
+    let records_content = fs::read_to_string(records).unwrap_or_default();
+    let _ = fs::remove_file(records);
+    if !exit_status.success() {
+        eprintln!("error: could not write the baseline, the crate doesn't compile");
+        return Err(exit_status.code().unwrap_or(-1));
+    }
+
+    let (baseline_value, warnings) = merge_records(&records_content);
+    let mut content = serde_json::to_string_pretty(&baseline_value).expect("the baseline is always serializable");
+    content.push('\n');
+    if let Err(e) = fs::write(baseline, content) {
+        eprintln!("error: could not write `{}`: {}", baseline.display(), e);
+        return Err(1);
+    }
+
+    eprintln!("Wrote {} warnings to `{}`", warnings, baseline.display());
+    Ok(())
+}
+
+/// Merges the warnings the drivers recorded into a baseline, returning it with its number of
+/// warnings.
+fn merge_records(records: &str) -> (serde_json::Value, u64) {
+    // A file shared by several crates is checked once per crate, so the count of an entry is the
+    // largest count of a single driver.
+    let mut counts: BTreeMap<(String, String, String), BTreeMap<u64, usize>> = BTreeMap::new();
+    for record in records
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+    {
+        let field = |name: &str| record[name].as_str().unwrap_or_default().to_owned();
+        let key = (field("file"), field("lint"), field("fingerprint"));
+        let process = record["process"].as_u64().unwrap_or_default();
+        *counts.entry(key).or_default().entry(process).or_default() += 1;
+    }
+
+    let entries: Vec<_> = counts
+        .into_iter()
+        .map(|((file, lint, fingerprint), per_process)| {
+            serde_json::json!({
+                "lint": lint,
+                "file": file,
+                "fingerprint": fingerprint,
+                "count": per_process.values().max().copied().unwrap_or_default(),
+            })
+        })
+        .collect();
+    let warnings = entries.iter().filter_map(|entry| entry["count"].as_u64()).sum();
+    (serde_json::json!({ "version": 1, "entries": entries }), warnings)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{merge_records, take_baseline_args, Baseline};
+    use serde_json::json;
+    use std::path::PathBuf;
+
+    fn strings(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn baseline_args() {
+        let current_dir = std::env::current_dir().unwrap();
+
+        let mut args = strings(&["--all-targets", "--baseline", "baseline.json", "--", "-D", "warnings"]);
+        match take_baseline_args(&mut args) {
+            Ok(Some(Baseline::Read(path))) => assert_eq!(path, current_dir.join("baseline.json")),
+            _ => panic!("expected `--baseline` to be read"),
+        }
+        assert_eq!(args, strings(&["--all-targets", "--", "-D", "warnings"]));
+
+        let mut args = strings(&["--write-baseline=/tmp/baseline.json"]);
+        match take_baseline_args(&mut args) {
+            Ok(Some(Baseline::Write { baseline, .. })) => assert_eq!(baseline, PathBuf::from("/tmp/baseline.json")),
+            _ => panic!("expected `--write-baseline` to be read"),
+        }
+        assert!(args.is_empty());
+
+        assert!(take_baseline_args(&mut strings(&["--all-targets"])).unwrap().is_none());
+        assert!(take_baseline_args(&mut strings(&["--baseline"])).is_err());
+        assert!(take_baseline_args(&mut strings(&["--baseline=a.json", "--write-baseline=b.json"])).is_err());
+    }
+
+    #[test]
+    fn merge() {
+        let record = |lint: &str, fingerprint: &str, process: u32| {
+            json!({ "lint": lint, "file": "src/lib.rs", "fingerprint": fingerprint, "process": process }).to_string()
+        };
+        let records = [
+            // the library and its unit tests both check `src/lib.rs`
+            record("needless_return", "0001", 1),
+            record("needless_return", "0001", 1),
+            record("needless_return", "0001", 2),
+            record("let_and_return", "0002", 2),
+            "not a record".to_string(),
+        ]
+        .join("\n");
+
+        let (baseline, warnings) = merge_records(&records);
+        assert_eq!(warnings, 3);
+        assert_eq!(
+            baseline,
+            json!({
+                "version": 1,
+                "entries": [
+                    { "lint": "let_and_return", "file": "src/lib.rs", "fingerprint": "0002", "count": 1 },
+                    { "lint": "needless_return", "file": "src/lib.rs", "fingerprint": "0001", "count": 2 },
+                ],
+            })
+        );
+    }
+}
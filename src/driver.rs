@@ -10,11 +10,13 @@ extern crate rustc_interface;
 
 use rustc_interface::interface;
 use rustc_tools_util::*;
+use serde::Serialize;
 
 use std::path::{Path, PathBuf};
 use std::process::{exit, Command};
 
 mod lintlist;
+mod workspace_env;
 
 /// If a command-line option matches `find_arg`, then apply the predicate `pred` on its value. If
 /// true, then return it. The parameter is assumed to be either `--arg=value` or `--arg value`.
@@ -56,13 +58,54 @@ fn test_arg_value() {
     assert_eq!(arg_value(&args, "--foo", |_| true), None);
 }
 
+/// Removes the `--lint-plugin PATH` arguments, which rustc doesn't know, returning the paths.
+fn take_lint_plugin_args(args: &mut Vec<String>) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    let mut i = 0;
+    while i < args.len() {
+        if args[i] == "--lint-plugin" && i + 1 < args.len() {
+            args.remove(i);
+            paths.push(PathBuf::from(args.remove(i)));
+        } else if args[i].starts_with("--lint-plugin=") {
+            paths.push(PathBuf::from(&args.remove(i)["--lint-plugin=".len()..]));
+        } else {
+            i += 1;
+        }
+    }
+    paths
+}
+
+#[test]
+fn test_take_lint_plugin_args() {
+    let mut args: Vec<_> = ["--lint-plugin", "a.so", "--crate-name", "foo", "--lint-plugin=b.so"]
+        .iter()
+        .map(std::string::ToString::to_string)
+        .collect();
+
+    assert_eq!(
+        take_lint_plugin_args(&mut args),
+        vec![PathBuf::from("a.so"), PathBuf::from("b.so")]
+    );
+    assert_eq!(args, vec!["--crate-name", "foo"]);
+}
+
 #[allow(clippy::too_many_lines)]
 
-struct ClippyCallbacks;
+struct ClippyCallbacks {
+    /// The lint plugins passed with `--lint-plugin`.
+    lint_plugins: Vec<PathBuf>,
+}
 
 impl rustc_driver::Callbacks for ClippyCallbacks {
     fn config(&mut self, config: &mut interface::Config) {
+        let lint_versions: Vec<(&str, &str)> = lintlist::ALL_LINTS
+            .iter()
+            .filter_map(|lint| lint.version.map(|version| (lint.name, version)))
+            .collect();
+        clippy_lints::register_conf_lint_levels(&mut config.opts, config.input_path.as_ref(), &lint_versions);
+
         let previous = config.register_lints.take();
+        let lint_plugins = self.lint_plugins.clone();
         config.register_lints = Some(Box::new(move |sess, mut lint_store| {
             // technically we're ~guaranteed that this is none but might as well call anything that
             // is there already. Certainly it can't hurt.
@@ -71,13 +114,86 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
             }
 
             let conf = clippy_lints::read_conf(&[], &sess);
+            clippy_lints::read_baseline(&sess);
             clippy_lints::register_plugins(&mut lint_store, &sess, &conf);
             clippy_lints::register_pre_expansion_lints(&mut lint_store, &conf);
             clippy_lints::register_renamed(&mut lint_store);
+            clippy_lints::register_lint_plugins(&mut lint_store, &sess, &conf, &lint_plugins);
+            if let Some(dir) = std::env::var_os(workspace_env::FACTS_ENV) {
+                clippy_lints::register_workspace_facts(&mut lint_store, &sess, dir.into());
+            }
         }));
     }
 }
 
+/// A lint of `--explain-lints=json`, with its `group` in lowercase and its default `level`.
+#[derive(Serialize)]
+struct LintJson {
+    name: &'static str,
+    group: String,
+    level: Option<&'static str>,
+    desc: &'static str,
+    deprecation: Option<&'static str>,
+    module: &'static str,
+    docs: &'static str,
+    config: &'static [&'static str],
+    version: Option<&'static str>,
+}
+
+impl From<&lintlist::Lint> for LintJson {
+    fn from(lint: &lintlist::Lint) -> Self {
+        Self {
+            name: lint.name,
+            group: lint.group.to_lowercase(),
+            level: lint.default_level(),
+            desc: lint.desc,
+            deprecation: lint.deprecation,
+            module: lint.module,
+            docs: lint.docs,
+            config: lint.config,
+            version: lint.version,
+        }
+    }
+}
+
+#[test]
+fn test_lint_json() {
+    let lint = lintlist::ALL_LINTS
+        .iter()
+        .find(|lint| lint.name == "needless_return")
+        .unwrap();
+    let json = serde_json::to_value(LintJson::from(lint)).unwrap();
+    assert_eq!(json["name"], "needless_return");
+    assert_eq!(json["group"], "style");
+    assert_eq!(json["level"], "warn");
+    assert_eq!(json["deprecation"], serde_json::Value::Null);
+    assert!(json["config"].is_array());
+
+    let deprecated = &lintlist::DEPRECATED_LINTS[0];
+    let json = serde_json::to_value(LintJson::from(deprecated)).unwrap();
+    assert_eq!(json["level"], serde_json::Value::Null);
+    assert!(json["deprecation"].is_string());
+}
+
+/// Prints every lint, including deprecated lints, as a JSON array.
+///
+/// Each lint has its `name`, `group`, default `level`, `desc`, `deprecation` reason, `module`,
+/// Markdown `docs`, the `config` keys of `clippy.toml` it reads and the `version` it was added
+/// in.
+fn describe_lints_json() {
+    use lintlist::*;
+
+    let lints: Vec<LintJson> = ALL_LINTS
+        .iter()
+        .chain(DEPRECATED_LINTS.iter())
+        .map(LintJson::from)
+        .collect();
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&lints).expect("the lints are always serializable")
+    );
+}
+
 #[allow(clippy::find_map, clippy::filter_map)]
 fn describe_lints() {
     use lintlist::*;
@@ -94,17 +210,7 @@ Available lint options:
 "
     );
 
-    let lint_level = |lint: &Lint| {
-        LINT_LEVELS
-            .iter()
-            .find(|level_mapping| level_mapping.0 == lint.group)
-            .map(|(_, level)| match level {
-                Level::Allow => "allow",
-                Level::Warn => "warn",
-                Level::Deny => "deny",
-            })
-            .unwrap()
-    };
+    let lint_level = |lint: &Lint| lint.default_level().unwrap();
 
     let mut lints: Vec<_> = ALL_LINTS.iter().collect();
     // The sort doesn't case-fold but it's doubtful we care.
@@ -128,16 +234,17 @@ Available lint options:
     let lint_groups: HashSet<_> = lints.iter().map(|lint| lint.group).collect();
 
     println!("Lint checks provided by clippy:\n");
-    println!("    {}  {:7.7}  meaning", padded("name"), "default");
-    println!("    {}  {:7.7}  -------", padded("----"), "-------");
+    println!("    {}  {:7.7}  {:7.7}  meaning", padded("name"), "default", "added");
+    println!("    {}  {:7.7}  {:7.7}  -------", padded("----"), "-------", "-----");
 
     let print_lints = |lints: &[&Lint]| {
         for lint in lints {
             let name = lint.name.replace("_", "-");
             println!(
-                "    {}  {:7.7}  {}",
+                "    {}  {:7.7}  {:7.7}  {}",
                 padded(&scoped(&name)),
                 lint_level(lint),
+                lint.version.unwrap_or(""),
                 lint.desc
             );
         }
@@ -185,6 +292,59 @@ Available lint options:
     print_lint_groups();
 }
 
+/// Prints the lints and lint groups registered by the lint plugins, if any.
+fn describe_plugin_lints(lint_plugins: &[PathBuf]) {
+    let store = match clippy_lints::plugin_lint_store(lint_plugins) {
+        Ok(store) => store,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            exit(1);
+        },
+    };
+    let lints = store.get_lints();
+    if lints.is_empty() {
+        return;
+    }
+    let lint_groups = store.get_lint_groups();
+
+    let max_name_len = lints
+        .iter()
+        .map(|lint| lint.name.len())
+        .chain(lint_groups.iter().map(|(name, ..)| name.len()))
+        .max()
+        .unwrap_or(0);
+    let padded = |x: &str| format!("{:>width$}", x, width = max_name_len);
+
+    println!("Lint checks provided by lint plugins:\n");
+    println!("    {}  {:7.7}  meaning", padded("name"), "default");
+    println!("    {}  {:7.7}  -------", padded("----"), "-------");
+    for lint in lints {
+        println!(
+            "    {}  {:7.7}  {}",
+            padded(&lint.name_lower().replace("_", "-")),
+            lint.default_level.as_str(),
+            lint.desc
+        );
+    }
+    println!("\n");
+
+    if lint_groups.is_empty() {
+        return;
+    }
+    println!("Lint groups provided by lint plugins:\n");
+    println!("    {}  sub-lints", padded("name"));
+    println!("    {}  ---------", padded("----"));
+    for (name, lints, _) in lint_groups {
+        let desc = lints
+            .iter()
+            .map(|lint| lint.to_string().replace("_", "-"))
+            .collect::<Vec<String>>()
+            .join(", ");
+        println!("    {}  {}", padded(&name.replace("_", "-")), desc);
+    }
+    println!("\n");
+}
+
 fn display_help() {
     println!(
         "\
@@ -196,6 +356,8 @@ Usage:
 Common options:
     -h, --help               Print this message
     -V, --version            Print version info and exit
+    --explain-lints=json     Print all lints with their documentation as JSON and exit
+    --lint-plugin PATH       Load the lints of a lint plugin, after `--` with `cargo clippy`
 
 Other options are the same as `cargo check`.
 
@@ -275,6 +437,8 @@ pub fn main() {
                 orig_args.remove(1);
             }
 
+            let mut lint_plugins = take_lint_plugin_args(&mut orig_args);
+
             if !wrapper_mode && std::env::args().any(|a| a == "--help" || a == "-h") {
                 display_help();
                 exit(0);
@@ -293,6 +457,19 @@ pub fn main() {
 
             if !wrapper_mode && should_describe_lints() {
                 describe_lints();
+                describe_plugin_lints(&lint_plugins);
+                exit(0);
+            }
+
+            if let (false, Some(format)) = (wrapper_mode, arg_value(&orig_args, "--explain-lints", |_| true)) {
+                if format != "json" {
+                    eprintln!(
+                        "error: unsupported format `{}` for `--explain-lints`, expected `json`",
+                        format
+                    );
+                    exit(1);
+                }
+                describe_lints_json();
                 exit(0);
             }
 
@@ -327,14 +504,19 @@ pub fn main() {
                         }
                     }));
                 }
+                lint_plugins.extend(take_lint_plugin_args(&mut args));
             }
 
-            let mut clippy = ClippyCallbacks;
+            let mut clippy = ClippyCallbacks { lint_plugins };
             let mut default = rustc_driver::DefaultCallbacks;
             let callbacks: &mut (dyn rustc_driver::Callbacks + Send) =
                 if clippy_enabled { &mut clippy } else { &mut default };
             let args = args;
-            rustc_driver::run_compiler(&args, callbacks, None, None)
+            let result = rustc_driver::run_compiler(&args, callbacks, None, None);
+            if clippy_enabled {
+                clippy_lints::print_profile(arg_value(&args, "--crate-name", |_| true).unwrap_or("<unknown>"));
+            }
+            result
         })
         .and_then(|result| result)
         .is_err() as i32,
@@ -0,0 +1,363 @@
+//! `cargo clippy --fix`: applies the suggestions emitted by the lints to the source files.
+
+use rustfix::diagnostics::{Applicability, Diagnostic};
+use rustfix::{CodeFix, Filter, Suggestion};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+pub struct FixOptions {
+    /// Apply the fixes even if the working directory has uncommitted changes.
+    allow_dirty: bool,
+    /// Also apply the suggestions marked `MaybeIncorrect`.
+    allow_maybe_incorrect: bool,
+}
+
+/// Removes `--fix` and its companion flags from the cargo arguments, returning the options if
+/// `--fix` was passed.
+pub fn take_fix_args(args: &mut Vec<String>) -> Option<FixOptions> {
+    if !args.iter().any(|arg| arg == "--fix") {
+        return None;
+    }
+
+    let options = FixOptions {
+        allow_dirty: args.iter().any(|arg| arg == "--allow-dirty"),
+        allow_maybe_incorrect: args.iter().any(|arg| arg == "--allow-maybe-incorrect"),
+    };
+    args.retain(|arg| arg != "--fix" && arg != "--allow-dirty" && arg != "--allow-maybe-incorrect");
+    Some(options)
+}
+
+/// Runs Clippy with JSON diagnostics, applies the suggestions to the files of the workspace and
+/// checks that the result still compiles, reverting the files if it doesn't.
+///
+/// `cargo` builds a `cargo` command running Clippy with the given arguments.
+pub fn fix<F>(args: &[String], options: &FixOptions, cargo: F) -> Result<(), i32>
+where
+    F: Fn(&[String]) -> Command,
+{
+    if !options.allow_dirty {
+        let dirty = dirty_files();
+        if !dirty.is_empty() {
+            eprintln!("error: the working directory has uncommitted changes:\n");
+            for file in dirty {
+                eprintln!("  * {}", file);
+            }
+            eprintln!("\ncommit or stash them before running `cargo clippy --fix`, or pass `--allow-dirty`");
+            return Err(1);
+        }
+    }
+
+    let workspace_root = workspace_root(args)?;
+
+    // Crates that cargo considers fresh would be skipped without any suggestion.
+    crate::clean_workspace(args)?;
+    let mut json_args = args.to_vec();
+    json_args.push("--message-format=json".to_owned());
+    let output = cargo(&json_args)
+        .stderr(Stdio::inherit())
+        .output()
+        .expect("could not run cargo");
+    let diagnostics = compiler_messages(&String::from_utf8_lossy(&output.stdout));
+
+    if !output.status.success() {
+        // Don't touch code that doesn't compile, the fixes can't be verified.
+        for diagnostic in &diagnostics {
+            if let Some(rendered) = &diagnostic.rendered {
+                eprint!("{}", rendered);
+            }
+        }
+        eprintln!("error: could not apply the suggestions, the crate doesn't compile");
+        return Err(output.status.code().unwrap_or(-1));
+    }
+
+    let mut seen = HashSet::new();
+    let mut by_file: BTreeMap<PathBuf, Vec<Suggestion>> = BTreeMap::new();
+    for mut diagnostic in diagnostics {
+        filter_applicability(&mut diagnostic, options.allow_maybe_incorrect);
+        let suggestion = match rustfix::collect_suggestions(&diagnostic, &HashSet::new(), Filter::Everything) {
+            Some(suggestion) => suggestion,
+            None => continue,
+        };
+        // The same suggestion is emitted once per target when a file is shared, e.g. by a lib and
+        // its tests.
+        if !seen.insert(suggestion.clone()) {
+            continue;
+        }
+        if let Some(file) = suggestion_file(&suggestion, &workspace_root) {
+            by_file.entry(file).or_default().push(suggestion);
+        }
+    }
+
+    let mut originals = Vec::new();
+    for (file, suggestions) in &by_file {
+        let source = match fs::read_to_string(file) {
+            Ok(source) => source,
+            Err(e) => {
+                eprintln!("warning: could not read `{}`: {}", file.display(), e);
+                continue;
+            },
+        };
+        let mut code = CodeFix::new(&source);
+        // Suggestions overlapping with an already applied one are skipped, running `--fix`
+        // again will apply them.
+        let applied = suggestions.iter().filter(|s| code.apply(s).is_ok()).count();
+        let fixed = match code.finish() {
+            Ok(fixed) => fixed,
+            Err(_) => continue,
+        };
+        if applied == 0 || fixed == source {
+            continue;
+        }
+        if let Err(e) = fs::write(file, fixed) {
+            eprintln!("warning: could not write `{}`: {}", file.display(), e);
+            continue;
+        }
+        eprintln!(
+            "      Fixing {} ({} fix{})",
+            file.strip_prefix(&workspace_root).unwrap_or(file).display(),
+            applied,
+            if applied == 1 { "" } else { "es" }
+        );
+        originals.push((file.clone(), source));
+    }
+
+    if !originals.is_empty() {
+        // Check without Clippy, so that remaining lints denied with `-D` don't count as a broken
+        // fix.
+        let check = Command::new("cargo").args(args).output().expect("could not run cargo");
+        if !check.status.success() {
+            let not_reverted: Vec<_> = originals
+                .iter()
+                .filter_map(|(file, source)| fs::write(file, source).err().map(|e| (file, e)))
+                .collect();
+            eprint!("{}", String::from_utf8_lossy(&check.stderr));
+            if not_reverted.is_empty() {
+                eprintln!(
+                    "error: the crate doesn't compile after applying the suggestions, the changes have been reverted"
+                );
+            } else {
+                eprintln!(
+                    "error: the crate doesn't compile after applying the suggestions, and some files could not be \
+                     reverted:\n"
+                );
+                for (file, error) in not_reverted {
+                    eprintln!("  * {}: {}", file.display(), error);
+                }
+                eprintln!("\nrestore them with `git checkout -- <file>`");
+            }
+            eprintln!(
+                "\nThis is likely a bug in Clippy, please report it at https://github.com/rust-lang/rust-clippy/issues \
+                 with the errors above"
+            );
+            return Err(check.status.code().unwrap_or(-1));
+        }
+    }
+
+    // Report what's left. The check above made cargo consider the fixed crates fresh, and the
+    // others were already checked.
+    crate::clean_workspace(args)?;
+    let exit_status = cargo(args)
+        .spawn()
+        .expect("could not run cargo")
+        .wait()
+        .expect("failed to wait for cargo?");
+    if exit_status.success() {
+        Ok(())
+    } else {
+        Err(exit_status.code().unwrap_or(-1))
+    }
+}
+
+/// Returns the files with uncommitted changes, or nothing if the directory isn't in a git
+/// repository.
+fn dirty_files() -> Vec<String> {
+    Command::new("git")
+        .args(&["status", "--porcelain"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map_or_else(Vec::new, |output| {
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .map(|line| line.get(3..).unwrap_or(line).to_owned())
+                .collect()
+        })
+}
+
+/// Returns the root of the workspace the fixes are confined to.
+fn workspace_root(args: &[String]) -> Result<PathBuf, i32> {
+    crate::cargo_metadata(args)?["workspace_root"]
+        .as_str()
+        .map(PathBuf::from)
+        .ok_or_else(|| {
+            eprintln!("error: could not find the workspace root in `cargo metadata`");
+            1
+        })
+}
+
+/// Extracts the rustc diagnostics from the JSON messages of cargo.
+fn compiler_messages(stdout: &str) -> Vec<Diagnostic> {
+    stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter(|message| message["reason"] == "compiler-message")
+        .filter_map(|mut message| serde_json::from_value(message["message"].take()).ok())
+        .collect()
+}
+
+/// Drops the replacements of the suggestions that aren't safe to apply.
+fn filter_applicability(diagnostic: &mut Diagnostic, allow_maybe_incorrect: bool) {
+    for child in &mut diagnostic.children {
+        for span in &mut child.spans {
+            let applicable = match span.suggestion_applicability {
+                Some(Applicability::MachineApplicable) => true,
+                Some(Applicability::MaybeIncorrect) => allow_maybe_incorrect,
+                _ => false,
+            };
+            if !applicable {
+                span.suggested_replacement = None;
+            }
+        }
+    }
+}
+
+/// Returns the file a suggestion applies to, if it's a single file inside the workspace.
+fn suggestion_file(suggestion: &Suggestion, workspace_root: &Path) -> Option<PathBuf> {
+    let mut files = suggestion
+        .solutions
+        .iter()
+        .flat_map(|solution| &solution.replacements)
+        .map(|replacement| &replacement.snippet.file_name);
+    let file = files.next()?;
+    if files.any(|other| other != file) {
+        return None;
+    }
+
+    // Files of the workspace members are relative to the workspace root, dependencies outside of
+    // it are absolute.
+    let path = workspace_root.join(file);
+    if path.starts_with(workspace_root) && !path.components().any(|c| c.as_os_str() == "..") {
+        Some(path)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{filter_applicability, suggestion_file, take_fix_args};
+    use rustfix::diagnostics::Diagnostic;
+    use rustfix::Filter;
+    use serde_json::json;
+    use std::collections::HashSet;
+    use std::path::{Path, PathBuf};
+
+    fn strings(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    /// A warning with a suggestion replacing `x` in `file`.
+    fn diagnostic(file: &str, applicability: &str) -> Diagnostic {
+        let span = |replacement: Option<&str>, applicability: Option<&str>| {
+            json!({
+                "file_name": file,
+                "byte_start": 4,
+                "byte_end": 5,
+                "line_start": 1,
+                "line_end": 1,
+                "column_start": 5,
+                "column_end": 6,
+                "is_primary": true,
+                "text": [{ "text": "let x = 1;", "highlight_start": 5, "highlight_end": 6 }],
+                "label": null,
+                "suggested_replacement": replacement,
+                "suggestion_applicability": applicability,
+                "expansion": null,
+            })
+        };
+        serde_json::from_value(json!({
+            "message": "a lint",
+            "code": null,
+            "level": "warning",
+            "spans": [span(None, None)],
+            "children": [{
+                "message": "try",
+                "code": null,
+                "level": "help",
+                "spans": [span(Some("y"), Some(applicability))],
+                "children": [],
+                "rendered": null,
+            }],
+            "rendered": null,
+        }))
+        .unwrap()
+    }
+
+    fn replacement(diagnostic: &Diagnostic) -> Option<&str> {
+        diagnostic.children[0].spans[0]
+            .suggested_replacement
+            .as_ref()
+            .map(String::as_str)
+    }
+
+    #[test]
+    fn fix_args() {
+        let mut args = strings(&["--all-targets", "--fix", "--allow-dirty", "--", "-D", "warnings"]);
+        let options = take_fix_args(&mut args).unwrap();
+        assert!(options.allow_dirty);
+        assert!(!options.allow_maybe_incorrect);
+        assert_eq!(args, strings(&["--all-targets", "--", "-D", "warnings"]));
+
+        let mut args = strings(&["--allow-maybe-incorrect", "--fix"]);
+        assert!(take_fix_args(&mut args).unwrap().allow_maybe_incorrect);
+        assert!(args.is_empty());
+
+        let mut args = strings(&["--allow-dirty"]);
+        assert!(take_fix_args(&mut args).is_none());
+        assert_eq!(args, strings(&["--allow-dirty"]));
+    }
+
+    #[test]
+    fn applicability() {
+        for &(applicability, allow_maybe_incorrect, applied) in &[
+            ("MachineApplicable", false, true),
+            ("MaybeIncorrect", false, false),
+            ("MaybeIncorrect", true, true),
+            ("HasPlaceholders", true, false),
+            ("Unspecified", true, false),
+        ] {
+            let mut diagnostic = diagnostic("src/lib.rs", applicability);
+            filter_applicability(&mut diagnostic, allow_maybe_incorrect);
+            assert_eq!(
+                replacement(&diagnostic).is_some(),
+                applied,
+                "{} with allow_maybe_incorrect = {}",
+                applicability,
+                allow_maybe_incorrect
+            );
+        }
+    }
+
+    #[test]
+    fn file_of_suggestion() {
+        let root = Path::new("/workspace");
+        let file = |file: &str| {
+            let suggestion = rustfix::collect_suggestions(
+                &diagnostic(file, "MachineApplicable"),
+                &HashSet::new(),
+                Filter::Everything,
+            )
+            .unwrap();
+            suggestion_file(&suggestion, root)
+        };
+        assert_eq!(file("src/lib.rs"), Some(PathBuf::from("/workspace/src/lib.rs")));
+        assert_eq!(
+            file("/workspace/member/src/lib.rs"),
+            Some(PathBuf::from("/workspace/member/src/lib.rs"))
+        );
+        assert_eq!(file("/registry/dependency/src/lib.rs"), None);
+        assert_eq!(file("../outside/src/lib.rs"), None);
+    }
+}
@@ -6,6 +6,26 @@ pub struct Lint {
     pub desc: &'static str,
     pub deprecation: Option<&'static str>,
     pub module: &'static str,
+    /// The documentation of the lint, in Markdown.
+    pub docs: &'static str,
+    /// The `clippy.toml` keys configuring the lint.
+    pub config: &'static [&'static str],
+    /// The version of Clippy the lint was added in, `None` for the lints older than this field.
+    pub version: Option<&'static str>,
+}
+
+impl Lint {
+    /// The level of the lint when it isn't configured, `None` for deprecated lints.
+    pub fn default_level(&self) -> Option<&'static str> {
+        LINT_LEVELS
+            .iter()
+            .find(|(group, _)| *group == self.group)
+            .map(|(_, level)| match level {
+                Level::Allow => "allow",
+                Level::Warn => "warn",
+                Level::Deny => "deny",
+            })
+    }
 }
 
 #[derive(PartialOrd, PartialEq, Ord, Eq)]
@@ -2,6 +2,13 @@
 
 use rustc_tools_util::*;
 
+mod baseline;
+mod fix;
+mod lintlist;
+mod sarif;
+mod workspace;
+mod workspace_env;
+
 const CARGO_CLIPPY_HELP: &str = r#"Checks a package to catch common mistakes and improve your Rust code.
 
 Usage:
@@ -10,6 +17,13 @@ Usage:
 Common options:
     -h, --help               Print this message
     -V, --version            Print version info and exit
+    --explain LINT           Print the documentation of LINT and exit
+    --fix                    Automatically apply the machine-applicable suggestions
+    --allow-dirty            Apply the fixes even if the working directory has changes
+    --allow-maybe-incorrect  Also apply the suggestions that might not be correct
+    --write-baseline PATH    Record the current warnings in the baseline file PATH
+    --baseline PATH          Only report the warnings missing from the baseline file PATH
+    --message-format=sarif   Print the lint warnings as a SARIF 2.1.0 log
 
 Other options are the same as `cargo check`.
 
@@ -47,11 +61,118 @@ pub fn main() {
         return;
     }
 
+    if let Some(lint) = explain_arg(std::env::args().skip(2)) {
+        if let Err(code) = explain(&lint) {
+            std::process::exit(code);
+        }
+        return;
+    }
+
     if let Err(code) = process(std::env::args().skip(2)) {
         std::process::exit(code);
     }
 }
 
+/// Returns the lint passed to `--explain LINT` or `--explain=LINT`, stopping at `--`. The lint is
+/// empty if `--explain` has no value.
+fn explain_arg<I>(mut args: I) -> Option<String>
+where
+    I: Iterator<Item = String>,
+{
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            break;
+        } else if arg == "--explain" {
+            return Some(args.next().filter(|lint| !lint.starts_with('-')).unwrap_or_default());
+        } else if arg.starts_with("--explain=") {
+            return Some(arg["--explain=".len()..].to_string());
+        }
+    }
+    None
+}
+
+/// Removes the lines hidden from the rendered documentation, like `# let x = 1;` in Rust code
+/// blocks.
+fn render_docs(docs: &str) -> String {
+    /// Code block attributes that still denote Rust code.
+    const RUST_ATTRIBUTES: [&str; 7] = [
+        "",
+        "rust",
+        "ignore",
+        "no_run",
+        "should_panic",
+        "compile_fail",
+        "edition2018",
+    ];
+
+    let mut in_code_block = false;
+    let mut in_rust_block = false;
+    docs.lines()
+        .filter(|line| {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") {
+                in_code_block = !in_code_block;
+                in_rust_block = in_code_block
+                    && trimmed
+                        .trim_start_matches('`')
+                        .split(',')
+                        .all(|attribute| RUST_ATTRIBUTES.contains(&attribute.trim()));
+                return true;
+            }
+            !(in_rust_block && (trimmed == "#" || trimmed.starts_with("# ")))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Prints the documentation, group, default level and configuration keys of `lint`.
+fn explain(lint: &str) -> Result<(), i32> {
+    use lintlist::*;
+
+    if lint.is_empty() {
+        eprintln!("error: `--explain` expects the name of a lint, e.g. `cargo clippy --explain needless_return`");
+        return Err(1);
+    }
+
+    let name = lint.trim_start_matches("clippy::").replace('-', "_");
+    let lint = if let Some(lint) = ALL_LINTS
+        .iter()
+        .chain(DEPRECATED_LINTS.iter())
+        .find(|lint| lint.name == name)
+    {
+        lint
+    } else {
+        eprintln!("error: unknown lint `{}`", name);
+        return Err(1);
+    };
+
+    if let Some(reason) = lint.deprecation {
+        println!("clippy::{} (deprecated)\n\n{}", lint.name, reason);
+    } else {
+        println!(
+            "clippy::{} ({}, {} by default)\n\n{}",
+            lint.name,
+            lint.group,
+            lint.default_level().unwrap_or("allow"),
+            lint.desc
+        );
+    }
+    println!("\n{}", render_docs(lint.docs));
+
+    if let Some(version) = lint.version {
+        println!("\nAdded in Clippy {}.", version);
+    }
+
+    if !lint.config.is_empty() {
+        println!("\nConfiguration keys in `clippy.toml`:");
+        for key in lint.config {
+            println!("    {}", key);
+        }
+    }
+
+    Ok(())
+}
+
 fn process<I>(mut old_args: I) -> Result<(), i32>
 where
     I: Iterator<Item = String>,
@@ -65,7 +186,12 @@ where
         args.push(arg);
     }
 
-    let clippy_args: String = old_args.map(|arg| format!("{}__CLIPPY_HACKERY__", arg)).collect();
+    let clippy_args: Vec<String> = old_args.collect();
+    let workspace_lints = workspace::is_enabled(&clippy_args);
+    let clippy_args: String = clippy_args
+        .iter()
+        .map(|arg| format!("{}__CLIPPY_HACKERY__", arg))
+        .collect();
 
     let mut path = std::env::current_exe()
         .expect("current executable path invalid")
@@ -94,19 +220,232 @@ where
         args.insert(0, "+nightly".to_string());
     }
 
-    let exit_status = std::process::Command::new("cargo")
-        .args(&args)
-        .env("RUSTC_WRAPPER", path)
-        .env("CLIPPY_ARGS", clippy_args)
-        .envs(target_dir)
+    let baseline = baseline::take_baseline_args(&mut args)?;
+    let fix_options = fix::take_fix_args(&mut args);
+
+    let cargo = |args: &[String]| {
+        let mut command = std::process::Command::new("cargo");
+        command
+            .args(args)
+            .env("RUSTC_WRAPPER", &path)
+            .env("CLIPPY_ARGS", &clippy_args)
+            .envs(target_dir.clone())
+            .envs(baseline.as_ref().map(baseline::Baseline::env));
+        command
+    };
+
+    let sarif = sarif::take_sarif_arg(&mut args);
+
+    match (&baseline, fix_options) {
+        (Some(baseline::Baseline::Write { .. }), Some(_)) => {
+            eprintln!("error: `--fix` and `--write-baseline` can't be used together");
+            return Err(1);
+        },
+        (_, Some(_)) | (Some(baseline::Baseline::Write { .. }), _) if sarif => {
+            eprintln!("error: `--message-format=sarif` can't be used with `--fix` or `--write-baseline`");
+            return Err(1);
+        },
+        (_, Some(options)) => return fix::fix(&args, &options, cargo),
+        (Some(baseline::Baseline::Write { baseline, records }), None) => {
+            return baseline::write(&args, baseline, records, cargo);
+        },
+        _ if sarif && workspace_lints => {
+            eprintln!(
+                "error: `--message-format=sarif` can't report the workspace lints, don't enable \
+                 `clippy::unused_workspace_item`, `clippy::inconsistent_workspace_edition` or their groups"
+            );
+            return Err(1);
+        },
+        _ if sarif => return sarif::run(&args, cargo),
+        _ => {},
+    }
+
+    let workspace = if workspace_lints {
+        Some(workspace::Workspace::prepare(&args)?)
+    } else {
+        None
+    };
+    let exit_status = cargo(&args)
+        .envs(
+            workspace
+                .as_ref()
+                .map(|workspace| (workspace_env::FACTS_ENV, &workspace.facts_dir)),
+        )
         .spawn()
         .expect("could not run cargo")
         .wait()
         .expect("failed to wait for cargo?");
 
-    if exit_status.success() {
+    if !exit_status.success() {
+        Err(exit_status.code().unwrap_or(-1))
+    } else if let Some(workspace) = workspace {
+        workspace.report()
+    } else {
+        Ok(())
+    }
+}
+
+/// Runs `cargo metadata` on the workspace checked with `args`, without its dependencies.
+fn cargo_metadata(args: &[String]) -> Result<serde_json::Value, i32> {
+    let mut command = std::process::Command::new("cargo");
+    command.args(&["metadata", "--no-deps", "--format-version", "1"]);
+    if let Some(manifest) = manifest_path(args) {
+        command.args(&["--manifest-path", manifest]);
+    }
+
+    let output = command
+        .stderr(std::process::Stdio::inherit())
+        .output()
+        .expect("could not run cargo");
+    if !output.status.success() {
+        return Err(output.status.code().unwrap_or(-1));
+    }
+    serde_json::from_slice(&output.stdout).map_err(|e| {
+        eprintln!("error: could not parse the output of `cargo metadata`: {}", e);
+        1
+    })
+}
+
+fn manifest_path(args: &[String]) -> Option<&str> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--manifest-path" {
+            return args.next().map(String::as_str);
+        } else if arg.starts_with("--manifest-path=") {
+            return Some(&arg["--manifest-path=".len()..]);
+        }
+    }
+    None
+}
+
+/// Runs `cargo clean -p` on every package of the workspace, so that cargo checks them again
+/// instead of considering them fresh and skipping their warnings.
+fn clean_workspace(args: &[String]) -> Result<(), i32> {
+    let metadata = cargo_metadata(args)?;
+    clean_packages(args, metadata["packages"].as_array().into_iter().flatten())
+}
+
+/// Runs `cargo clean -p` on packages of `cargo metadata`, so that cargo checks them again. Only
+/// their own artifacts are removed, their dependencies aren't built again.
+fn clean_packages<'a, I>(args: &[String], packages: I) -> Result<(), i32>
+where
+    I: IntoIterator<Item = &'a serde_json::Value>,
+{
+    let specs: Vec<String> = packages
+        .into_iter()
+        .filter_map(|package| {
+            Some(format!(
+                "{}:{}",
+                package["name"].as_str()?,
+                package["version"].as_str()?
+            ))
+        })
+        .flat_map(|spec| vec!["-p".to_string(), spec])
+        .collect();
+    if specs.is_empty() {
+        return Ok(());
+    }
+
+    let status = std::process::Command::new("cargo")
+        .arg("clean")
+        .args(&specs)
+        .args(clean_args(args))
+        .status()
+        .expect("could not run cargo");
+    if status.success() {
         Ok(())
     } else {
-        Err(exit_status.code().unwrap_or(-1))
+        Err(status.code().unwrap_or(-1))
+    }
+}
+
+/// The arguments of `cargo check` that tell `cargo clean` where the artifacts are.
+fn clean_args(args: &[String]) -> Vec<String> {
+    /// Options followed by a value.
+    const OPTIONS: [&str; 3] = ["--manifest-path", "--target-dir", "--target"];
+    /// Flags without a value.
+    const FLAGS: [&str; 4] = ["--release", "--frozen", "--locked", "--offline"];
+
+    let mut clean_args = Vec::new();
+    let mut args = args.iter().take_while(|arg| *arg != "--");
+    while let Some(arg) = args.next() {
+        if FLAGS.contains(&arg.as_str()) {
+            clean_args.push(arg.clone());
+        } else if OPTIONS.contains(&arg.as_str()) {
+            clean_args.push(arg.clone());
+            clean_args.extend(args.next().cloned());
+        } else if OPTIONS.iter().any(|option| arg.starts_with(&format!("{}=", option))) {
+            clean_args.push(arg.clone());
+        }
+    }
+    clean_args
+}
+
+#[cfg(test)]
+mod test {
+    use super::{clean_args, explain_arg, render_docs};
+
+    fn explain(args: &[&str]) -> Option<String> {
+        explain_arg(args.iter().map(ToString::to_string))
+    }
+
+    #[test]
+    fn explain_args() {
+        assert_eq!(
+            explain(&["--explain", "needless_return"]),
+            Some("needless_return".into())
+        );
+        assert_eq!(explain(&["--explain=needless_return"]), Some("needless_return".into()));
+        assert_eq!(explain(&["--all-targets", "--explain=x"]), Some("x".into()));
+        assert_eq!(explain(&["--explain="]), Some(String::new()));
+        assert_eq!(explain(&["--explain"]), Some(String::new()));
+        assert_eq!(explain(&["--explain", "--", "needless_return"]), Some(String::new()));
+        assert_eq!(explain(&["--explain", "--all-targets"]), Some(String::new()));
+        assert_eq!(explain(&["--", "--explain", "needless_return"]), None);
+        assert_eq!(explain(&["--", "--explain=needless_return"]), None);
+        assert_eq!(explain(&["--all-targets"]), None);
+    }
+
+    #[test]
+    fn hidden_lines() {
+        let docs = "Example:\n```rust\n# let x = 1;\n#\nlet y = x;\n```\n# Title";
+        assert_eq!(render_docs(docs), "Example:\n```rust\nlet y = x;\n```\n# Title");
+
+        let docs = "```\n# fn f() {}\nf();\n```\n```ignore,edition2018\n# fn g() {}\n```";
+        assert_eq!(render_docs(docs), "```\nf();\n```\n```ignore,edition2018\n```");
+    }
+
+    #[test]
+    fn shown_lines() {
+        let docs = "```toml\n# a comment\nmsrv = \"1.31\"\n```\n```text\n# not rust\n```";
+        assert_eq!(render_docs(docs), docs);
+    }
+
+    #[test]
+    fn clean_options() {
+        let args: Vec<String> = [
+            "--all-targets",
+            "--release",
+            "--manifest-path",
+            "a/Cargo.toml",
+            "--target-dir=target/clippy",
+            "-p",
+            "a",
+            "--",
+            "--target",
+            "x",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+        assert_eq!(
+            clean_args(&args),
+            [
+                "--release",
+                "--manifest-path",
+                "a/Cargo.toml",
+                "--target-dir=target/clippy"
+            ]
+        );
     }
 }
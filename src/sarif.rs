@@ -0,0 +1,405 @@
+//! `cargo clippy --message-format=sarif`: prints the lint warnings as a [SARIF 2.1.0] log, for
+//! code scanning tools.
+//!
+//! [SARIF 2.1.0]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use crate::lintlist::{Lint, ALL_LINTS};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashSet};
+use std::process::{Command, Stdio};
+
+const SRCROOT: &str = "%SRCROOT%";
+
+/// Removes `--message-format=sarif` from the cargo arguments, returning whether it was passed.
+pub fn take_sarif_arg(args: &mut Vec<String>) -> bool {
+    if let Some(index) = args.iter().position(|arg| arg == "--message-format=sarif") {
+        args.remove(index);
+        true
+    } else if let Some(index) = args
+        .windows(2)
+        .position(|pair| pair[0] == "--message-format" && pair[1] == "sarif")
+    {
+        args.drain(index..index + 2);
+        true
+    } else {
+        false
+    }
+}
+
+/// Runs Clippy with JSON diagnostics and prints them as a SARIF log.
+pub fn run<F>(args: &[String], cargo: F) -> Result<(), i32>
+where
+    F: Fn(&[String]) -> Command,
+{
+    let metadata = crate::cargo_metadata(args)?;
+    let workspace_root = metadata["workspace_root"].as_str().unwrap_or_default();
+    // Crates that cargo considers fresh would be skipped, leaving their warnings out of the log.
+    crate::clean_packages(args, metadata["packages"].as_array().into_iter().flatten())?;
+
+    let mut json_args = args.to_vec();
+    json_args.push("--message-format=json".to_owned());
+    let output = cargo(&json_args)
+        .stderr(Stdio::inherit())
+        .output()
+        .expect("could not run cargo");
+
+    let mut seen = HashSet::new();
+    let mut results = Vec::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let message = match serde_json::from_str::<Value>(line) {
+            Ok(message) => message,
+            Err(_) => continue,
+        };
+        if message["reason"] != "compiler-message" {
+            continue;
+        }
+        let diagnostic = &message["message"];
+
+        // The log only has the lints, but compilation errors are still shown.
+        let code = diagnostic["code"]["code"].as_str().unwrap_or_default();
+        if !code.starts_with("clippy::") {
+            if diagnostic["level"] == "error" {
+                if let Some(rendered) = diagnostic["rendered"].as_str() {
+                    eprint!("{}", rendered);
+                }
+            }
+            continue;
+        }
+
+        // A file shared by several crates has its warnings emitted once per crate.
+        let result = result(diagnostic, code);
+        if seen.insert(result.to_string()) {
+            results.push(result);
+        }
+    }
+
+    let log = log(workspace_root, results);
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&log).expect("the SARIF log is always serializable")
+    );
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(output.status.code().unwrap_or(-1))
+    }
+}
+
+/// The SARIF log of a single run of Clippy on the workspace at `workspace_root`.
+fn log(workspace_root: &str, results: Vec<Value>) -> Value {
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": { "driver": tool() },
+            "originalUriBaseIds": {
+                SRCROOT: { "uri": directory_uri(workspace_root) },
+            },
+            "columnKind": "unicodeCodePoints",
+            "results": results,
+        }],
+    })
+}
+
+fn tool() -> Value {
+    let rules: Vec<_> = ALL_LINTS.iter().map(rule).collect();
+    json!({
+        "name": "clippy",
+        "semanticVersion": env!("CARGO_PKG_VERSION"),
+        "informationUri": "https://github.com/rust-lang/rust-clippy",
+        "rules": rules,
+    })
+}
+
+/// The `reportingDescriptor` of a lint.
+fn rule(lint: &Lint) -> Value {
+    let level = match lint.default_level().unwrap_or("allow") {
+        "deny" => "error",
+        "warn" => "warning",
+        _ => "none",
+    };
+    json!({
+        "id": format!("clippy::{}", lint.name),
+        "name": lint.name,
+        "shortDescription": { "text": lint.desc },
+        "help": {
+            "text": crate::render_docs(lint.docs),
+            "markdown": lint.docs,
+        },
+        "helpUri": format!("https://rust-lang.github.io/rust-clippy/{}/index.html#{}", docs_version(), lint.name),
+        "defaultConfiguration": {
+            "enabled": level != "none",
+            "level": if level == "none" { "warning" } else { level },
+        },
+        "properties": { "tags": [lint.group] },
+    })
+}
+
+/// The version of the lint documentation, like in the links of the diagnostics.
+fn docs_version() -> String {
+    option_env!("RUST_RELEASE_NUM").map_or("master".to_string(), |n| {
+        // extract just major + minor version and ignore patch versions
+        format!("rust-{}", n.rsplitn(2, '.').nth(1).unwrap())
+    })
+}
+
+/// The `result` of a diagnostic emitted by the lint `code`.
+fn result(diagnostic: &Value, code: &str) -> Value {
+    let spans = diagnostic["spans"].as_array().map_or(&[][..], Vec::as_slice);
+    let locations: Vec<_> = spans
+        .iter()
+        .filter(|span| span["is_primary"] == true)
+        .filter_map(location)
+        .collect();
+    let related_locations: Vec<_> = spans
+        .iter()
+        .filter(|span| span["is_primary"] != true)
+        .filter_map(location)
+        .collect();
+
+    let mut result = json!({
+        "ruleId": code,
+        "level": if diagnostic["level"] == "error" { "error" } else { "warning" },
+        "message": { "text": diagnostic["message"] },
+        "locations": locations,
+    });
+    if let Some(index) = ALL_LINTS
+        .iter()
+        .position(|lint| code.trim_start_matches("clippy::") == lint.name)
+    {
+        result["ruleIndex"] = json!(index);
+    }
+    if !related_locations.is_empty() {
+        result["relatedLocations"] = json!(related_locations);
+    }
+
+    let fixes: Vec<_> = diagnostic["children"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(fix)
+        .collect();
+    if !fixes.is_empty() {
+        result["fixes"] = json!(fixes);
+    }
+    result
+}
+
+/// A `fix` for the suggestions of a sub-diagnostic, if it has any.
+fn fix(child: &Value) -> Option<Value> {
+    let mut changes: BTreeMap<&str, Vec<Value>> = BTreeMap::new();
+    let mut applicability = None;
+    for span in child["spans"].as_array().into_iter().flatten() {
+        let replacement = match span["suggested_replacement"].as_str() {
+            Some(replacement) => replacement,
+            None => continue,
+        };
+        let file = span["file_name"].as_str()?;
+        applicability = span["suggestion_applicability"].as_str();
+        changes.entry(file).or_default().push(json!({
+            "deletedRegion": region(span),
+            "insertedContent": { "text": replacement },
+        }));
+    }
+    if changes.is_empty() {
+        return None;
+    }
+
+    let artifact_changes: Vec<_> = changes
+        .into_iter()
+        .map(|(file, replacements)| {
+            json!({
+                "artifactLocation": artifact_location(file),
+                "replacements": replacements,
+            })
+        })
+        .collect();
+    Some(json!({
+        "description": { "text": child["message"] },
+        "artifactChanges": artifact_changes,
+        "properties": { "applicability": applicability },
+    }))
+}
+
+fn location(span: &Value) -> Option<Value> {
+    let file = span["file_name"].as_str()?;
+    // Spans of macros defined outside of the crate have names like `<::std::macros::panic macros>`.
+    if file.starts_with('<') {
+        return None;
+    }
+    Some(json!({
+        "physicalLocation": {
+            "artifactLocation": artifact_location(file),
+            "region": region(span),
+        },
+    }))
+}
+
+fn region(span: &Value) -> Value {
+    json!({
+        "startLine": span["line_start"],
+        "startColumn": span["column_start"],
+        "endLine": span["line_end"],
+        "endColumn": span["column_end"],
+    })
+}
+
+/// Files of the workspace members are relative to the workspace root, the others are absolute.
+fn artifact_location(file: &str) -> Value {
+    if std::path::Path::new(file).is_absolute() {
+        json!({ "uri": file_uri(file) })
+    } else {
+        json!({ "uri": percent_encode(&file.replace('\\', "/")), "uriBaseId": SRCROOT })
+    }
+}
+
+fn directory_uri(path: &str) -> String {
+    let mut uri = file_uri(path);
+    if !uri.ends_with('/') {
+        uri.push('/');
+    }
+    uri
+}
+
+fn file_uri(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    if path.starts_with('/') {
+        format!("file://{}", percent_encode(&path))
+    } else {
+        // Windows paths like `C:/foo`.
+        format!("file:///{}", percent_encode(&path))
+    }
+}
+
+fn percent_encode(path: &str) -> String {
+    path.bytes()
+        .map(|byte| match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => {
+                char::from(byte).to_string()
+            },
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{artifact_location, directory_uri, file_uri, log, percent_encode, result, take_sarif_arg};
+    use crate::lintlist::ALL_LINTS;
+    use serde_json::json;
+
+    fn span(file: &str, is_primary: bool) -> serde_json::Value {
+        json!({
+            "file_name": file,
+            "is_primary": is_primary,
+            "line_start": 2,
+            "line_end": 2,
+            "column_start": 5,
+            "column_end": 13,
+            "suggested_replacement": null,
+            "suggestion_applicability": null,
+        })
+    }
+
+    #[test]
+    fn sarif_arg() {
+        let mut args = vec!["--message-format=sarif".to_string(), "--all-targets".to_string()];
+        assert!(take_sarif_arg(&mut args));
+        assert_eq!(args, ["--all-targets"]);
+
+        let mut args = vec!["--message-format".to_string(), "sarif".to_string()];
+        assert!(take_sarif_arg(&mut args));
+        assert!(args.is_empty());
+
+        let mut args = vec!["--message-format".to_string(), "json".to_string()];
+        assert!(!take_sarif_arg(&mut args));
+        assert_eq!(args.len(), 2);
+    }
+
+    #[test]
+    fn log_shape() {
+        let log = log(
+            "/home/user/my project",
+            vec![json!({ "ruleId": "clippy::needless_return" })],
+        );
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(log["runs"].as_array().map(Vec::len), Some(1));
+
+        let run = &log["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "clippy");
+        assert_eq!(
+            run["originalUriBaseIds"]["%SRCROOT%"]["uri"],
+            "file:///home/user/my%20project/"
+        );
+        assert_eq!(run["results"][0]["ruleId"], "clippy::needless_return");
+
+        // a rule per lint, in the order of `ruleIndex`
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), ALL_LINTS.len());
+        for (rule, lint) in rules.iter().zip(ALL_LINTS.iter()) {
+            assert_eq!(rule["id"], format!("clippy::{}", lint.name));
+            assert_eq!(rule["name"], lint.name);
+        }
+    }
+
+    #[test]
+    fn result_of_diagnostic() {
+        let index = ALL_LINTS
+            .iter()
+            .position(|lint| lint.name == "needless_return")
+            .unwrap();
+        let mut suggestion = span("src/main.rs", true);
+        suggestion["suggested_replacement"] = json!("x");
+        suggestion["suggestion_applicability"] = json!("MachineApplicable");
+        let diagnostic = json!({
+            "message": "unneeded return statement",
+            "level": "warning",
+            "spans": [span("src/main.rs", true), span("src/lib.rs", false)],
+            "children": [{ "message": "remove `return`", "spans": [suggestion] }],
+        });
+        let result = result(&diagnostic, "clippy::needless_return");
+        assert_eq!(result["ruleId"], "clippy::needless_return");
+        assert_eq!(result["ruleIndex"], index);
+        assert_eq!(result["level"], "warning");
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["artifactLocation"],
+            json!({ "uri": "src/main.rs", "uriBaseId": "%SRCROOT%" })
+        );
+        assert_eq!(result["locations"][0]["physicalLocation"]["region"]["startColumn"], 5);
+        assert_eq!(
+            result["relatedLocations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "src/lib.rs"
+        );
+
+        let fix = &result["fixes"][0];
+        assert_eq!(fix["description"]["text"], "remove `return`");
+        assert_eq!(fix["properties"]["applicability"], "MachineApplicable");
+        assert_eq!(
+            fix["artifactChanges"][0]["replacements"][0]["insertedContent"]["text"],
+            "x"
+        );
+    }
+
+    #[test]
+    fn uris() {
+        assert_eq!(
+            artifact_location("src\\bin\\main.rs"),
+            json!({ "uri": "src/bin/main.rs", "uriBaseId": "%SRCROOT%" })
+        );
+        assert_eq!(
+            artifact_location("/registry/serde-1.0.0/src/lib.rs"),
+            json!({ "uri": "file:///registry/serde-1.0.0/src/lib.rs" })
+        );
+        assert_eq!(file_uri("C:\\Users\\me\\lib.rs"), "file:///C:/Users/me/lib.rs");
+        assert_eq!(directory_uri("/workspace"), "file:///workspace/");
+        assert_eq!(directory_uri("/workspace/"), "file:///workspace/");
+    }
+
+    #[test]
+    fn percent_encoding() {
+        assert_eq!(percent_encode("src/my-file_1.rs"), "src/my-file_1.rs");
+        assert_eq!(percent_encode("my file#1%.rs"), "my%20file%231%25.rs");
+        assert_eq!(percent_encode("é.rs"), "%C3%A9.rs");
+    }
+}
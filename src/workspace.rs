@@ -0,0 +1,576 @@
+//! The lints of the whole workspace, reported by `cargo clippy` once every crate is checked. The
+//! drivers write the facts these lints need about each crate, see `clippy_lints/src/workspace.rs`.
+//! Nothing is collected unless one of these lints is enabled on the command line, as `cargo clippy`
+//! can't see the lint attributes of the crates before checking them.
+
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+const LINT_DOCS: &str = "https://rust-lang.github.io/rust-clippy/master/index.html";
+
+pub struct Workspace {
+    pub facts_dir: PathBuf,
+    root: PathBuf,
+    /// The names of the members by the directory of their `Cargo.toml`.
+    members: BTreeMap<PathBuf, String>,
+}
+
+/// The facts a driver wrote about a crate.
+struct Facts {
+    value: Value,
+    modified: SystemTime,
+}
+
+impl Facts {
+    fn manifest_dir(&self) -> Option<&Path> {
+        self.value["manifest_dir"].as_str().map(Path::new)
+    }
+
+    fn level(&self, field: &str) -> &str {
+        self.value[field].as_str().unwrap_or("allow")
+    }
+}
+
+impl Workspace {
+    /// Reads the members of the workspace. The crates are only checked when cargo considers them
+    /// dirty, so the members checked with `args` that have no facts, checked before they were
+    /// collected, are marked dirty.
+    pub fn prepare(args: &[String]) -> Result<Self, i32> {
+        let metadata = crate::cargo_metadata(args)?;
+        let path = |value: &Value| PathBuf::from(value.as_str().unwrap_or_default());
+        let workspace = Self {
+            facts_dir: path(&metadata["target_directory"]).join("clippy-workspace"),
+            root: path(&metadata["workspace_root"]),
+            members: metadata["packages"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|package| {
+                    let dir = path(&package["manifest_path"]).parent()?.to_path_buf();
+                    Some((dir, package["name"].as_str()?.to_string()))
+                })
+                .collect(),
+        };
+
+        let checked: HashSet<PathBuf> = workspace
+            .read_facts()
+            .iter()
+            .filter_map(|facts| facts.manifest_dir().map(Path::to_path_buf))
+            .collect();
+        let current_dir = std::env::current_dir().unwrap_or_default();
+        let selected = workspace.selected(args, &current_dir);
+        let unchecked = metadata["packages"].as_array().into_iter().flatten().filter(|package| {
+            path(&package["manifest_path"])
+                .parent()
+                .map_or(false, |dir| selected.contains(dir) && !checked.contains(dir))
+        });
+        crate::clean_packages(args, unchecked)?;
+
+        Ok(workspace)
+    }
+
+    /// The directories of the members that `cargo check` checks with `args`, run in `current_dir`.
+    fn selected(&self, args: &[String], current_dir: &Path) -> HashSet<&Path> {
+        let members = self.members.iter().map(|(dir, name)| (dir.as_path(), name.as_str()));
+        let packages = option_values(args, &["-p", "--package"]);
+        if !packages.is_empty() {
+            return members
+                .filter(|(_, name)| packages.contains(name))
+                .map(|(dir, _)| dir)
+                .collect();
+        }
+        if args.iter().any(|arg| arg == "--workspace" || arg == "--all") {
+            let excluded = option_values(args, &["--exclude"]);
+            return members
+                .filter(|(_, name)| !excluded.contains(name))
+                .map(|(dir, _)| dir)
+                .collect();
+        }
+
+        // the package of the nearest `Cargo.toml`, or every member for a virtual manifest
+        let manifest_dir = match crate::manifest_path(args) {
+            Some(manifest) => current_dir.join(manifest).parent().map(Path::to_path_buf),
+            None => Some(current_dir.to_path_buf()),
+        };
+        let current = manifest_dir.and_then(|dir| {
+            dir.ancestors()
+                .find_map(|dir| self.members.keys().find(|member| *member == dir))
+                .map(PathBuf::as_path)
+        });
+        match current {
+            Some(dir) => Some(dir).into_iter().collect(),
+            None => members.map(|(dir, _)| dir).collect(),
+        }
+    }
+
+    /// Reports the lints of the workspace, returning an error if one of them is denied.
+    pub fn report(&self) -> Result<(), i32> {
+        let facts = self.read_facts();
+        let mut errors = 0;
+        let mut emit = |diagnostic: Diagnostic| {
+            if diagnostic.level == "deny" || diagnostic.level == "forbid" {
+                errors += 1;
+            }
+            diagnostic.emit();
+        };
+
+        unused_items(&facts).into_iter().for_each(&mut emit);
+        self.inconsistent_editions(&facts).into_iter().for_each(&mut emit);
+
+        match errors {
+            0 => Ok(()),
+            1 => {
+                eprintln!("error: aborting due to previous error\n");
+                Err(1)
+            },
+            _ => {
+                eprintln!("error: aborting due to {} previous errors\n", errors);
+                Err(1)
+            },
+        }
+    }
+
+    /// Reads the facts of the members of the workspace.
+    fn read_facts(&self) -> Vec<Facts> {
+        let entries = match fs::read_dir(&self.facts_dir) {
+            Ok(entries) => entries,
+            Err(_) => return Vec::new(),
+        };
+        newest(
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let modified = entry.metadata().and_then(|metadata| metadata.modified()).ok()?;
+                    let value = serde_json::from_slice(&fs::read(entry.path()).ok()?).ok()?;
+                    Some(Facts { value, modified })
+                })
+                .filter(|facts| facts.manifest_dir().map_or(false, |dir| self.members.contains_key(dir))),
+        )
+    }
+
+    /// The packages whose edition differs from the edition of most packages.
+    fn inconsistent_editions<'a>(&self, facts: &'a [Facts]) -> Vec<Diagnostic<'a>> {
+        // the crates of a package have the same edition
+        let mut packages: BTreeMap<&Path, (&str, &str)> = BTreeMap::new();
+        for facts in facts {
+            if let (Some(dir), Some(edition)) = (facts.manifest_dir(), facts.value["edition"].as_str()) {
+                let level = facts.level("edition_level");
+                let package = packages.entry(dir).or_insert((edition, level));
+                package.1 = most_severe(package.1, level);
+            }
+        }
+
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for (edition, _) in packages.values() {
+            *counts.entry(edition).or_default() += 1;
+        }
+        // ties go to the newest edition
+        let (common, count) = match counts.iter().max_by_key(|(edition, count)| (*count, *edition)) {
+            Some((common, count)) => (*common, *count),
+            None => return Vec::new(),
+        };
+
+        packages
+            .into_iter()
+            .filter(|(_, (edition, level))| *edition != common && *level != "allow")
+            .map(|(dir, (edition, level))| {
+                let manifest = dir.join("Cargo.toml");
+                let content = fs::read_to_string(&manifest).unwrap_or_default();
+                let key_line = find_line(&content, |line| line.starts_with("edition") && line.contains('='));
+                let line = key_line.or_else(|| find_line(&content, |line| line == "[package]"));
+                let mut notes = Vec::new();
+                if key_line.is_none() {
+                    notes.push(format!(
+                        "the edition is {} when `Cargo.toml` doesn't set `edition`",
+                        edition
+                    ));
+                }
+                Diagnostic {
+                    lint: "inconsistent_workspace_edition",
+                    level,
+                    message: format!(
+                        "package `{}` uses the {} edition, unlike the {} packages of the workspace using the {} edition",
+                        self.members[dir], edition, count, common
+                    ),
+                    file: self.relative(&manifest),
+                    line: line.unwrap_or(1),
+                    column: 1,
+                    notes,
+                }
+            })
+            .collect()
+    }
+
+    /// Displays paths relative to the root of the workspace, like rustc.
+    fn relative(&self, path: &Path) -> String {
+        path.strip_prefix(&self.root).unwrap_or(path).display().to_string()
+    }
+}
+
+/// The public items that no crate of the workspace uses.
+/// The newest facts of each crate. A crate checked with other features or profiles has older facts,
+/// which describe code that may have changed since.
+fn newest(facts: impl IntoIterator<Item = Facts>) -> Vec<Facts> {
+    let mut newest: BTreeMap<(String, String, String, bool), Facts> = BTreeMap::new();
+    for facts in facts {
+        let field = |name: &str| facts.value[name].as_str().unwrap_or_default().to_string();
+        let key = (
+            field("manifest_dir"),
+            field("crate"),
+            field("root"),
+            facts.value["test"] == true,
+        );
+        if newest.get(&key).map_or(true, |newest| facts.modified > newest.modified) {
+            newest.insert(key, facts);
+        }
+    }
+    newest.into_iter().map(|(_, facts)| facts).collect()
+}
+
+fn unused_items(facts: &[Facts]) -> Vec<Diagnostic<'_>> {
+    let used: HashSet<&str> = facts
+        .iter()
+        .filter_map(|facts| facts.value["uses"].as_array())
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+
+    facts
+        .iter()
+        .filter(|facts| facts.value["library"] == true)
+        .filter_map(|facts| facts.value["exports"].as_array())
+        .flatten()
+        .filter(|export| export["id"].as_str().map_or(false, |id| !used.contains(id)))
+        .filter_map(|export| {
+            Some(Diagnostic {
+                lint: "unused_workspace_item",
+                level: export["level"].as_str()?,
+                message: format!(
+                    "public item `{}` is never used in the workspace",
+                    export["path"].as_str()?
+                ),
+                file: export["file"].as_str()?.to_string(),
+                line: export["line"].as_u64()?,
+                column: export["column"].as_u64()?,
+                notes: Vec::new(),
+            })
+        })
+        .collect()
+}
+
+/// Whether `clippy_args`, the arguments of `cargo clippy` after `--`, enable one of the lints of
+/// the workspace, by their name or their group.
+pub fn is_enabled(clippy_args: &[String]) -> bool {
+    const NAMES: [&str; 4] = [
+        "clippy::unused_workspace_item",
+        "clippy::inconsistent_workspace_edition",
+        "clippy::restriction",
+        "clippy::cargo",
+    ];
+    option_values(clippy_args, &["-W", "-D", "-F", "--warn", "--deny", "--forbid"])
+        .iter()
+        .any(|lint| NAMES.contains(&lint.replace('-', "_").as_str()))
+}
+
+/// The values of the options `flags` in `args`, given as `-p foo`, `-pfoo`, `--package foo` or
+/// `--package=foo`.
+fn option_values<'a>(args: &'a [String], flags: &[&str]) -> Vec<&'a str> {
+    let mut values = Vec::new();
+    let mut args = args.iter().map(String::as_str);
+    while let Some(arg) = args.next() {
+        for flag in flags {
+            if arg == *flag {
+                values.extend(args.next());
+            } else if arg.starts_with(flag) {
+                let value = &arg[flag.len()..];
+                if !flag.starts_with("--") {
+                    values.push(value);
+                } else if value.starts_with('=') {
+                    values.push(&value[1..]);
+                }
+            }
+        }
+    }
+    values
+}
+
+fn most_severe<'a>(a: &'a str, b: &'a str) -> &'a str {
+    const LEVELS: [&str; 4] = ["allow", "warn", "deny", "forbid"];
+    let severity = |level| LEVELS.iter().position(|l| *l == level);
+    if severity(b) > severity(a) {
+        b
+    } else {
+        a
+    }
+}
+
+/// The 1-based number of the first line of `content` matching `predicate`, once trimmed.
+fn find_line<F: Fn(&str) -> bool>(content: &str, predicate: F) -> Option<u64> {
+    content
+        .lines()
+        .position(|line| predicate(line.trim()))
+        .map(|index| index as u64 + 1)
+}
+
+struct Diagnostic<'a> {
+    lint: &'static str,
+    level: &'a str,
+    message: String,
+    file: String,
+    line: u64,
+    column: u64,
+    notes: Vec<String>,
+}
+
+impl Diagnostic<'_> {
+    /// Prints the diagnostic like rustc.
+    fn emit(&self) {
+        let kind = if self.level == "warn" { "warning" } else { "error" };
+        eprintln!("{}: {}", kind, self.message);
+        eprintln!("  --> {}:{}:{}", self.file, self.line, self.column);
+        eprintln!("   |");
+        for note in &self.notes {
+            eprintln!("   = note: {}", note);
+        }
+        eprintln!("   = note: `clippy::{}` is set to `{}` here", self.lint, self.level);
+        eprintln!("   = help: for further information visit {}#{}\n", LINT_DOCS, self.lint);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{find_line, is_enabled, most_severe, newest, option_values, unused_items, Facts, Workspace};
+    use serde_json::{json, Value};
+    use std::path::{Path, PathBuf};
+    use std::time::{Duration, SystemTime};
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    fn facts(value: Value, modified: u64) -> Facts {
+        Facts {
+            value,
+            modified: SystemTime::UNIX_EPOCH + Duration::from_secs(modified),
+        }
+    }
+
+    fn workspace(members: &[(&str, &str)]) -> Workspace {
+        Workspace {
+            facts_dir: PathBuf::from("/ws/target/clippy-workspace"),
+            root: PathBuf::from("/ws"),
+            members: members
+                .iter()
+                .map(|(dir, name)| (PathBuf::from(dir), name.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn enabled() {
+        assert!(!is_enabled(&[]));
+        assert!(!is_enabled(&args(&["-D", "warnings", "-W", "clippy::pedantic"])));
+        assert!(!is_enabled(&args(&["-A", "clippy::unused_workspace_item"])));
+        assert!(is_enabled(&args(&["-W", "clippy::unused_workspace_item"])));
+        assert!(is_enabled(&args(&["-Dclippy::inconsistent-workspace-edition"])));
+        assert!(is_enabled(&args(&["--forbid=clippy::cargo"])));
+        assert!(is_enabled(&args(&["--warn", "clippy::restriction"])));
+    }
+
+    #[test]
+    fn options() {
+        let args = args(&[
+            "-p",
+            "a",
+            "-pb",
+            "--package",
+            "c",
+            "--package=d",
+            "--packages",
+            "e",
+            "--release",
+        ]);
+        assert_eq!(option_values(&args, &["-p", "--package"]), ["a", "b", "c", "d"]);
+        assert!(option_values(&args, &["--exclude"]).is_empty());
+    }
+
+    #[test]
+    fn selected() {
+        let workspace = workspace(&[("/ws/a", "a"), ("/ws/b", "b"), ("/ws/c", "c")]);
+        let selected = |cargo_args: &[&str], current_dir: &str| {
+            let mut selected: Vec<_> = workspace
+                .selected(&args(cargo_args), Path::new(current_dir))
+                .into_iter()
+                .map(|dir| dir.to_str().unwrap())
+                .collect();
+            selected.sort();
+            selected
+        };
+
+        assert_eq!(selected(&["-p", "a", "--package=c"], "/ws/b"), ["/ws/a", "/ws/c"]);
+        assert_eq!(
+            selected(&["--workspace", "--exclude", "b"], "/ws/b"),
+            ["/ws/a", "/ws/c"]
+        );
+        assert_eq!(selected(&["--all"], "/ws/b"), ["/ws/a", "/ws/b", "/ws/c"]);
+        assert_eq!(selected(&[], "/ws/b/src"), ["/ws/b"]);
+        assert_eq!(selected(&["--manifest-path", "c/Cargo.toml"], "/ws"), ["/ws/c"]);
+        // a virtual manifest checks every member
+        assert_eq!(selected(&[], "/ws"), ["/ws/a", "/ws/b", "/ws/c"]);
+    }
+
+    #[test]
+    fn unused() {
+        let export = |id: &str, level: &str| {
+            json!({
+                "id": id,
+                "path": format!("util::{}", id),
+                "file": "util/src/lib.rs",
+                "line": 3,
+                "column": 1,
+                "level": level,
+            })
+        };
+        let facts = [
+            facts(
+                json!({
+                    "crate": "util",
+                    "manifest_dir": "/ws/util",
+                    "library": true,
+                    "exports": [export("used", "warn"), export("unused", "deny"), export("self_used", "warn")],
+                    "uses": ["self_used"],
+                }),
+                2,
+            ),
+            facts(
+                json!({
+                    "crate": "app",
+                    "manifest_dir": "/ws/app",
+                    "library": false,
+                    "exports": [export("binary", "warn")],
+                    "uses": ["used"],
+                }),
+                3,
+            ),
+        ];
+
+        let unused = unused_items(&facts);
+        assert_eq!(unused.len(), 1);
+        assert_eq!(unused[0].lint, "unused_workspace_item");
+        assert_eq!(unused[0].level, "deny");
+        assert_eq!(
+            unused[0].message,
+            "public item `util::unused` is never used in the workspace"
+        );
+        assert_eq!(
+            (unused[0].file.as_str(), unused[0].line, unused[0].column),
+            ("util/src/lib.rs", 3, 1)
+        );
+    }
+
+    #[test]
+    fn newest_facts() {
+        let crate_facts = |krate: &str, root: &str, test: bool, uses: &[&str], modified: u64| {
+            facts(
+                json!({
+                    "crate": krate,
+                    "manifest_dir": "/ws/app",
+                    "root": root,
+                    "test": test,
+                    "uses": uses,
+                }),
+                modified,
+            )
+        };
+        let facts = newest(vec![
+            // checked again since, with other features
+            crate_facts("app", "app/src/main.rs", false, &["old"], 1),
+            crate_facts("app", "app/src/main.rs", false, &["new"], 2),
+            // the lib, its tests and the binary have the same name
+            crate_facts("app", "app/src/main.rs", true, &["main_test"], 0),
+            crate_facts("app", "app/src/lib.rs", false, &["lib"], 0),
+            crate_facts("app", "app/src/lib.rs", true, &["lib_test"], 0),
+            crate_facts("tests", "app/tests/tests.rs", true, &["integration"], 0),
+        ]);
+
+        let mut uses: Vec<_> = facts
+            .iter()
+            .flat_map(|facts| facts.value["uses"].as_array().unwrap())
+            .map(|id| id.as_str().unwrap())
+            .collect();
+        uses.sort();
+        assert_eq!(uses, ["integration", "lib", "lib_test", "main_test", "new"]);
+    }
+
+    #[test]
+    fn inconsistent_editions() {
+        let crate_facts = |dir: &str, edition: &str, level: &str| {
+            facts(
+                json!({ "manifest_dir": dir, "edition": edition, "edition_level": level }),
+                0,
+            )
+        };
+        let workspace = workspace(&[
+            ("/ws/a", "a"),
+            ("/ws/b", "b"),
+            ("/ws/c", "c"),
+            ("/ws/d", "d"),
+            ("/ws/e", "e"),
+        ]);
+        let facts = [
+            crate_facts("/ws/a", "2018", "warn"),
+            crate_facts("/ws/b", "2018", "warn"),
+            crate_facts("/ws/c", "2018", "allow"),
+            // the level of the package is the most severe of its crates
+            crate_facts("/ws/d", "2015", "allow"),
+            crate_facts("/ws/d", "2015", "deny"),
+            crate_facts("/ws/e", "2015", "allow"),
+        ];
+
+        let inconsistent = workspace.inconsistent_editions(&facts);
+        assert_eq!(inconsistent.len(), 1);
+        assert_eq!(inconsistent[0].lint, "inconsistent_workspace_edition");
+        assert_eq!(inconsistent[0].level, "deny");
+        assert_eq!(
+            inconsistent[0].message,
+            "package `d` uses the 2015 edition, unlike the 3 packages of the workspace using the 2018 edition"
+        );
+        assert_eq!(inconsistent[0].file, "d/Cargo.toml");
+        assert_eq!(
+            inconsistent[0].notes,
+            ["the edition is 2015 when `Cargo.toml` doesn't set `edition`"]
+        );
+
+        // ties go to the newest edition
+        let facts = [
+            crate_facts("/ws/a", "2015", "warn"),
+            crate_facts("/ws/b", "2018", "warn"),
+        ];
+        let inconsistent = workspace.inconsistent_editions(&facts);
+        assert_eq!(inconsistent.len(), 1);
+        assert!(inconsistent[0].message.starts_with("package `a` uses the 2015 edition"));
+
+        assert!(workspace.inconsistent_editions(&[]).is_empty());
+    }
+
+    #[test]
+    fn severity() {
+        assert_eq!(most_severe("allow", "warn"), "warn");
+        assert_eq!(most_severe("deny", "warn"), "deny");
+        assert_eq!(most_severe("forbid", "deny"), "forbid");
+        assert_eq!(most_severe("warn", "warn"), "warn");
+    }
+
+    #[test]
+    fn line() {
+        let manifest = "[package]\nname = \"a\"\n  edition = \"2018\"\n\n[dependencies]\n";
+        let is_edition = |line: &str| line.starts_with("edition");
+        assert_eq!(find_line(manifest, |line| line == "[package]"), Some(1));
+        assert_eq!(find_line(manifest, is_edition), Some(3));
+        assert_eq!(find_line(manifest, |line| line == "[features]"), None);
+        assert_eq!(find_line("", is_edition), None);
+    }
+}
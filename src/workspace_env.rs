@@ -0,0 +1,5 @@
+//! Shared by `cargo clippy` and the drivers, see `src/workspace.rs`.
+
+/// The environment variable of the directory the drivers write the facts of the workspace lints
+/// to, only set when one of these lints is enabled.
+pub const FACTS_ENV: &str = "CLIPPY_WORKSPACE_FACTS";
@@ -3,11 +3,21 @@
 use compiletest_rs as compiletest;
 extern crate test;
 
-use std::env::{set_var, var};
+use rustfix::diagnostics::Diagnostic;
+use rustfix::{CodeFix, Filter};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::env::{set_var, var, var_os};
 use std::ffi::OsStr;
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[allow(dead_code)]
+#[path = "../src/lintlist/mod.rs"]
+mod lintlist;
 
 #[must_use]
 fn clippy_driver_path() -> PathBuf {
@@ -152,6 +162,308 @@ fn run_ui_toml() {
     }
 }
 
+/// What the ui tests exercise of a lint.
+#[derive(Debug, Default, Serialize)]
+struct LintCoverage {
+    diagnostics: usize,
+    suggestions: usize,
+    machine_applicable: usize,
+    /// Whether a test with a `.fixed` file checks the machine applicable suggestions.
+    fixed: bool,
+    /// The diagnostics and suggestions of each test emitting the lint.
+    tests: BTreeMap<String, (usize, usize)>,
+}
+
+/// Runs the driver on every ui test with JSON diagnostics, writes the hits of each lint to
+/// `report`, and prints the lints and tests that aren't covered.
+fn run_coverage(report: &Path) {
+    let config = config("ui", "tests/ui".into());
+    let flags = config.target_rustcflags.clone().unwrap_or_default();
+    let out_dir = config.build_base.join("coverage");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let mut lints: BTreeMap<String, LintCoverage> = BTreeMap::new();
+    let mut without_lints = Vec::new();
+    for file in &ui_tests(&config) {
+        let test = file.strip_prefix(&config.src_base).unwrap().display().to_string();
+        let has_fixed = file.with_extension("fixed").exists();
+        for (lint, suggestions, machine_applicable) in test_diagnostics(file, &flags, &out_dir) {
+            let coverage = lints.entry(lint).or_default();
+            coverage.diagnostics += 1;
+            coverage.suggestions += suggestions;
+            coverage.machine_applicable += machine_applicable;
+            coverage.fixed |= has_fixed && machine_applicable > 0;
+            let hits = coverage.tests.entry(test.clone()).or_default();
+            hits.0 += 1;
+            hits.1 += suggestions;
+        }
+
+        // a test without `.stderr` emits nothing at all
+        let stderr = fs::read_to_string(file.with_extension("stderr")).unwrap_or_default();
+        if !stderr.contains("clippy::") {
+            without_lints.push(test);
+        }
+    }
+
+    fs::write(report, serde_json::to_string_pretty(&lints).unwrap()).unwrap();
+
+    println!("\nlints without any diagnostic in the ui tests:");
+    for lint in lintlist::ALL_LINTS.iter().filter(|lint| lint.deprecation.is_none()) {
+        if !lints.contains_key(lint.name) {
+            println!("    {}", lint.name);
+        }
+    }
+    println!("\nlints with machine applicable suggestions not checked by a `.fixed` file:");
+    for (lint, coverage) in &lints {
+        if coverage.machine_applicable > 0 && !coverage.fixed {
+            println!("    {} ({} suggestions)", lint, coverage.machine_applicable);
+        }
+    }
+    println!("\nui tests without a `.stderr`, or whose `.stderr` contains no Clippy lint:");
+    for test in &without_lints {
+        println!("    {}", test);
+    }
+    println!("\nthe hits of each lint are in {}", report.display());
+}
+
+/// The ui tests selected by `TESTNAME`.
+fn ui_tests(config: &compiletest::Config) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    collect_ui_tests(&config.src_base, &mut files);
+    files.sort();
+    if let Some(filter) = &config.filter {
+        files.retain(|file| file.to_string_lossy().contains(filter.as_str()));
+    }
+    files
+}
+
+fn collect_ui_tests(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            if path.file_name() != Some(OsStr::new("auxiliary")) {
+                collect_ui_tests(&path, files);
+            }
+        } else if path.extension() == Some(OsStr::new("rs")) {
+            files.push(path);
+        }
+    }
+}
+
+/// Compiles `source` with the headers of the ui test `test` and returns its JSON diagnostics.
+/// `source` is either the test or a fixed copy of it.
+fn ui_diagnostics(test: &Path, source: &Path, flags: &str, out_dir: &Path, extra_args: &[&str]) -> Vec<Value> {
+    let aux_dir = out_dir.join(test.file_stem().unwrap());
+    fs::create_dir_all(&aux_dir).unwrap();
+
+    let mut cmd = Command::new(clippy_driver_path());
+    cmd.args(flags.split_whitespace())
+        .args(extra_args)
+        .arg("-L")
+        .arg(&aux_dir)
+        .arg("--error-format=json")
+        .arg("--emit=metadata")
+        .arg("--out-dir")
+        .arg(&aux_dir)
+        .arg(source);
+    for line in fs::read_to_string(test).unwrap().lines() {
+        if line.starts_with("// compile-flags:") {
+            cmd.args(line["// compile-flags:".len()..].split_whitespace());
+        } else if line.starts_with("// aux-build:") {
+            let aux = test
+                .parent()
+                .unwrap()
+                .join("auxiliary")
+                .join(line["// aux-build:".len()..].trim());
+            build_aux(&aux, flags, &aux_dir);
+        }
+    }
+
+    let output = cmd.output().unwrap();
+    String::from_utf8_lossy(&output.stderr)
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+/// The name of the Clippy lint emitting `diag`, without the `clippy::` prefix.
+fn clippy_lint(diag: &Value) -> Option<&str> {
+    let lint = diag["code"]["code"].as_str()?;
+    if lint.starts_with("clippy::") {
+        Some(&lint["clippy::".len()..])
+    } else {
+        None
+    }
+}
+
+/// Identifies an error independently of its position, which the suggestions may change.
+fn error_message(diag: &Value) -> String {
+    format!(
+        "{} {}",
+        diag["code"]["code"].as_str().unwrap_or_default(),
+        diag["message"].as_str().unwrap_or_default()
+    )
+}
+
+/// Compiles a ui test and returns, for each Clippy diagnostic, the lint name, the number of
+/// suggestions, and the number of machine applicable ones.
+fn test_diagnostics(file: &Path, flags: &str, out_dir: &Path) -> Vec<(String, usize, usize)> {
+    ui_diagnostics(file, file, flags, out_dir, &[])
+        .iter()
+        .filter_map(|diag| {
+            let lint = clippy_lint(diag)?;
+            let spans = diag["children"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .chain(Some(diag))
+                .flat_map(|diag| diag["spans"].as_array().into_iter().flatten());
+            let (mut suggestions, mut machine_applicable) = (0, 0);
+            for span in spans.filter(|span| span["suggested_replacement"].is_string()) {
+                suggestions += 1;
+                if span["suggestion_applicability"] == "MachineApplicable" {
+                    machine_applicable += 1;
+                }
+            }
+            Some((lint.to_string(), suggestions, machine_applicable))
+        })
+        .collect()
+}
+
+/// Applies the machine applicable suggestions of the Clippy lints in the ui tests that don't
+/// `run-rustfix`, and checks that the fixed code compiles and doesn't trigger the fixed lints
+/// again. Enabled by setting `CLIPPY_RUSTFIX_CHECK`.
+fn run_rustfix_checks() {
+    let config = config("ui", "tests/ui".into());
+    let flags = config.target_rustcflags.clone().unwrap_or_default();
+    let out_dir = config.build_base.join("rustfix");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let failures: Vec<String> = ui_tests(&config)
+        .iter()
+        .filter_map(|file| check_suggestions(file, &flags, &out_dir).err())
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "applying the machine applicable suggestions broke {} tests:\n\n{}\n\
+         Fix the suggestions, or add `// no-rustfix-check` to tests exercising known bugs.",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+fn check_suggestions(file: &Path, flags: &str, out_dir: &Path) -> Result<(), String> {
+    let source = fs::read_to_string(file).unwrap();
+    // the `.fixed` file already checks the suggestions
+    if source.contains("// run-rustfix") || source.contains("// no-rustfix-check") {
+        return Ok(());
+    }
+    // suggestions are rendered as help messages, the tests without any aren't compiled again
+    let stderr = fs::read_to_string(file.with_extension("stderr")).unwrap_or_default();
+    if !stderr.contains("help:") {
+        return Ok(());
+    }
+
+    let mut fix = CodeFix::new(&source);
+    // the diagnostics of each lint expected to be left once the suggestions are applied
+    let mut expected: BTreeMap<String, usize> = BTreeMap::new();
+    let mut fixed_lints = BTreeSet::new();
+    // the errors the test expects, which the fixed code can still have
+    let mut expected_errors: BTreeMap<String, usize> = BTreeMap::new();
+    for diag in ui_diagnostics(file, file, flags, out_dir, &[]) {
+        let lint = match clippy_lint(&diag) {
+            Some(lint) => lint.to_string(),
+            None => {
+                if diag["level"] == "error" {
+                    *expected_errors.entry(error_message(&diag)).or_default() += 1;
+                }
+                continue;
+            },
+        };
+        *expected.entry(lint.clone()).or_default() += 1;
+        let suggestion = serde_json::from_value::<Diagnostic>(diag)
+            .ok()
+            .and_then(|diag| rustfix::collect_suggestions(&diag, &HashSet::new(), Filter::MachineApplicableOnly));
+        // overlapping suggestions are skipped, they are checked by the diagnostic count
+        if suggestion.map_or(false, |suggestion| fix.apply(&suggestion).is_ok()) {
+            *expected.get_mut(&lint).unwrap() -= 1;
+            fixed_lints.insert(lint);
+        }
+    }
+    if fixed_lints.is_empty() {
+        return Ok(());
+    }
+
+    let fixed = fix
+        .finish()
+        .map_err(|e| format!("{}: could not apply the suggestions: {}", file.display(), e))?;
+    // The fixed code is compiled next to the test, so that `#[path]` and `include_str!` find the
+    // same files, and kept in `out_dir` to look at it.
+    let fixed_copy = FixedCopy(file.with_file_name(format!(
+        "{}_rustfix_check.rs",
+        file.file_stem().unwrap().to_str().unwrap()
+    )));
+    fs::write(&fixed_copy.0, &fixed).unwrap();
+    let fixed_file = out_dir.join(file.file_stem().unwrap()).join(file.file_name().unwrap());
+    fs::write(&fixed_file, fixed).unwrap();
+
+    let mut errors = String::new();
+    let mut left: BTreeMap<String, usize> = BTreeMap::new();
+    for diag in ui_diagnostics(file, &fixed_copy.0, flags, out_dir, &["--cap-lints=warn"]) {
+        if let Some(lint) = clippy_lint(&diag) {
+            *left.entry(lint.to_string()).or_default() += 1;
+        } else if diag["level"] == "error" {
+            match expected_errors.get_mut(&error_message(&diag)) {
+                Some(count) if *count > 0 => *count -= 1,
+                _ => errors.push_str(diag["rendered"].as_str().unwrap_or_default()),
+            }
+        }
+    }
+    if !errors.is_empty() {
+        return Err(format!(
+            "{}: the fixed code in {} doesn't compile:\n{}",
+            file.display(),
+            fixed_file.display(),
+            errors
+        ));
+    }
+    for lint in &fixed_lints {
+        let count = left.get(lint).copied().unwrap_or(0);
+        if count > expected[lint] {
+            return Err(format!(
+                "{}: `clippy::{}` still triggers in {} after applying its suggestions",
+                file.display(),
+                lint,
+                fixed_file.display()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// A fixed copy of a ui test, removed once checked, even if the check panics.
+struct FixedCopy(PathBuf);
+
+impl Drop for FixedCopy {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+/// Builds an auxiliary crate like compiletest does, as a dylib unless it says `no-prefer-dynamic`.
+fn build_aux(aux: &Path, flags: &str, out_dir: &Path) {
+    let mut cmd = Command::new(clippy_driver_path());
+    cmd.args(flags.split_whitespace())
+        .arg("--out-dir")
+        .arg(out_dir)
+        .arg(aux);
+    if !fs::read_to_string(aux).unwrap().contains("no-prefer-dynamic") {
+        cmd.arg("--crate-type=dylib");
+    }
+    let status = cmd.status().unwrap();
+    assert!(status.success(), "failed to build {}", aux.display());
+}
+
 fn prepare_env() {
     set_var("CLIPPY_DISABLE_DOCS_LINKS", "true");
     set_var("CLIPPY_TESTS", "true");
@@ -162,5 +474,11 @@ fn prepare_env() {
 fn compile_test() {
     prepare_env();
     run_mode("ui", "tests/ui".into());
+    if var_os("CLIPPY_RUSTFIX_CHECK").is_some() {
+        run_rustfix_checks();
+    }
     run_ui_toml();
+    if let Some(report) = var_os("CLIPPY_COVERAGE") {
+        run_coverage(Path::new(&report));
+    }
 }
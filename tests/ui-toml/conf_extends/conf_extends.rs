@@ -0,0 +1,8 @@
+#![warn(clippy::too_many_lines)]
+
+fn main() {
+    let toto = 42;
+    let tata = 42;
+    let titi = 42;
+    println!("{} {} {}", toto, tata, titi);
+}
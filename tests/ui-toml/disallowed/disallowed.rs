@@ -0,0 +1,13 @@
+#![warn(clippy::disallowed_method, clippy::disallowed_type, clippy::disallowed_macro)]
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+fn main() {
+    std::env::set_var("KEY", "value");
+    let _start = Instant::now();
+    let _map: HashMap<u32, u32> = HashMap::new();
+    let _value = std::env::var("KEY");
+    println!("done");
+    eprintln!("done");
+}
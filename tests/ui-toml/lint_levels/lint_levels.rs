@@ -0,0 +1,18 @@
+// The levels of `dbg_macro` and `too_many_lines` come from the `[lints]` table in `clippy.toml`.
+
+fn short() {
+    println!("This is good.");
+    println!("This is good.");
+}
+
+fn too_many_lines() {
+    println!("This is bad.");
+    println!("This is bad.");
+    println!("This is bad.");
+}
+
+fn main() {
+    dbg!(42);
+    short();
+    too_many_lines();
+}
@@ -0,0 +1,9 @@
+// `disallowed_method` was added after `max-lint-version` and is allowed, `disallowed_type` is
+// enabled in the `[lints]` table.
+
+use std::collections::HashMap;
+
+fn main() {
+    std::env::set_var("KEY", "value");
+    let _map: HashMap<u32, u32> = HashMap::new();
+}
@@ -0,0 +1,37 @@
+// The lints suggesting std APIs or language features newer than the `msrv` of the configuration
+// are silent, `map_clone` suggests `cloned` instead of `copied`.
+
+#![warn(clippy::checked_conversions, clippy::missing_const_for_fn)]
+#![allow(dead_code)]
+
+use std::time::Duration;
+
+fn checked_conversion(value: i64) -> bool {
+    value <= (u32::max_value() as i64) && value >= 0
+}
+
+fn duration_subsec(duration: Duration) -> u32 {
+    duration.subsec_nanos() / 1_000
+}
+
+fn missing_const_for_fn() -> u32 {
+    1
+}
+
+fn map_clone(values: &[u8]) -> Vec<u8> {
+    values.iter().map(|x| *x).collect()
+}
+
+fn ptr_offset_with_cast(ptr: *const u8, offset: usize) -> *const u8 {
+    unsafe { ptr.offset(offset as isize) }
+}
+
+fn range_plus_one(end: usize) -> usize {
+    (0..end + 1).count()
+}
+
+fn transmute_int_to_float(bits: u32) -> f32 {
+    unsafe { std::mem::transmute::<u32, f32>(bits) }
+}
+
+fn main() {}
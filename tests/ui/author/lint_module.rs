@@ -0,0 +1,4 @@
+fn main() {
+    #[clippy::author(lint = "char_cast", generalize)]
+    let x: char = 0x45 as char;
+}
@@ -0,0 +1,22 @@
+// rustc-env:CLIPPY_BASELINE=tests/ui/baseline.json
+
+#![warn(clippy::needless_return)]
+
+// in the baseline
+fn known() -> u32 {
+    return 1;
+}
+
+// the baseline only has one occurrence of this warning
+fn copied() -> u32 {
+    return 1;
+}
+
+// not in the baseline
+fn new() -> u32 {
+    return 2;
+}
+
+fn main() {
+    let _ = known() + copied() + new();
+}
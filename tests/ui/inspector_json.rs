@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+
+struct Point {
+    #[clippy::dump = "json"]
+    x: i32,
+    y: i32,
+}
+
+enum Shape {
+    #[clippy::dump = "json"]
+    Circle { radius: u32 },
+    Empty,
+}
+
+trait Area {
+    #[clippy::dump = "json"]
+    type Output;
+}
+
+extern "C" {
+    #[clippy::dump = "json"]
+    static COUNT: u8;
+}
+
+fn main() {
+    #[clippy::dump = "json"]
+    let _x = 1;
+}
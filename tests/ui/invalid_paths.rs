@@ -0,0 +1,32 @@
+#![deny(clippy::internal)]
+#![allow(dead_code)]
+
+mod paths {
+    // good paths
+    pub const ANY_TRAIT: [&str; 3] = ["std", "any", "Any"];
+    pub const TRANSMUTE: [&str; 4] = ["core", "intrinsics", "", "transmute"];
+    pub const VEC_NEW: [&str; 4] = ["alloc", "vec", "Vec", "new"];
+    pub const SLICE_INTO_VEC: [&str; 4] = ["alloc", "slice", "<impl [T]>", "into_vec"];
+
+    // a crate that isn't a dependency can't be checked
+    pub const REGEX_NEW: [&str; 3] = ["regex", "Regex", "new"];
+
+    // moved or misspelled paths
+    pub const BAD_CRATE_PATH: [&str; 2] = ["std", "not_an_item"];
+    pub const BAD_METHOD_PATH: [&str; 4] = ["alloc", "vec", "Vec", "not_a_method"];
+
+    // not used
+    pub const OPTION: [&str; 3] = ["core", "option", "Option"];
+}
+
+fn main() {
+    let _ = (
+        paths::ANY_TRAIT,
+        paths::TRANSMUTE,
+        paths::VEC_NEW,
+        paths::SLICE_INTO_VEC,
+        paths::REGEX_NEW,
+        paths::BAD_CRATE_PATH,
+        paths::BAD_METHOD_PATH,
+    );
+}
@@ -0,0 +1,19 @@
+#![feature(custom_inner_attributes)]
+#![clippy::msrv = "1.0"]
+#![allow(dead_code)]
+
+// The crate attribute overrides the configuration, the lints suggesting newer std APIs are silent.
+
+fn ptr_offset_with_cast(ptr: *const u8, offset: usize) -> *const u8 {
+    unsafe { ptr.offset(offset as isize) }
+}
+
+fn range_plus_one(end: usize) -> usize {
+    (0..end + 1).count()
+}
+
+fn transmute_int_to_float(bits: u32) -> f32 {
+    unsafe { std::mem::transmute::<u32, f32>(bits) }
+}
+
+fn main() {}